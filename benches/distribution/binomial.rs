@@ -25,3 +25,11 @@ fn mass(bencher: &mut Bencher) {
 
     bencher.iter(|| black_box(x.iter().map(|&x| d.mass(x)).collect::<Vec<_>>()));
 }
+
+#[bench]
+fn sample(bencher: &mut Bencher) {
+    let mut source = source::Xorshift128Plus::new([42, 69]);
+    let d = Binomial::new(1_000_000, 0.3);
+
+    bencher.iter(|| black_box(d.sample(&mut source)));
+}
@@ -1,6 +1,26 @@
 use probability::prelude::*;
 use test::{Bencher, black_box};
 
+#[bench]
+fn density(bencher: &mut Bencher) {
+    let d = Gaussian::new(0.0, 1.0);
+    let x = Independent(&d, &mut source::default()).take(1000).collect::<Vec<_>>();
+
+    bencher.iter(|| black_box(x.iter().map(|&x| d.density(x)).collect::<Vec<_>>()));
+}
+
+#[bench]
+fn density_into(bencher: &mut Bencher) {
+    let d = Gaussian::new(0.0, 1.0);
+    let x = Independent(&d, &mut source::default()).take(1000).collect::<Vec<_>>();
+    let mut p = vec![0.0; x.len()];
+
+    bencher.iter(|| {
+        d.density_into(&x, &mut p);
+        black_box(&p);
+    });
+}
+
 #[bench]
 fn distribution(bencher: &mut Bencher) {
     let d = Gaussian::new(0.0, 1.0);
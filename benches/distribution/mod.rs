@@ -1,4 +1,5 @@
 mod beta;
 mod binomial;
 mod categorical;
+mod gamma;
 mod gaussian;
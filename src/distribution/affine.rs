@@ -0,0 +1,265 @@
+use distribution::{self, Continuous, Distribution, Inverse, Mean, Parametric, Sample, Support,
+                   Variance};
+use source::Source;
+use std::fmt;
+
+/// An affine transformation of a distribution.
+///
+/// The distribution is that of `a X + b` where `X` follows another continuous
+/// distribution. A negative `a` reflects the distribution, which swaps the
+/// roles of the lower and upper tails.
+#[derive(Clone, Copy)]
+pub struct Affine<D> {
+    inner: D,
+    a: f64,
+    b: f64,
+}
+
+impl<D> Affine<D> where D: Continuous + Distribution<Value=f64> + Sample {
+    /// Create a distribution by scaling `inner` by `a` and shifting it by `b`.
+    ///
+    /// It should hold that `a` is finite and nonzero and that `b` is finite.
+    #[inline]
+    pub fn new(inner: D, a: f64, b: f64) -> Self {
+        should!(a != 0.0 && a.is_finite() && b.is_finite());
+        Affine { inner: inner, a: a, b: b }
+    }
+
+    /// Create an affine transformation or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(inner: D, a: f64, b: f64) -> Result<Self, &'static str> {
+        must!(a != 0.0 && a.is_finite(), "a must be finite and nonzero");
+        must!(b.is_finite(), "b must be finite");
+        Ok(Affine::new(inner, a, b))
+    }
+
+    /// Return the underlying distribution.
+    #[inline(always)]
+    pub fn inner(&self) -> &D { &self.inner }
+
+    /// Return the scale.
+    #[inline(always)]
+    pub fn a(&self) -> f64 { self.a }
+
+    /// Return the shift.
+    #[inline(always)]
+    pub fn b(&self) -> f64 { self.b }
+}
+
+impl<D> fmt::Display for Affine<D> where D: fmt::Display {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Affine({}, a={}, b={})", self.inner, self.a, self.b)
+    }
+}
+
+impl<D> distribution::Continuous for Affine<D>
+    where D: Continuous + Distribution<Value=f64> + Sample
+{
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        self.inner.density((x - self.b) / self.a) / self.a.abs()
+    }
+
+    #[inline]
+    fn ln_density(&self, x: f64) -> f64 {
+        self.inner.ln_density((x - self.b) / self.a) - self.a.abs().ln()
+    }
+}
+
+impl<D> distribution::Distribution for Affine<D>
+    where D: Continuous + Distribution<Value=f64> + Sample
+{
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// For a negative scale, the function is the complementary cumulative
+    /// distribution function of the underlying distribution, which relies on
+    /// the underlying distribution being continuous.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        let y = (x - self.b) / self.a;
+        if self.a > 0.0 { self.inner.distribution(y) } else { self.inner.complementary(y) }
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        let y = (x - self.b) / self.a;
+        if self.a > 0.0 { self.inner.complementary(y) } else { self.inner.distribution(y) }
+    }
+}
+
+impl<D> distribution::Inverse for Affine<D>
+    where D: Continuous + Distribution<Value=f64> + Inverse + Sample
+{
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        let y = if self.a > 0.0 { self.inner.inverse(p) } else { self.inner.inverse(1.0 - p) };
+        self.a * y + self.b
+    }
+}
+
+impl<D> distribution::Mean for Affine<D>
+    where D: Continuous + Distribution<Value=f64> + Mean + Sample
+{
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.a * self.inner.mean() + self.b
+    }
+}
+
+impl<D> distribution::Parametric for Affine<D>
+    where D: Continuous + Distribution<Value=f64> + Parametric + Sample
+{
+    /// Return the number of free parameters, which are those of the underlying
+    /// distribution together with the scale and the shift.
+    #[inline]
+    fn num_parameters(&self) -> usize {
+        self.inner.num_parameters() + 2
+    }
+}
+
+impl<D> distribution::Sample for Affine<D>
+    where D: Continuous + Distribution<Value=f64> + Sample
+{
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        self.a * self.inner.sample(source) + self.b
+    }
+}
+
+impl<D> distribution::Support for Affine<D>
+    where D: Continuous + Distribution<Value=f64> + Sample + Support
+{
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        let (lower, upper) = self.inner.support();
+        if self.a > 0.0 {
+            (self.a * lower + self.b, self.a * upper + self.b)
+        } else {
+            (self.a * upper + self.b, self.a * lower + self.b)
+        }
+    }
+}
+
+impl<D> distribution::Variance for Affine<D>
+    where D: Continuous + Distribution<Value=f64> + Sample + Variance
+{
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.a * self.a * self.inner.variance()
+    }
+
+    #[inline]
+    fn deviation(&self) -> f64 {
+        self.a.abs() * self.inner.deviation()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($inner:expr, $a:expr, $b:expr) => (Affine::new($inner, $a, $b));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(Gaussian::standard(), 2.0, 1.0);
+        let e = Gaussian::new(1.0, 2.0);
+        for &x in &[-3.0, -1.0, 0.0, 0.5, 1.0, 2.0, 5.0] {
+            assert::close(d.density(x), e.density(x), 1e-15);
+            assert::close(d.ln_density(x), e.ln_density(x), 1e-14);
+        }
+
+        let d = new!(Exponential::new(2.0), -0.5, 1.0);
+        assert_eq!(d.density(1.5), 0.0);
+        assert::close(d.density(0.5), 2.0 * Exponential::new(2.0).density(1.0), 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(Gaussian::standard(), 2.0, 1.0).to_string(),
+                   "Affine(Gaussian(μ=0, σ=1), a=2, b=1)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(Gaussian::standard(), 2.0, 1.0);
+        let e = Gaussian::new(1.0, 2.0);
+        for &x in &[-3.0, -1.0, 0.0, 0.5, 1.0, 2.0, 5.0] {
+            assert::close(d.distribution(x), e.distribution(x), 1e-15);
+            assert::close(d.complementary(x), e.complementary(x), 1e-15);
+        }
+
+        let d = new!(Gaussian::standard(), -2.0, 1.0);
+        for &x in &[-3.0, -1.0, 0.0, 0.5, 1.0, 2.0, 5.0] {
+            assert::close(d.distribution(x), e.distribution(x), 1e-15);
+            assert::close(d.complementary(x), e.complementary(x), 1e-15);
+        }
+
+        let d = new!(Exponential::new(2.0), -0.5, 1.0);
+        assert_eq!(d.distribution(1.0), 1.0);
+        assert_eq!(d.distribution(2.0), 1.0);
+        assert::close(d.distribution(0.5), (-2.0f64).exp(), 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        for &a in &[2.0, -2.0] {
+            let d = new!(Gaussian::standard(), a, 1.0);
+            let e = Gaussian::new(1.0, 2.0);
+            for &p in &[0.01, 0.25, 0.5, 0.75, 0.99] {
+                assert::close(d.inverse(p), e.inverse(p), 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(Exponential::new(2.0), -3.0, 1.0).mean(), -0.5);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(Exponential::new(2.0), -3.0, 1.0).num_parameters(), 3);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(Exponential::new(2.0), -3.0, 1.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x <= 1.0));
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::INFINITY;
+        assert_eq!(new!(Exponential::new(2.0), 3.0, 1.0).support(), (1.0, INFINITY));
+        assert_eq!(new!(Exponential::new(2.0), -3.0, 1.0).support(), (-INFINITY, 1.0));
+        assert_eq!(new!(Uniform::new(0.0, 1.0), -2.0, 1.0).support(), (-1.0, 1.0));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Affine::try_new(Gaussian::standard(), -2.0, 1.0).is_ok());
+        assert_eq!(Affine::try_new(Gaussian::standard(), 0.0, 1.0).err(),
+                   Some("a must be finite and nonzero"));
+        assert_eq!(Affine::try_new(Gaussian::standard(), 1.0, ::std::f64::NAN).err(),
+                   Some("b must be finite"));
+    }
+
+    #[test]
+    fn variance() {
+        let d = new!(Gaussian::new(1.0, 2.0), -3.0, 1.0);
+        assert_eq!(d.variance(), 36.0);
+        assert_eq!(d.deviation(), 6.0);
+    }
+}
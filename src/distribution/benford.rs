@@ -0,0 +1,359 @@
+use distribution;
+use source::Source;
+use std::fmt;
+use std::ops::Range;
+
+/// A Benford distribution.
+///
+/// The distribution describes the leading digits of data that span several
+/// orders of magnitude. In base `b`, the mass at digit `d ∈ {1, …, b - 1}` is
+/// `log_b(1 + 1 / d)`, so that small digits are more frequent than large ones.
+#[derive(Clone, Copy)]
+pub struct Benford {
+    base: u64,
+    ln_base: f64,
+}
+
+impl Benford {
+    /// Create a Benford distribution in base `base`.
+    ///
+    /// It should hold that `base >= 2`.
+    #[inline]
+    pub fn new(base: u64) -> Self {
+        should!(base >= 2);
+        Benford { base: base, ln_base: (base as f64).ln() }
+    }
+
+    /// Create a Benford distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(base: u64) -> Result<Self, &'static str> {
+        must!(base >= 2, "base must be at least 2");
+        Ok(Benford::new(base))
+    }
+
+    /// Return the base.
+    #[inline(always)]
+    pub fn base(&self) -> u64 { self.base }
+
+    /// Compute Pearson's chi-squared statistic of the leading digits of
+    /// `data` against the distribution.
+    ///
+    /// Zeros and values that are not finite have no leading digit and are
+    /// skipped. Under the distribution, the statistic approximately follows the
+    /// chi-squared distribution with `base - 2` degrees of freedom. It should
+    /// hold that at least one value has a leading digit.
+    pub fn chi_squared(&self, data: &[f64]) -> f64 {
+        use distribution::Discrete;
+        let mut counts = vec![0usize; self.base as usize - 1];
+        for digit in data.iter().filter_map(|&x| self.first_digit(x)) {
+            counts[digit - 1] += 1;
+        }
+        let n = counts.iter().fold(0, |sum, &count| sum + count) as f64;
+        should!(n > 0.0);
+        counts.iter().enumerate().fold(0.0, |sum, (i, &count)| {
+            let expected = n * self.mass(i + 1);
+            let difference = count as f64 - expected;
+            sum + difference * difference / expected
+        })
+    }
+
+    /// Return the leading digit of `x` in the base of the distribution.
+    ///
+    /// The sign is ignored, and `None` is returned for zero and for values
+    /// that are not finite.
+    pub fn first_digit(&self, x: f64) -> Option<usize> {
+        let x = x.abs();
+        if x == 0.0 || !x.is_finite() {
+            return None;
+        }
+        let base = self.base as f64;
+        let mut exponent = (x.ln() / self.ln_base).floor();
+        let mut digit = (x / base.powf(exponent)).floor();
+        if digit < 1.0 {
+            exponent -= 1.0;
+            digit = (x / base.powf(exponent)).floor();
+        } else if digit >= base {
+            exponent += 1.0;
+            digit = (x / base.powf(exponent)).floor();
+        }
+        Some(digit.max(1.0).min(base - 1.0) as usize)
+    }
+}
+
+impl Default for Benford {
+    #[inline]
+    fn default() -> Self {
+        Benford::new(10)
+    }
+}
+
+impl fmt::Display for Benford {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Benford(base={})", self.base)
+    }
+}
+
+impl distribution::Discrete for Benford {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x == 0 || x as u64 >= self.base {
+            0.0
+        } else {
+            (1.0 / x as f64).ln_1p() / self.ln_base
+        }
+    }
+}
+
+impl distribution::DiscreteSupport for Benford {
+    type Iter = Range<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (1, self.base as i64 - 1)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        1..(self.base as usize)
+    }
+}
+
+impl distribution::Distribution for Benford {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function, which is `log_b(1 + ⌊x⌋)`
+    /// for `1 ≤ x < b`.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            0.0
+        } else if x >= (self.base - 1) as f64 {
+            1.0
+        } else {
+            x.floor().ln_1p() / self.ln_base
+        }
+    }
+}
+
+impl distribution::Entropy for Benford {
+    fn entropy(&self) -> f64 {
+        use distribution::Discrete;
+        -(1..(self.base as usize)).fold(0.0, |sum, d| {
+            let p = self.mass(d);
+            sum + p * p.ln()
+        })
+    }
+}
+
+impl distribution::Inverse for Benford {
+    /// Compute the inverse of the cumulative distribution function, which is
+    /// `⌈b^p - 1⌉` clamped to the support.
+    ///
+    /// The closed form is corrected by one digit if rounding places it on the
+    /// wrong side of a step of the cumulative distribution function.
+    fn inverse(&self, p: f64) -> usize {
+        use distribution::Distribution;
+        should!(0.0 <= p && p <= 1.0);
+        let digit = ((p * self.ln_base).exp() - 1.0).ceil();
+        let digit = digit.max(1.0).min((self.base - 1) as f64) as usize;
+        if digit > 1 && self.distribution((digit - 1) as f64) >= p {
+            digit - 1
+        } else if (digit as u64) < self.base - 1 && self.distribution(digit as f64) < p {
+            digit + 1
+        } else {
+            digit
+        }
+    }
+}
+
+impl distribution::Mean for Benford {
+    fn mean(&self) -> f64 {
+        use distribution::Discrete;
+        (1..(self.base as usize)).fold(0.0, |sum, d| sum + d as f64 * self.mass(d))
+    }
+}
+
+impl distribution::Modes for Benford {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Sample for Benford {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> usize where S: Source {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Variance for Benford {
+    fn variance(&self) -> f64 {
+        use distribution::{Discrete, Mean};
+        let mean = self.mean();
+        (1..(self.base as usize)).fold(0.0, |sum, d| {
+            let difference = d as f64 - mean;
+            sum + difference * difference * self.mass(d)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        () => (Benford::default());
+        ($base:expr) => (Benford::new($base));
+    );
+
+    #[test]
+    fn chi_squared() {
+        let d = new!();
+        let p = (1..10).map(|digit| d.mass(digit)).collect::<Vec<_>>();
+        let mut data = vec![];
+        for (i, &p) in p.iter().enumerate() {
+            for j in 0..(1000.0 * p).round() as usize {
+                data.push(((i + 1) as f64 + 0.5) * 10f64.powi(j as i32 % 7 - 3));
+            }
+        }
+        data.push(0.0);
+        assert!(d.chi_squared(&data) < 0.01);
+
+        let data = (1..1000).map(|x| x as f64).collect::<Vec<_>>();
+        assert!(d.chi_squared(&data) > Chisquared::new(8).inverse(0.999));
+
+        let mut source = source::default();
+        let samples = Independent(&d, &mut source).take(10000).map(|x| x as f64)
+                                                               .collect::<Vec<_>>();
+        assert!(d.chi_squared(&samples) < Chisquared::new(8).inverse(0.999));
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(new!().base(), 10);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(16).to_string(), "Benford(base=16)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!();
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 3.0102999566398120e-01,
+            4.7712125471966244e-01, 6.0205999132796240e-01, 6.9897000433601886e-01,
+            7.7815125038364363e-01, 8.4509804001425681e-01, 9.0308998699194354e-01,
+            9.5424250943932487e-01, 1.0000000000000000e+00, 1.0000000000000000e+00,
+        ];
+
+        let x = (-1..11).map(|x| d.distribution(x as f64)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+
+        let x = (-1..11).map(|x| d.distribution(x as f64 + 0.5)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!().entropy(), 1.9934331507912042, 1e-15);
+        assert::close(new!(2).entropy(), 0.0, 1e-15);
+    }
+
+    #[test]
+    fn first_digit() {
+        let d = new!();
+        assert_eq!(d.first_digit(1.0), Some(1));
+        assert_eq!(d.first_digit(1000.0), Some(1));
+        assert_eq!(d.first_digit(999.999), Some(9));
+        assert_eq!(d.first_digit(-0.00345), Some(3));
+        assert_eq!(d.first_digit(7e-300), Some(7));
+        assert_eq!(d.first_digit(0.0), None);
+        assert_eq!(d.first_digit(::std::f64::NAN), None);
+        assert_eq!(d.first_digit(::std::f64::INFINITY), None);
+
+        let d = new!(16);
+        assert_eq!(d.first_digit(255.0), Some(15));
+        assert_eq!(d.first_digit(256.0), Some(1));
+        assert_eq!(new!(2).first_digit(12345.0), Some(1));
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!();
+        for x in 1..10 {
+            assert_eq!(d.inverse(d.distribution(x as f64)), x);
+        }
+        assert_eq!(d.inverse(0.0), 1);
+        assert_eq!(d.inverse(1.0), 9);
+    }
+
+    #[test]
+    fn iter_support() {
+        assert_eq!(new!(4).iter_support().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!();
+        let p = vec![
+            0.0000000000000000e+00, 3.0102999566398120e-01, 1.7609125905568124e-01,
+            1.2493873660829995e-01, 9.6910013008056420e-02, 7.9181246047624831e-02,
+            6.6946789630613193e-02, 5.7991946977686754e-02, 5.1152522447381291e-02,
+            4.5757490560675122e-02, 0.0000000000000000e+00,
+        ];
+
+        assert::close(&(0..11).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        for &base in &[2, 10] {
+            let d = new!(base);
+            let sum = d.iter_support().fold(0.0, |sum, x| sum + d.mass(x));
+            assert::close(sum, 1.0, 1e-15);
+        }
+        assert_eq!(new!(2).mass(1), 1.0);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!().mean(), 3.440236967123206, 1e-15);
+        assert_eq!(new!(2).mean(), 1.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!().modes(), vec![1]);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!();
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| 1 <= x && x <= 9));
+        let mean = samples.iter().fold(0.0, |a, &b| a + b as f64) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!().support(), (1, 9));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Benford::try_new(2).is_ok());
+        assert_eq!(Benford::try_new(1).err(), Some("base must be at least 2"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!().variance(), 6.0565126313756661, 1e-14);
+        assert_eq!(new!(2).variance(), 0.0);
+    }
+}
@@ -1,5 +1,7 @@
 use distribution;
 use source::Source;
+use std::fmt;
+use std::ops::Range;
 
 /// A Bernoulli distribution.
 #[derive(Clone, Copy)]
@@ -19,6 +21,14 @@ impl Bernoulli {
         Bernoulli { p: p, q: 1.0 - p, pq: p * (1.0 - p) }
     }
 
+    /// Create a Bernoulli distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(p: f64) -> Result<Self, &'static str> {
+        must!(p > 0.0 && p < 1.0, "p must be in (0, 1)");
+        Ok(Bernoulli::new(p))
+    }
+
     /// Create a Bernoulli distribution with failure probability `q`.
     ///
     /// It should hold that `q > 0` and `q < 1`. This constructor is preferable
@@ -29,6 +39,21 @@ impl Bernoulli {
         Bernoulli { p: 1.0 - q, q: q, pq: (1.0 - q) * q }
     }
 
+    /// Create a Bernoulli distribution with log-odds `z`, that is, with success
+    /// probability `1 / (1 + exp(-z))`.
+    ///
+    /// The success and failure probabilities are computed separately, so that
+    /// the smaller one retains its relative accuracy for large `|z|` and
+    /// saturates to zero instead of producing a NaN.
+    #[inline]
+    pub fn with_logit(z: f64) -> Self {
+        should!(!z.is_nan());
+        let e = (-z.abs()).exp();
+        let (large, small) = (1.0 / (1.0 + e), e / (1.0 + e));
+        let (p, q) = if z >= 0.0 { (large, small) } else { (small, large) };
+        Bernoulli { p: p, q: q, pq: p * q }
+    }
+
     /// Return the success probability.
     #[inline(always)]
     pub fn p(&self) -> f64 { self.p }
@@ -36,6 +61,16 @@ impl Bernoulli {
     /// Return the failure probability.
     #[inline(always)]
     pub fn q(&self) -> f64 { self.q }
+
+    /// Return the log-odds, which is `ln(p / q)`.
+    #[inline]
+    pub fn logit(&self) -> f64 { self.p.ln() - self.q.ln() }
+}
+
+impl fmt::Display for Bernoulli {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Bernoulli(p={})", self.p)
+    }
 }
 
 impl distribution::Discrete for Bernoulli {
@@ -49,6 +84,31 @@ impl distribution::Discrete for Bernoulli {
             0.0
         }
     }
+
+    #[inline]
+    fn ln_mass(&self, x: u8) -> f64 {
+        if x == 0 {
+            self.q.ln()
+        } else if x == 1 {
+            self.p.ln()
+        } else {
+            ::std::f64::NEG_INFINITY
+        }
+    }
+}
+
+impl distribution::DiscreteSupport for Bernoulli {
+    type Iter = Range<u8>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (0, 1)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        0..2
+    }
 }
 
 impl distribution::Distribution for Bernoulli {
@@ -116,6 +176,11 @@ impl distribution::Modes for Bernoulli {
     }
 }
 
+impl distribution::Parametric for Bernoulli {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
 impl distribution::Sample for Bernoulli {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> u8 where S: Source {
@@ -145,6 +210,11 @@ mod tests {
         ($p:expr) => (Bernoulli::new($p));
     );
 
+    #[test]
+    fn display() {
+        assert_eq!(new!(0.25).to_string(), "Bernoulli(p=0.25)");
+    }
+
     #[test]
     fn distribution() {
         let d = new!(0.25);
@@ -168,11 +238,36 @@ mod tests {
         assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x);
     }
 
+    #[test]
+    fn iter_support() {
+        let d = new!(0.25);
+        assert_eq!(d.iter_support().collect::<Vec<_>>(), vec![0, 1]);
+        assert::close(d.iter_support().fold(0.0, |sum, x| sum + d.mass(x)), 1.0, 1e-14);
+    }
+
     #[test]
     fn kurtosis() {
         assert_eq!(new!(0.5).kurtosis(), -2.0);
     }
 
+    #[test]
+    fn ln_mass() {
+        use std::f64::NEG_INFINITY;
+        let d = new!(0.25);
+        assert_eq!(d.ln_mass(0), 0.75f64.ln());
+        assert_eq!(d.ln_mass(1), 0.25f64.ln());
+        assert_eq!(d.ln_mass(2), NEG_INFINITY);
+    }
+
+    #[test]
+    fn logit() {
+        assert_eq!(new!(0.5).logit(), 0.0);
+        assert::close(new!(0.75).logit(), 3f64.ln(), 1e-15);
+        for &z in &[-40.0, -5.0, -0.5, 0.5, 5.0, 40.0] {
+            assert::close(Bernoulli::with_logit(z).logit(), z, 1e-14);
+        }
+    }
+
     #[test]
     fn mass() {
         let d = new!(0.25);
@@ -198,6 +293,11 @@ mod tests {
         assert_eq!(new!(0.75).modes(), vec![1]);
     }
 
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(0.25).num_parameters(), 1);
+    }
+
     #[test]
     fn sample() {
         assert!(Independent(&new!(0.25), &mut source::default()).take(100)
@@ -209,8 +309,39 @@ mod tests {
         assert_eq!(new!(0.5).skewness(), 0.0);
     }
 
+    #[test]
+    fn support() {
+        assert_eq!(new!(0.25).support(), (0, 1));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Bernoulli::try_new(0.5).is_ok());
+        assert_eq!(Bernoulli::try_new(1.0).err(), Some("p must be in (0, 1)"));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(0.25).variance(), 0.1875);
     }
+
+    #[test]
+    fn with_logit() {
+        let d = Bernoulli::with_logit(0.0);
+        assert_eq!((d.p(), d.q()), (0.5, 0.5));
+
+        let d = Bernoulli::with_logit(40.0);
+        assert_eq!(d.p(), 1.0);
+        assert::close(d.q(), 4.2483542552915889e-18, 1e-33);
+
+        let d = Bernoulli::with_logit(-40.0);
+        assert::close(d.p(), 4.2483542552915889e-18, 1e-33);
+        assert_eq!(d.q(), 1.0);
+
+        let d = Bernoulli::with_logit(1000.0);
+        assert_eq!((d.p(), d.q()), (1.0, 0.0));
+
+        let d = Bernoulli::with_logit(-1000.0);
+        assert_eq!((d.p(), d.q()), (0.0, 1.0));
+    }
 }
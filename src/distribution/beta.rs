@@ -1,5 +1,6 @@
 use distribution;
 use source::Source;
+use std::fmt;
 
 /// A beta distribution.
 #[derive(Clone, Copy)]
@@ -23,6 +24,39 @@ impl Beta {
         Beta { alpha: alpha, beta: beta, a: a, b: b, ln_beta: alpha.ln_beta(beta) }
     }
 
+    /// Create a beta distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(alpha: f64, beta: f64, a: f64, b: f64) -> Result<Self, &'static str> {
+        must!(alpha > 0.0, "alpha must be positive");
+        must!(beta > 0.0, "beta must be positive");
+        must!(a < b, "a must be less than b");
+        Ok(Beta::new(alpha, beta, a, b))
+    }
+
+    /// Fit a beta distribution on `[0, 1]` to `samples` by the method of
+    /// moments.
+    ///
+    /// The shape parameters are chosen so that the mean and the variance of
+    /// the distribution match the sample mean and the unbiased sample variance.
+    /// If the sample variance is too large for any beta distribution, the
+    /// parameters are clamped to a small positive value. It should hold that
+    /// there are at least two samples and that all of them lie in `(0, 1)`.
+    pub fn fit(samples: &[f64]) -> Self {
+        const EPSILON: f64 = 1e-8;
+        should!(samples.len() > 1);
+        should!(samples.iter().all(|&x| 0.0 < x && x < 1.0));
+        let n = samples.len() as f64;
+        let mean = samples.iter().fold(0.0, |sum, &x| sum + x) / n;
+        let variance = samples.iter().fold(0.0, |sum, &x| sum + (x - mean) * (x - mean)) /
+                       (n - 1.0);
+        let common = mean * (1.0 - mean) / variance - 1.0;
+        let alpha = mean * common;
+        let beta = (1.0 - mean) * common;
+        Beta::new(if alpha > EPSILON { alpha } else { EPSILON },
+                  if beta > EPSILON { beta } else { EPSILON }, 0.0, 1.0)
+    }
+
     /// Return the first shape parameter.
     #[inline(always)]
     pub fn alpha(&self) -> f64 { self.alpha }
@@ -40,6 +74,12 @@ impl Beta {
     pub fn b(&self) -> f64 { self.b }
 }
 
+impl fmt::Display for Beta {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Beta(α={}, β={}, a={}, b={})", self.alpha, self.beta, self.a, self.b)
+    }
+}
+
 impl distribution::Continuous for Beta {
     fn density(&self, x: f64) -> f64 {
         if x < self.a || x > self.b {
@@ -51,6 +91,17 @@ impl distribution::Continuous for Beta {
                 self.ln_beta).exp() / scale
         }
     }
+
+    fn ln_density(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            ::std::f64::NEG_INFINITY
+        } else {
+            let scale = self.b - self.a;
+            let x = (x - self.a) / scale;
+            (self.alpha - 1.0) * x.ln() + (self.beta - 1.0) * (-x).ln_1p() - self.ln_beta -
+                scale.ln()
+        }
+    }
 }
 
 impl distribution::Distribution for Beta {
@@ -78,11 +129,34 @@ impl distribution::Entropy for Beta {
 }
 
 impl distribution::Inverse for Beta {
-    #[inline]
+    /// Compute the inverse of the cumulative distribution function.
+    ///
+    /// The regularized incomplete beta function is inverted by the
+    /// Newton–Raphson method. The root is kept in a bracket that is first
+    /// halved once, and the initial guess is given by the power-law behavior
+    /// of the tail containing the root. A step leaving the bracket is replaced
+    /// by a bisection, so the iteration cannot diverge even when the density
+    /// is highly concentrated.
+    ///
+    /// When both `p` and the root exceed one half, the complementary equation
+    /// `I(1 - x; β, α) = 1 - p` is solved for `1 - x` instead, so that a root
+    /// close to one, as for small `β`, keeps its accuracy.
     fn inverse(&self, p: f64) -> f64 {
-        use special::Beta;
+        use special::Beta as SpecialBeta;
+
         should!(0.0 <= p && p <= 1.0);
-        self.a + (self.b - self.a) * p.inv_inc_beta(self.alpha, self.beta, self.ln_beta)
+        if p <= 0.0 {
+            return self.a;
+        }
+        if p >= 1.0 {
+            return self.b;
+        }
+        let &Beta { alpha, beta, a, b, ln_beta } = self;
+        if p > 0.5 && p > 0.5.inc_beta(alpha, beta, ln_beta) {
+            b - (b - a) * invert(1.0 - p, beta, alpha, ln_beta)
+        } else {
+            a + (b - a) * invert(p, alpha, beta, ln_beta)
+        }
     }
 }
 
@@ -107,7 +181,7 @@ impl distribution::Median for Beta {
     fn median(&self) -> f64 {
         use distribution::Inverse;
         if self.alpha == self.beta {
-            0.5 * (self.b - self.a)
+            self.a + 0.5 * (self.b - self.a)
         } else if self.alpha > 1.0 && self.beta > 1.0 {
             self.a + (self.b - self.a) * (self.alpha - 1.0 / 3.0) /
                 (self.alpha + self.beta - 2.0 / 3.0)
@@ -137,6 +211,11 @@ impl distribution::Modes for Beta {
     }
 }
 
+impl distribution::Parametric for Beta {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
 impl distribution::Sample for Beta {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
@@ -155,6 +234,13 @@ impl distribution::Skewness for Beta {
     }
 }
 
+impl distribution::Support for Beta {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        (self.a, self.b)
+    }
+}
+
 impl distribution::Variance for Beta {
     fn variance(&self) -> f64 {
         let scale = self.b - self.a;
@@ -163,6 +249,45 @@ impl distribution::Variance for Beta {
     }
 }
 
+/// Solve `I(x; α, β) = p` for `x` in `[0, 1]`, where `0 < p < 1`.
+fn invert(p: f64, alpha: f64, beta: f64, ln_beta: f64) -> f64 {
+    use special::Beta as SpecialBeta;
+
+    const EPSILON: f64 = 1e-15;
+    const MAX_ITERATIONS: usize = 1000;
+
+    let (mut a, mut b, mut x) = if p <= 0.5.inc_beta(alpha, beta, ln_beta) {
+        let x = ((p.ln() + alpha.ln() + ln_beta) / alpha).exp();
+        (0.0, 0.5, if x < 0.5 { x } else { 0.25 })
+    } else {
+        let x = -((-p).ln_1p() + beta.ln() + ln_beta) / beta;
+        let x = -x.exp_m1();
+        (0.5, 1.0, if x > 0.5 { x } else { 0.75 })
+    };
+    if x <= 0.0 || x >= 1.0 {
+        // The root is not representable in the interior of [0, 1].
+        return x;
+    }
+    for _ in 0..MAX_ITERATIONS {
+        let f = x.inc_beta(alpha, beta, ln_beta) - p;
+        if f == 0.0 {
+            break;
+        }
+        if f < 0.0 { a = x } else { b = x }
+        let density = ((alpha - 1.0) * x.ln() + (beta - 1.0) * (-x).ln_1p() - ln_beta).exp();
+        let mut next = x - f / density;
+        if !(a < next && next < b) {
+            next = 0.5 * (a + b);
+        }
+        let done = (next - x).abs() <= EPSILON * next || b - a <= EPSILON * next;
+        x = next;
+        if done {
+            break;
+        }
+    }
+    x
+}
+
 #[cfg(test)]
 mod tests {
     use assert;
@@ -193,6 +318,11 @@ mod tests {
         assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-14);
     }
 
+    #[test]
+    fn display() {
+        assert_eq!(new!(2.0, 3.0, 0.0, 1.5).to_string(), "Beta(α=2, β=3, a=0, b=1.5)");
+    }
+
     #[test]
     fn distribution() {
         let d = new!(2.0, 3.0, -1.0, 2.0);
@@ -225,8 +355,28 @@ mod tests {
                       &vec![0.0, 1.0, -0.2349066497879999, 0.8637056388801096], 1e-15);
     }
 
+    #[test]
+    fn fit() {
+        let d = Beta::fit(&[0.1, 0.2, 0.3, 0.4]);
+        assert::close(d.alpha(), 2.5625, 1e-14);
+        assert::close(d.beta(), 7.6875, 1e-14);
+        assert_eq!((d.a(), d.b()), (0.0, 1.0));
+
+        let mut source = source::default();
+        let samples = Independent(&new!(2.0, 5.0, 0.0, 1.0), &mut source).take(10000)
+                                                                        .collect::<Vec<_>>();
+        let d = Beta::fit(&samples);
+        assert!((d.alpha() - 2.0).abs() < 0.2);
+        assert!((d.beta() - 5.0).abs() < 0.5);
+
+        let d = Beta::fit(&[1e-6, 1.0 - 1e-6]);
+        assert!(d.alpha() > 0.0 && d.beta() > 0.0);
+    }
+
     #[test]
     fn inverse() {
+        use special::Beta as SpecialBeta;
+
         let d = new!(1.0, 2.0, 3.0, 4.0);
         let p = vec![
             0.0, 0.05, 0.1, 0.15, 0.2, 0.25, 0.3, 0.35, 0.4, 0.45, 0.5,
@@ -242,6 +392,41 @@ mod tests {
             3.683772233983162e+00, 3.776393202250021e+00, 4.000000000000000e+00,
         ];
         assert::close(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x, 1e-14);
+
+        let d = new!(1e4, 1e4, 0.0, 1.0);
+        assert_eq!(d.inverse(0.0), 0.0);
+        assert_eq!(d.inverse(1.0), 1.0);
+        assert::close(d.inverse(0.5), 0.5, 1e-14);
+        assert::close(d.inverse(0.1) + d.inverse(0.9), 1.0, 1e-14);
+
+        for &(alpha, beta) in &[(1e7, 1.0), (0.5, 1.0), (1.0, 0.05), (1.0, 1e3)] {
+            let d = new!(alpha, beta, 0.0, 1.0);
+            for &p in &[1e-10f64, 0.001, 0.1, 0.5, 0.9, 0.999] {
+                let x = if beta == 1.0 {
+                    p.powf(1.0 / alpha)
+                } else {
+                    -((-p).ln_1p() / beta).exp_m1()
+                };
+                assert::close(d.inverse(p), x, 1e-12 * x);
+            }
+        }
+
+        let d = new!(0.05, 50.0, 0.0, 1.0);
+        assert::close(d.inverse(0.1), 1.1801177234440239e-22, 1e-34);
+        assert::close(d.distribution(d.inverse(0.9)), 0.9, 1e-15);
+
+        // The root is within one spacing of the floating-point numbers below
+        // one, which is checked via the complementary form on `1 - x`.
+        let h = 0.5 * ::std::f64::EPSILON;
+        for &(alpha, beta) in &[(1.0, 0.05), (2.0, 0.5), (5.0, 0.2)] {
+            let d = new!(alpha, beta, 0.0, 1.0);
+            let ln_beta = alpha.ln_beta(beta);
+            for &p in &[0.9, 0.99, 0.999, 0.999999, 1.0 - 1e-9] {
+                let y = 1.0 - d.inverse(p);
+                assert!((y - h).max(0.0).inc_beta(beta, alpha, ln_beta) <= 1.0 - p);
+                assert!(1.0 - p <= (y + h).inc_beta(beta, alpha, ln_beta));
+            }
+        }
     }
 
     #[test]
@@ -251,6 +436,22 @@ mod tests {
         assert_eq!(new!(3.0, 2.0, -1.0, 2.0).kurtosis(), -0.6428571428571429);
     }
 
+    #[test]
+    fn ln_density() {
+        use std::f64::NEG_INFINITY;
+
+        let d = new!(2.0, 3.0, -1.0, 2.0);
+        for &x in &[-0.85, 0.0, 0.5, 1.5, 1.9] {
+            assert::close(d.ln_density(x), d.density(x).ln(), 1e-14);
+        }
+        assert_eq!(d.ln_density(-1.5), NEG_INFINITY);
+        assert_eq!(d.ln_density(2.5), NEG_INFINITY);
+
+        let d = new!(500.0, 500.0, 0.0, 1.0);
+        assert_eq!(d.density(0.01), 0.0);
+        assert::close(d.ln_density(0.01), -1608.0063179132414, 1e-10);
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(0.5, 0.5, 0.0, 1.0).mean(), 0.5);
@@ -262,6 +463,7 @@ mod tests {
         assert_eq!(new!(2.0, 2.0, 0.0, 1.0).median(), 0.5);
         assert_eq!(new!(2.0, 3.0, 0.0, 1.0).median(), 5.0 / 13.0);
         assert_eq!(new!(2.0, 3.0, -1.0, 2.0).median(), 3.0 * (5.0 / 13.0) -1.0);
+        assert_eq!(new!(2.0, 2.0, -1.0, 2.0).median(), 0.5);
     }
 
     #[test]
@@ -281,6 +483,11 @@ mod tests {
         }
     }
 
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.0, 3.0, -1.0, 2.0).num_parameters(), 2);
+    }
+
     #[test]
     fn sample() {
         for x in Independent(&new!(1.0, 2.0, 7.0, 42.0), &mut source::default()).take(100) {
@@ -295,6 +502,18 @@ mod tests {
         assert_eq!(new!(3.0, 2.0, -1.0, 2.0).skewness(), -0.28571428571428575);
     }
 
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.0, 3.0, -1.0, 2.0).support(), (-1.0, 2.0));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Beta::try_new(2.0, 3.0, -1.0, 2.0).is_ok());
+        assert_eq!(Beta::try_new(0.0, 3.0, 0.0, 1.0).err(), Some("alpha must be positive"));
+        assert_eq!(Beta::try_new(2.0, 3.0, 1.0, 1.0).err(), Some("a must be less than b"));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(1.0, 1.0, 0.0, 1.0).variance(), 1.0 / 12.0);
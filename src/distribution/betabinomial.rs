@@ -0,0 +1,295 @@
+use distribution::{self, Beta, Binomial, Discrete};
+use source::Source;
+use std::fmt;
+use std::ops::Range;
+
+/// A beta-binomial distribution.
+///
+/// The distribution models the number of successes in `n` Bernoulli trials
+/// whose common success probability is itself drawn from a beta distribution
+/// with shape parameters `α` and `β`. Compared with the binomial distribution
+/// with the same mean, the variance is inflated by the factor
+/// `(α + β + n) / (α + β + 1)`.
+#[derive(Clone, Copy)]
+pub struct BetaBinomial {
+    n: u64,
+    alpha: f64,
+    beta: f64,
+    ln_norm: f64,
+}
+
+impl BetaBinomial {
+    /// Create a beta-binomial distribution with `n` trials and shape
+    /// parameters `alpha` and `beta`.
+    ///
+    /// It should hold that `alpha > 0` and `beta > 0`.
+    #[inline]
+    pub fn new(n: u64, alpha: f64, beta: f64) -> Self {
+        use special::Beta;
+        should!(alpha > 0.0 && beta > 0.0);
+        BetaBinomial { n: n, alpha: alpha, beta: beta, ln_norm: alpha.ln_beta(beta) }
+    }
+
+    /// Create a beta-binomial distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(n: u64, alpha: f64, beta: f64) -> Result<Self, &'static str> {
+        must!(alpha > 0.0, "alpha must be positive");
+        must!(beta > 0.0, "beta must be positive");
+        Ok(BetaBinomial::new(n, alpha, beta))
+    }
+
+    /// Return the number of trials.
+    #[inline(always)]
+    pub fn n(&self) -> u64 { self.n }
+
+    /// Return the first shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 { self.alpha }
+
+    /// Return the second shape parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 { self.beta }
+}
+
+impl fmt::Display for BetaBinomial {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "BetaBinomial(n={}, α={}, β={})", self.n, self.alpha, self.beta)
+    }
+}
+
+impl distribution::Discrete for BetaBinomial {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        self.ln_mass(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability mass function, which
+    /// is `ln C(n, k) + ln B(k + α, n - k + β) - ln B(α, β)`.
+    ///
+    /// The binomial coefficient is computed as `1 / ((n + 1) B(n - k + 1, k +
+    /// 1))`.
+    fn ln_mass(&self, x: usize) -> f64 {
+        use special::Beta;
+        if x as u64 > self.n {
+            return ::std::f64::NEG_INFINITY;
+        }
+        let (n, k) = (self.n as f64, x as f64);
+        -(n + 1.0).ln() - (n - k + 1.0).ln_beta(k + 1.0) +
+            (k + self.alpha).ln_beta(n - k + self.beta) - self.ln_norm
+    }
+}
+
+impl distribution::DiscreteSupport for BetaBinomial {
+    type Iter = Range<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (0, self.n as i64)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        0..(self.n as usize + 1)
+    }
+}
+
+impl distribution::Distribution for BetaBinomial {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function by summing the masses.
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        let x = x.floor();
+        if x >= self.n as f64 {
+            return 1.0;
+        }
+        (0..(x as usize + 1)).fold(0.0, |sum, k| sum + self.mass(k))
+    }
+
+    /// Compute the complementary cumulative distribution function by summing
+    /// the masses above `x`.
+    fn complementary(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 1.0;
+        }
+        let x = x.floor();
+        if x >= self.n as f64 {
+            return 0.0;
+        }
+        ((x as usize + 1)..(self.n as usize + 1)).fold(0.0, |sum, k| sum + self.mass(k))
+    }
+}
+
+impl distribution::Mean for BetaBinomial {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.n as f64 * self.alpha / (self.alpha + self.beta)
+    }
+}
+
+impl distribution::Modes for BetaBinomial {
+    /// Compute the modes.
+    ///
+    /// The masses are compared with a relative tolerance of `1e-12`, so that
+    /// the two ends of a symmetric U-shaped distribution are both reported.
+    fn modes(&self) -> Vec<usize> {
+        const EPSILON: f64 = 1e-12;
+        let masses = (0..(self.n as usize + 1)).map(|k| self.mass(k)).collect::<Vec<_>>();
+        let max = masses.iter().fold(0.0, |max: f64, &p| max.max(p));
+        (0..masses.len()).filter(|&k| masses[k] >= max * (1.0 - EPSILON)).collect()
+    }
+}
+
+impl distribution::Parametric for BetaBinomial {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for BetaBinomial {
+    /// Draw a sample by drawing the success probability from the beta
+    /// distribution and then the number of successes from the binomial one.
+    fn sample<S>(&self, source: &mut S) -> usize where S: Source {
+        let p = Beta::new(self.alpha, self.beta, 0.0, 1.0).sample(source);
+        if p <= 0.0 {
+            0
+        } else if p >= 1.0 {
+            self.n as usize
+        } else {
+            Binomial::new(self.n as usize, p).sample(source)
+        }
+    }
+}
+
+impl distribution::Variance for BetaBinomial {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let (n, alpha, beta) = (self.n as f64, self.alpha, self.beta);
+        let sum = alpha + beta;
+        n * alpha * beta * (sum + n) / (sum * sum * (sum + 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($n:expr, $alpha:expr, $beta:expr) => (BetaBinomial::new($n, $alpha, $beta));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(10, 2.0, 3.0);
+        for &x in &[-1.0, 0.0, 2.5, 5.0, 9.0, 10.0, 11.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-14);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(10, 2.0, 3.5).to_string(), "BetaBinomial(n=10, α=2, β=3.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(10, 2.0, 3.0);
+        let p = vec![
+            0.0, 6.5934065934065936e-02, 1.7582417582417584e-01, 3.1068931068931072e-01,
+            4.5454545454545453e-01, 5.9440559440559437e-01, 7.2027972027972031e-01,
+            8.2517482517482521e-01, 9.0509490509490509e-01, 9.5904095904095910e-01,
+            9.8901098901098905e-01, 1.0,
+        ];
+
+        let x = (-1..11).map(|x| d.distribution(x as f64)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-14);
+
+        let x = (-1..11).map(|x| d.distribution(x as f64 + 0.5)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-14);
+    }
+
+    #[test]
+    fn iter_support() {
+        assert_eq!(new!(3, 2.0, 3.0).iter_support().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn ln_mass() {
+        let d = new!(10, 2.0, 3.0);
+        for x in 0..11 {
+            assert::close(d.ln_mass(x), d.mass(x).ln(), 1e-14);
+        }
+        assert_eq!(d.ln_mass(11), ::std::f64::NEG_INFINITY);
+        assert::close(new!(1000, 0.5, 2.5).ln_mass(1000), -17.151728462810646, 1e-11);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(10, 2.0, 3.0);
+        let p = vec![
+            6.5934065934065936e-02, 1.0989010989010989e-01, 1.3486513486513488e-01,
+            1.4385614385614387e-01, 1.3986013986013987e-01, 1.2587412587412589e-01,
+            1.0489510489510490e-01, 7.9920079920079920e-02, 5.3946053946053944e-02,
+            2.9970029970029972e-02, 1.0989010989010990e-02, 0.0,
+        ];
+
+        assert::close(&(0..12).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(10, 1.0, 1.0);
+        for x in 0..11 {
+            assert::close(d.mass(x), 1.0 / 11.0, 1e-15);
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(10, 2.0, 3.0).mean(), 4.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(10, 2.0, 3.0).modes(), vec![3]);
+        assert_eq!(new!(10, 0.5, 0.5).modes(), vec![0, 10]);
+        assert_eq!(new!(10, 0.5, 2.0).modes(), vec![0]);
+        assert_eq!(new!(3, 1.0, 1.0).modes(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(10, 2.0, 3.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(20, 2.0, 3.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x <= 20));
+        let mean = samples.iter().fold(0.0, |a, &b| a + b as f64) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+        let variance = samples.iter().fold(0.0, |a, &b| a + (b as f64 - mean).powi(2)) /
+                       (n - 1) as f64;
+        assert!((variance / d.variance() - 1.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(10, 2.0, 3.0).support(), (0, 10));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(BetaBinomial::try_new(10, 2.0, 3.0).is_ok());
+        assert_eq!(BetaBinomial::try_new(10, 0.0, 3.0).err(), Some("alpha must be positive"));
+        assert_eq!(BetaBinomial::try_new(10, 2.0, -1.0).err(), Some("beta must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(10, 2.0, 3.0).variance(), 6.0, 1e-15);
+    }
+}
@@ -1,5 +1,7 @@
 use distribution;
 use source::Source;
+use std::fmt;
+use std::ops::Range;
 
 /// A binomial distribution.
 #[derive(Clone, Copy)]
@@ -25,6 +27,14 @@ impl Binomial {
         Binomial { n: n, p: p, q: q, np: np, nq: nq, npq: np * q }
     }
 
+    /// Create a binomial distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(n: usize, p: f64) -> Result<Self, &'static str> {
+        must!(0.0 < p && p < 1.0, "p must be in (0, 1)");
+        Ok(Binomial::new(n, p))
+    }
+
     /// Create a binomial distribution with `n` trails and failure probability
     /// `q`.
     ///
@@ -51,6 +61,12 @@ impl Binomial {
     pub fn q(&self) -> f64 { self.q }
 }
 
+impl fmt::Display for Binomial {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Binomial(n={}, p={})", self.n, self.p)
+    }
+}
+
 impl distribution::Discrete for Binomial {
     /// Compute the probability mass function.
     ///
@@ -62,27 +78,75 @@ impl distribution::Discrete for Binomial {
     /// 1. C. Loader, “Fast and Accurate Computation of Binomial Probabilities,”
     ///    2000.
     fn mass(&self, x: usize) -> f64 {
+        self.ln_mass(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability mass function.
+    ///
+    /// The logarithm of the saddle-point expansion is computed directly, which
+    /// stays finite far in the tails where `mass` underflows.
+    fn ln_mass(&self, x: usize) -> f64 {
+        use std::f64::NEG_INFINITY;
         use std::f64::consts::PI;
 
+        if x > self.n {
+            return NEG_INFINITY;
+        }
         if self.p == 0.0 {
-            return if x == 0 { 1.0 } else { 0.0 };
+            return if x == 0 { 0.0 } else { NEG_INFINITY };
         }
         if self.p == 1.0 {
-            return if x == self.n { 1.0 } else { 0.0 };
+            return if x == self.n { 0.0 } else { NEG_INFINITY };
         }
 
         let n = self.n as f64;
         if x == 0 {
-            (n * self.q.ln()).exp()
+            n * self.q.ln()
         } else if x == self.n {
-            (n * self.p.ln()).exp()
+            n * self.p.ln()
         } else {
             let x = x as f64;
             let n_m_x = n - x;
             let ln_c = stirlerr(n) - stirlerr(x) - stirlerr(n_m_x)
                 - ln_d0(x, self.np) - ln_d0(n_m_x, self.nq);
-            ln_c.exp() * (n / (2.0 * PI * x * (n_m_x))).sqrt()
+            ln_c + 0.5 * (n / (2.0 * PI * x * (n_m_x))).ln()
+        }
+    }
+
+    /// Compute the probability mass function at each point of `range`.
+    ///
+    /// The masses are computed by the recurrence
+    /// `p(x) = p(x - 1) (n - x + 1) p / (x q)`, which is restarted from `mass`
+    /// whenever it underflows.
+    fn masses(&self, range: Range<usize>) -> Vec<f64> {
+        let mut masses = Vec::with_capacity(range.len());
+        let ratio = self.p / self.q;
+        let mut p = 0.0;
+        for x in range {
+            p = if x > self.n {
+                0.0
+            } else if p == 0.0 {
+                self.mass(x)
+            } else {
+                p * ratio * (self.n - x + 1) as f64 / x as f64
+            };
+            masses.push(p);
         }
+        masses
+    }
+}
+
+impl distribution::DiscreteSupport for Binomial {
+    type Iter = Range<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (0, self.n as i64)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        0..(self.n + 1)
     }
 }
 
@@ -248,11 +312,33 @@ impl distribution::Modes for Binomial {
     }
 }
 
+impl distribution::Parametric for Binomial {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
 impl distribution::Sample for Binomial {
-    #[inline]
+    /// Draw a sample.
+    ///
+    /// For `n min(p, q) < 30`, the sample is obtained by inverting the
+    /// cumulative distribution function. Otherwise, the BTPE algorithm is
+    /// used, which is an acceptance–rejection method whose expected running
+    /// time does not depend on `n`.
+    ///
+    /// ## References
+    ///
+    /// 1. V. Kachitvichyanukul and B. W. Schmeiser, “Binomial random variate
+    ///    generation,” Communications of the ACM, vol. 31, no. 2, pp. 216–222,
+    ///    1988.
     fn sample<S>(&self, source: &mut S) -> usize where S: Source {
         use distribution::Inverse;
-        self.inverse(source.read::<f64>())
+        if self.n as f64 * self.p.min(self.q) < 30.0 {
+            self.inverse(source.read::<f64>())
+        } else if self.p <= 0.5 {
+            btpe(self, self.p, source)
+        } else {
+            self.n - btpe(self, self.q, source)
+        }
     }
 }
 
@@ -268,6 +354,100 @@ impl distribution::Variance for Binomial {
     fn variance(&self) -> f64 { self.npq }
 }
 
+// See [Kachitvichyanukul, 1988, pp. 218].
+fn btpe<S>(d: &Binomial, p: f64, source: &mut S) -> usize where S: Source {
+    use distribution::Discrete;
+
+    // The mass function of the distribution with success probability p,
+    // which is either d.p or d.q.
+    let ln_mass = |y: usize| if p == d.p { d.ln_mass(y) } else { d.ln_mass(d.n - y) };
+
+    let n = d.n as f64;
+    let q = 1.0 - p;
+    let npq = n * p * q;
+    let f_m = n * p + p;
+    let m = f_m.floor();
+    let p1 = (2.195 * npq.sqrt() - 4.6 * q).floor() + 0.5;
+    let x_m = m + 0.5;
+    let x_l = x_m - p1;
+    let x_r = x_m + p1;
+    let c = 0.134 + 20.5 / (15.3 + m);
+    let a = (f_m - x_l) / (f_m - x_l * p);
+    let lambda_l = a * (1.0 + 0.5 * a);
+    let a = (x_r - f_m) / (x_r * q);
+    let lambda_r = a * (1.0 + 0.5 * a);
+    let p2 = p1 * (1.0 + 2.0 * c);
+    let p3 = p2 + c / lambda_l;
+    let p4 = p3 + c / lambda_r;
+
+    loop {
+        let u = source.read::<f64>() * p4;
+        let mut v = source.read::<f64>();
+        let y;
+        if u <= p1 {
+            // Triangular region.
+            return (x_m - p1 * v + u).floor() as usize;
+        } else if u <= p2 {
+            // Parallelogram region.
+            let x = x_l + (u - p1) / c;
+            v = v * c + 1.0 - (m - x + 0.5).abs() / p1;
+            if v > 1.0 {
+                continue;
+            }
+            y = x.floor();
+        } else if u <= p3 {
+            // Left exponential tail.
+            y = (x_l + v.ln() / lambda_l).floor();
+            if y < 0.0 {
+                continue;
+            }
+            v *= (u - p2) * lambda_l;
+        } else {
+            // Right exponential tail.
+            y = (x_r - v.ln() / lambda_r).floor();
+            if y > n {
+                continue;
+            }
+            v *= (u - p3) * lambda_r;
+        }
+
+        let k = (y - m).abs();
+        if k <= 20.0 || k >= 0.5 * npq - 1.0 {
+            // Evaluate the ratio of the masses at y and m recursively.
+            let s = p / q;
+            let a = s * (n + 1.0);
+            let mut f = 1.0;
+            if m < y {
+                let mut i = m + 1.0;
+                while i <= y {
+                    f *= a / i - s;
+                    i += 1.0;
+                }
+            } else if m > y {
+                let mut i = y + 1.0;
+                while i <= m {
+                    f /= a / i - s;
+                    i += 1.0;
+                }
+            }
+            if v <= f {
+                return y as usize;
+            }
+        } else {
+            // Squeeze using the bounds on the logarithm of the ratio.
+            let rho = (k / npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / npq + 0.5);
+            let t = -k * k / (2.0 * npq);
+            let alpha = v.ln();
+            if alpha < t - rho {
+                return y as usize;
+            }
+            if alpha <= t + rho && alpha <= ln_mass(y as usize) - ln_mass(m as usize) {
+                return y as usize;
+            }
+        }
+    }
+}
+
 // See [Moorhead, 2013, pp. 7].
 fn approximate_by_normal(p: f64, np: f64, v: f64, u: f64) -> f64 {
     use distribution::gaussian;
@@ -325,7 +505,7 @@ fn stirlerr(n: f64) -> f64 {
         2.079067210376509311e-02, 1.664469118982119216e-02,
         1.387612882307074800e-02, 1.189670994589177010e-02,
         1.041126526197209650e-02, 9.255462182712732918e-03,
-        8.330563433362871256e-03, 7.757367548795184079e-03,
+        8.330563433362871256e-03, 7.573675487951840590e-03,
         6.942840107209529866e-03, 6.408994188004207068e-03,
         5.951370112758847736e-03, 5.554733551962801371e-03,
     ];
@@ -377,6 +557,11 @@ mod tests {
         ($n:expr, $p:expr) => (Binomial::new($n, $p));
     }
 
+    #[test]
+    fn display() {
+        assert_eq!(new!(16, 0.25).to_string(), "Binomial(n=16, p=0.25)");
+    }
+
     #[test]
     fn distribution() {
         let d = new!(16, 0.75);
@@ -396,7 +581,7 @@ mod tests {
 
     #[test]
     fn entropy() {
-        assert_eq!(new!(16, 0.25).entropy(), 1.9588018945068573);
+        assert::close(new!(16, 0.25).entropy(), 1.9588258531430701, 1e-15);
         assert_eq!(new!(10_000_000, 0.5).entropy(), 8.784839178123887);
     }
 
@@ -417,11 +602,35 @@ mod tests {
         assert_eq!(new!(1_000_000_000, 6.66e-9).inverse(0.8), 8);
     }
 
+    #[test]
+    fn iter_support() {
+        let d = new!(10, 0.25);
+        assert_eq!(d.iter_support().collect::<Vec<_>>(), (0..11).collect::<Vec<_>>());
+        assert::close(d.iter_support().fold(0.0, |sum, x| sum + d.mass(x)), 1.0, 1e-14);
+    }
+
     #[test]
     fn kurtosis() {
         assert_eq!(new!(16, 0.25).kurtosis(), -0.041666666666666664);
     }
 
+    #[test]
+    fn ln_mass() {
+        use std::f64::NEG_INFINITY;
+
+        let d = new!(16, 0.25);
+        for x in 0..17 {
+            assert::close(d.ln_mass(x), d.mass(x).ln(), 1e-14);
+        }
+        assert_eq!(d.ln_mass(17), NEG_INFINITY);
+
+        let d = new!(1_000_000, 0.3);
+        assert_eq!(d.mass(400_000), 0.0);
+        assert::close(d.ln_mass(0), -356674.94393873238, 1e-9);
+        assert::close(d.ln_mass(400_000), -22589.534220255646, 1e-10);
+        assert::close(d.ln_mass(1_000_000), -1203972.8043259359, 1e-8);
+    }
+
     #[test]
     fn mass() {
         let d = new!(16, 0.25);
@@ -432,6 +641,30 @@ mod tests {
         ];
 
         assert::close(&(0..9).map(|i| d.mass(2 * i)).collect::<Vec<_>>(), &p, 1e-14);
+
+        let d = new!(1000, 0.3);
+        for x in 0..1001 {
+            use special::Gamma;
+            let (n, p, k) = (1000.0, 0.3f64, x as f64);
+            let ln_p = (n + 1.0).ln_gamma().0 - Gamma::ln_gamma(k + 1.0).0 -
+                Gamma::ln_gamma(n - k + 1.0).0 + k * p.ln() + (n - k) * (1.0 - p).ln();
+            assert!((d.mass(x) - ln_p.exp()).abs() <= 1e-11 * ln_p.exp());
+        }
+
+        let d = new!(10_000_000, 0.3);
+        let x = vec![2_995_000, 3_000_000, 3_001_000];
+        let p = vec![7.1466053543621848e-07, 2.7529631924020769e-04, 2.1695125838732977e-04];
+        assert!(x.iter().zip(&p).all(|(&x, &p)| (d.mass(x) - p).abs() <= 1e-12 * p));
+    }
+
+    #[test]
+    fn masses() {
+        let d = new!(16, 0.25);
+        assert::close(&d.masses(0..20), &(0..20).map(|x| d.mass(x)).collect::<Vec<_>>(), 1e-15);
+
+        let d = new!(2000, 0.3);
+        let p = d.masses(0..2001);
+        assert!(p.iter().zip(0..).all(|(&p, x)| (p - d.mass(x)).abs() <= 1e-12 * d.mass(x)));
     }
 
     #[test]
@@ -455,11 +688,53 @@ mod tests {
         assert_eq!(new!(39, 0.1).modes(), vec![3, 4]);
     }
 
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(10, 0.25).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let m = 100000;
+        for &(n, p) in &[(10, 0.3), (1_000_000, 0.3), (1000, 0.9), (100, 0.5)] {
+            let d = new!(n, p);
+            let x = Independent(&d, &mut source).take(m).map(|x| x as f64).collect::<Vec<_>>();
+            assert!(x.iter().all(|&x| x <= n as f64));
+            let mean = x.iter().fold(0.0, |sum, &x| sum + x) / m as f64;
+            let variance = x.iter().fold(0.0, |sum, &x| sum + (x - mean).powi(2)) / m as f64;
+            assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / m as f64).sqrt());
+            assert!((variance - d.variance()).abs() < 5.0 * d.variance() * (2.0 / m as f64).sqrt());
+        }
+
+        let d = new!(200, 0.4);
+        let mut counts = vec![0; 201];
+        for x in Independent(&d, &mut source).take(m) {
+            counts[x] += 1;
+        }
+        let (chi2, k) = (50..111).fold((0.0, 0), |(sum, k), x| {
+            let expected = m as f64 * d.mass(x);
+            (sum + (counts[x] as f64 - expected).powi(2) / expected, k + 1)
+        });
+        assert!(chi2 < Chisquared::new(k).inverse(0.999));
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(16, 0.25).skewness(), 0.2886751345948129);
     }
 
+    #[test]
+    fn support() {
+        assert_eq!(new!(10, 0.25).support(), (0, 10));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Binomial::try_new(16, 0.25).is_ok());
+        assert_eq!(Binomial::try_new(16, 0.0).err(), Some("p must be in (0, 1)"));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(16, 0.25).variance(), 3.0);
@@ -1,12 +1,24 @@
 use distribution;
 use source::Source;
+use std::fmt;
+use std::ops::Range;
 
 /// A categorical distribution.
+///
+/// Sampling is performed in constant time by the alias method.
+///
+/// ## References
+///
+/// 1. M. D. Vose, “A linear algorithm for generating random numbers with a
+///    given distribution,” IEEE Transactions on Software Engineering, vol. 17,
+///    no. 9, pp. 972–975, 1991.
 #[derive(Clone)]
 pub struct Categorical {
     k: usize,
     p: Vec<f64>,
     cumsum: Vec<f64>,
+    prob: Vec<f64>,
+    alias: Vec<usize>,
 }
 
 impl Categorical {
@@ -19,14 +31,92 @@ impl Categorical {
             p.iter().all(|&p| p >= 0.0 && p <= 1.0) &&
                 (p.iter().fold(0.0, |sum, &p| sum + p) - 1.0).abs() < EPSILON
         });
+        Categorical::build(p.to_vec())
+    }
+
+    /// Create a categorical distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(p: &[f64]) -> Result<Self, &'static str> {
+        must!(!p.is_empty(), "p must be nonempty");
+        must!(p.iter().all(|&p| p >= 0.0 && p <= 1.0), "p must contain probabilities");
+        must!((p.iter().fold(0.0, |sum, &p| sum + p) - 1.0).abs() < 1e-12, "p must sum up to one");
+        Ok(Categorical::new(p))
+    }
+
+    /// Create a categorical distribution with event probabilities proportional
+    /// to `weights`.
+    ///
+    /// It should hold that `weights[i] >= 0` and `sum(weights) > 0`.
+    pub fn from_weights(weights: &[f64]) -> Self {
+        should!(weights.iter().all(|&w| w >= 0.0 && w.is_finite()));
+        let sum = weights.iter().fold(0.0, |sum, &w| sum + w);
+        should!(sum > 0.0);
+        Categorical::build(weights.iter().map(|&w| w / sum).collect())
+    }
+
+    /// Create a categorical distribution with event probabilities proportional
+    /// to `weights` or return an error if the weights are invalid.
+    #[inline]
+    pub fn try_from_weights(weights: &[f64]) -> Result<Self, &'static str> {
+        must!(!weights.is_empty(), "weights must be nonempty");
+        must!(weights.iter().all(|&w| w >= 0.0 && w.is_finite()),
+              "weights must be nonnegative and finite");
+        must!(weights.iter().any(|&w| w > 0.0), "weights must not all be zero");
+        Ok(Categorical::from_weights(weights))
+    }
+
+    /// Create a categorical distribution with event probabilities proportional
+    /// to `exp(log_weights)`.
+    ///
+    /// The weights are normalized in the log domain relative to the largest
+    /// one, which avoids overflow for logits of large magnitude. It should
+    /// hold that at least one of `log_weights` is finite and none is NaN or
+    /// positive infinity.
+    pub fn from_log_weights(log_weights: &[f64]) -> Self {
+        let max = log_weights.iter().fold(::std::f64::NEG_INFINITY, |max, &w| max.max(w));
+        should!(max.is_finite() && log_weights.iter().all(|w| !w.is_nan()));
+        let weights = log_weights.iter().map(|&w| (w - max).exp()).collect::<Vec<_>>();
+        Categorical::from_weights(&weights)
+    }
 
+    /// Draw a sample from the categorical distribution with event
+    /// probabilities proportional to `exp(log_weights)` without constructing
+    /// it.
+    ///
+    /// The sample is obtained by the Gumbel-max trick: standard Gumbel noise
+    /// is added to each log-weight, and the index of the largest result is
+    /// returned. This takes `O(k)` time per sample, in contrast to the
+    /// constant time of `sample`, but it requires no preprocessing, which is
+    /// advantageous when the log-weights change between samples. The same
+    /// requirements as for `from_log_weights` apply.
+    pub fn sample_log_weights<S>(log_weights: &[f64], source: &mut S) -> usize where S: Source {
+        use distribution::{Gumbel, Sample};
+        should!(log_weights.iter().any(|w| w.is_finite()) &&
+                log_weights.iter().all(|w| !w.is_nan()));
+        let gumbel = Gumbel::new(0.0, 1.0);
+        let mut best = (0, ::std::f64::NEG_INFINITY);
+        for (i, &w) in log_weights.iter().enumerate() {
+            if w == ::std::f64::NEG_INFINITY {
+                continue;
+            }
+            let value = w + gumbel.sample(source);
+            if value > best.1 {
+                best = (i, value);
+            }
+        }
+        best.0
+    }
+
+    fn build(p: Vec<f64>) -> Self {
         let k = p.len();
-        let mut cumsum = p.to_vec();
+        let mut cumsum = p.clone();
         for i in 1..(k - 1) {
             cumsum[i] += cumsum[i - 1];
         }
         cumsum[k - 1] = 1.0;
-        Categorical { k: k, p: p.to_vec(), cumsum: cumsum }
+        let (prob, alias) = alias(&p);
+        Categorical { k: k, p: p, cumsum: cumsum, prob: prob, alias: alias }
     }
 
     /// Return the number of categories.
@@ -38,12 +128,38 @@ impl Categorical {
     pub fn p(&self) -> &[f64] { &self.p }
 }
 
+impl fmt::Display for Categorical {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Categorical(p={:?})", self.p)
+    }
+}
+
 impl distribution::Discrete for Categorical {
     #[inline]
     fn mass(&self, x: usize) -> f64 {
         should!(x < self.k);
         self.p[x]
     }
+
+    #[inline]
+    fn ln_mass(&self, x: usize) -> f64 {
+        should!(x < self.k);
+        self.p[x].ln()
+    }
+}
+
+impl distribution::DiscreteSupport for Categorical {
+    type Iter = Range<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (0, self.k as i64 - 1)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        0..self.k
+    }
 }
 
 impl distribution::Distribution for Categorical {
@@ -129,11 +245,21 @@ impl distribution::Modes for Categorical {
     }
 }
 
+impl distribution::Parametric for Categorical {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { self.k - 1 }
+}
+
 impl distribution::Sample for Categorical {
+    /// Draw a sample.
+    ///
+    /// A category is chosen uniformly at random, and it is then either kept or
+    /// replaced with its alias according to the probability table.
     #[inline]
     fn sample<S>(&self, source: &mut S) -> usize where S: Source {
-        use distribution::Inverse;
-        self.inverse(source.read::<f64>())
+        let i = (source.read::<f64>() * self.k as f64) as usize;
+        let i = if i < self.k { i } else { self.k - 1 };
+        if source.read::<f64>() < self.prob[i] { i } else { self.alias[i] }
     }
 }
 
@@ -158,15 +284,60 @@ impl distribution::Variance for Categorical {
     }
 }
 
+fn alias(p: &[f64]) -> (Vec<f64>, Vec<usize>) {
+    let k = p.len();
+    let mut prob = p.iter().map(|&p| p * k as f64).collect::<Vec<_>>();
+    let mut alias = (0..k).collect::<Vec<_>>();
+    let (mut small, mut large): (Vec<_>, Vec<_>) = (0..k).partition(|&i| prob[i] < 1.0);
+    while let (Some(&i), Some(&j)) = (small.last(), large.last()) {
+        small.pop();
+        alias[i] = j;
+        prob[j] -= 1.0 - prob[i];
+        if prob[j] < 1.0 {
+            large.pop();
+            small.push(j);
+        }
+    }
+    // The remaining categories are full up to rounding errors, except for
+    // those with zero probability, which have to be redirected.
+    let j = (0..k).fold(0, |j, i| if p[i] > p[j] { i } else { j });
+    for i in small.into_iter().chain(large) {
+        if p[i] > 0.0 {
+            prob[i] = 1.0;
+        } else {
+            prob[i] = 0.0;
+            alias[i] = j;
+        }
+    }
+    (prob, alias)
+}
+
 #[cfg(test)]
 mod tests {
+    use assert;
     use prelude::*;
 
     macro_rules! new(
         (equal $k:expr) => { Categorical::new(&[1.0 / $k as f64; $k]) };
-        ($p:expr) => { Categorical::new(&$p); }
+        ($p:expr) => { Categorical::new(&$p) }
     );
 
+    #[test]
+    fn alias() {
+        let p = [0.0, 0.1, 0.4, 0.0, 0.05, 0.25, 0.2];
+        let (prob, alias) = super::alias(&p);
+        let mut q = prob.iter().map(|prob| prob / p.len() as f64).collect::<Vec<_>>();
+        for (&prob, &j) in prob.iter().zip(&alias) {
+            q[j] += (1.0 - prob) / p.len() as f64;
+        }
+        assert::close(&q, &p.to_vec(), 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!([0.25, 0.75]).to_string(), "Categorical(p=[0.25, 0.75])");
+    }
+
     #[test]
     fn distribution() {
         let d = new!([0.0, 0.75, 0.25, 0.0]);
@@ -195,6 +366,26 @@ mod tests {
         assert_eq!(new!([0.1, 0.2, 0.3, 0.4]).entropy(), 1.2798542258336676);
     }
 
+    #[test]
+    fn from_weights() {
+        let d = Categorical::from_weights(&[2.0, 2.0]);
+        assert_eq!(d.p(), new!(equal 2).p());
+        assert_eq!((d.mass(0), d.mass(1)), (0.5, 0.5));
+
+        let d = Categorical::from_weights(&[0.0, 3.0, 1.0]);
+        assert_eq!(d.p(), &[0.0, 0.75, 0.25]);
+        assert_eq!(d.distribution(1.0), 0.75);
+    }
+
+    #[test]
+    fn from_log_weights() {
+        let d = Categorical::from_log_weights(&[1000.0, 1000.0 + 3f64.ln()]);
+        assert::close(d.p(), &[0.25, 0.75], 1e-12);
+
+        let d = Categorical::from_log_weights(&[-1000.0, ::std::f64::NEG_INFINITY, -1000.0]);
+        assert_eq!(d.p(), &[0.5, 0.0, 0.5]);
+    }
+
     #[test]
     fn inverse() {
         let d = new!([0.0, 0.75, 0.25, 0.0]);
@@ -206,12 +397,27 @@ mod tests {
         assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &vec![0, 1, 2, 2]);
     }
 
+    #[test]
+    fn iter_support() {
+        let d = new!([0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(d.iter_support().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert::close(d.iter_support().fold(0.0, |sum, x| sum + d.mass(x)), 1.0, 1e-14);
+    }
+
     #[test]
     fn kurtosis() {
         assert_eq!(new!(equal 2).kurtosis(), -2.0);
         assert_eq!(new!([0.1, 0.2, 0.3, 0.4]).kurtosis(), -0.7999999999999998);
     }
 
+    #[test]
+    fn ln_mass() {
+        let d = new!([0.0, 0.75, 0.25, 0.0]);
+        for x in 0..4 {
+            assert_eq!(d.ln_mass(x), d.mass(x).ln());
+        }
+    }
+
     #[test]
     fn mass() {
         let p = [0.0, 0.75, 0.25, 0.0];
@@ -246,6 +452,11 @@ mod tests {
         assert_eq!(new!([1.0 / 6.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 6.0]).modes(), vec![1, 2]);
     }
 
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!([0.1, 0.2, 0.3, 0.4]).num_parameters(), 3);
+    }
+
     #[test]
     fn sample() {
         let mut source = source::default();
@@ -255,6 +466,37 @@ mod tests {
 
         let p = (0..11).map(|i| if i % 2 != 0 { 0.2 } else { 0.0 }).collect::<Vec<_>>();
         assert!(Independent(&new!(p), &mut source).take(1000).all(|x| x % 2 != 0));
+
+        let k = 100;
+        let n = 100000;
+        let p = (0..k).map(|i| (i + 1) as f64 / (k * (k + 1) / 2) as f64).collect::<Vec<_>>();
+        let mut counts = vec![0; k];
+        for x in Independent(&new!(p), &mut source).take(n) {
+            counts[x] += 1;
+        }
+        let chi2 = counts.iter().zip(&p).fold(0.0, |sum, (&count, &p)| {
+            sum + (count as f64 - n as f64 * p).powi(2) / (n as f64 * p)
+        });
+        assert!(chi2 < Chisquared::new(k as u64 - 1).inverse(0.999));
+    }
+
+    #[test]
+    fn sample_log_weights() {
+        use std::f64::NEG_INFINITY;
+
+        let mut source = source::default();
+        let n = 100000;
+        let log_weights = [-800.0, -800.0 + 2f64.ln(), NEG_INFINITY, -800.0 + 5f64.ln()];
+        let d = Categorical::from_log_weights(&log_weights);
+        let mut counts = vec![0; 4];
+        for _ in 0..n {
+            counts[Categorical::sample_log_weights(&log_weights, &mut source)] += 1;
+        }
+        assert_eq!(counts[2], 0);
+        let chi2 = [0, 1, 3].iter().fold(0.0, |sum, &i| {
+            sum + (counts[i] as f64 - n as f64 * d.p()[i]).powi(2) / (n as f64 * d.p()[i])
+        });
+        assert!(chi2 < Chisquared::new(2).inverse(0.999));
     }
 
     #[test]
@@ -264,6 +506,27 @@ mod tests {
         assert_eq!(new!([0.1, 0.2, 0.3, 0.4]).skewness(), -0.6);
     }
 
+    #[test]
+    fn support() {
+        assert_eq!(new!([0.1, 0.2, 0.3, 0.4]).support(), (0, 3));
+    }
+
+    #[test]
+    fn try_from_weights() {
+        assert!(Categorical::try_from_weights(&[1.0, 3.0]).is_ok());
+        assert_eq!(Categorical::try_from_weights(&[0.0, 0.0]).err(),
+                   Some("weights must not all be zero"));
+        assert_eq!(Categorical::try_from_weights(&[1.0, -1.0]).err(),
+                   Some("weights must be nonnegative and finite"));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Categorical::try_new(&[0.25, 0.75]).is_ok());
+        assert_eq!(Categorical::try_new(&[]).err(), Some("p must be nonempty"));
+        assert_eq!(Categorical::try_new(&[0.25, 0.5]).err(), Some("p must sum up to one"));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(equal 3).variance(), 2.0 / 3.0);
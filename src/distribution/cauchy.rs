@@ -0,0 +1,313 @@
+use distribution;
+use source::Source;
+use std::f64::consts::PI;
+use std::fmt;
+
+/// A Cauchy distribution.
+///
+/// The distribution has no moments, and the traits computing them are not
+/// implemented.
+#[derive(Clone, Copy)]
+pub struct Cauchy {
+    x0: f64,
+    gamma: f64,
+}
+
+impl Cauchy {
+    /// Create a Cauchy distribution with location `x0` and scale `gamma`.
+    ///
+    /// It should hold that `gamma > 0`.
+    #[inline]
+    pub fn new(x0: f64, gamma: f64) -> Self {
+        should!(gamma > 0.0);
+        Cauchy { x0: x0, gamma: gamma }
+    }
+
+    /// Create a Cauchy distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(x0: f64, gamma: f64) -> Result<Self, &'static str> {
+        must!(gamma > 0.0, "gamma must be positive");
+        Ok(Cauchy::new(x0, gamma))
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn x0(&self) -> f64 { self.x0 }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn gamma(&self) -> f64 { self.gamma }
+}
+
+impl Default for Cauchy {
+    #[inline]
+    fn default() -> Self {
+        Cauchy::new(0.0, 1.0)
+    }
+}
+
+impl fmt::Display for Cauchy {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Cauchy(x₀={}, γ={})", self.x0, self.gamma)
+    }
+}
+
+impl distribution::CharacteristicFunction for Cauchy {
+    fn characteristic(&self, t: f64) -> (f64, f64) {
+        let modulus = (-self.gamma * t.abs()).exp();
+        let argument = self.x0 * t;
+        (modulus * argument.cos(), modulus * argument.sin())
+    }
+}
+
+impl distribution::Continuous for Cauchy {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        let z = (x - self.x0) / self.gamma;
+        1.0 / (PI * self.gamma * (1.0 + z * z))
+    }
+}
+
+impl distribution::Distribution for Cauchy {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is evaluated as `atan2(1, -z) / π`, which equals
+    /// `1 / 2 + atan(z) / π` but keeps the relative accuracy in the left tail.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        1.0f64.atan2((self.x0 - x) / self.gamma) / PI
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        1.0f64.atan2((x - self.x0) / self.gamma) / PI
+    }
+}
+
+impl distribution::Entropy for Cauchy {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        (4.0 * PI * self.gamma).ln()
+    }
+}
+
+impl distribution::Inverse for Cauchy {
+    /// Compute the inverse of the cumulative distribution function.
+    ///
+    /// In the tails, `tan(π (p - 1 / 2))` is evaluated as `-1 / tan(π p)` and
+    /// `1 / tan(π (1 - p))`, respectively, which keeps the accuracy for `p`
+    /// close to zero and one.
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        if p < 0.25 {
+            self.x0 - self.gamma / (PI * p).tan()
+        } else if p > 0.75 {
+            self.x0 + self.gamma / (PI * (1.0 - p)).tan()
+        } else {
+            self.x0 + self.gamma * (PI * (p - 0.5)).tan()
+        }
+    }
+}
+
+impl distribution::Median for Cauchy {
+    #[inline]
+    fn median(&self) -> f64 { self.x0 }
+}
+
+impl distribution::Modes for Cauchy {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.x0]
+    }
+}
+
+impl distribution::Parametric for Cauchy {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for Cauchy {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by inverting the cumulative distribution
+    /// function at a uniform variate. A variate equal to zero or one, which
+    /// would map to an infinite value, is rejected and another one is read.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::Inverse;
+        loop {
+            let u = source.read::<f64>();
+            if 0.0 < u && u < 1.0 {
+                return self.inverse(u);
+            }
+        }
+    }
+}
+
+impl distribution::Support for Cauchy {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        (NEG_INFINITY, INFINITY)
+    }
+}
+
+impl distribution::Symmetric for Cauchy {}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($x0:expr, $gamma:expr) => (Cauchy::new($x0, $gamma));
+    );
+
+    #[test]
+    fn characteristic() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-1.5, 0.0, 0.5, 2.0];
+        let phi = vec![
+            (3.5217978955813041e-03, -4.9662351094657588e-02), (1.0, 0.0),
+            (3.2284458245003300e-01, 1.7637079922503196e-01),
+            (-7.6219951828865474e-03, 1.6654363312194380e-02),
+        ];
+        for (&t, &(re, im)) in t.iter().zip(&phi) {
+            let (x, y) = d.characteristic(t);
+            assert::close(x, re, 1e-15);
+            assert::close(y, im, 1e-15);
+        }
+    }
+
+    #[test]
+    fn complementary() {
+        let d = new!(1.0, 2.0);
+        for &x in &[-3.0, -1.0, 0.0, 1.0, 2.5, 5.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+        assert::close(d.complementary(1e10), 6.3661977243124332e-11, 1e-15 * 6.4e-11);
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-3.0, -1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 5.0];
+        let p = vec![
+            3.1830988618379068e-02, 7.9577471545947673e-02, 1.2732395447351627e-01,
+            1.4979288761590148e-01, 1.5915494309189535e-01, 1.4979288761590148e-01,
+            1.2732395447351627e-01, 7.9577471545947673e-02, 3.1830988618379068e-02,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 2.0).to_string(), "Cauchy(x₀=1, γ=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+
+        let d = new!(1.0, 2.0);
+        let x = vec![-3.0, -1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 5.0];
+        let p = vec![
+            1.4758361765043326e-01, 2.5000000000000000e-01, 3.5241638234956674e-01,
+            4.2202086962263069e-01, 5.0000000000000000e-01, 5.7797913037736937e-01,
+            6.4758361765043326e-01, 7.5000000000000000e-01, 8.5241638234956674e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        assert::close(d.distribution(-1e10), 6.3661977230391941e-11, 1e-15 * 6.4e-11);
+        assert_eq!(d.distribution(NEG_INFINITY), 0.0);
+        assert_eq!(d.distribution(INFINITY), 1.0);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 2.0).entropy(), 3.2241714275292361, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+
+        let d = new!(1.0, 2.0);
+        let p = vec![0.0, 0.05, 0.1, 0.25, 0.5, 0.75, 0.9, 0.95, 1.0];
+        let x = vec![
+            NEG_INFINITY, -1.1627503029350086e+01, -5.1553670743505062e+00,
+            -1.0000000000000000e+00, 1.0000000000000000e+00, 3.0000000000000000e+00,
+            7.1553670743505080e+00, 1.3627503029350075e+01, INFINITY,
+        ];
+
+        assert::close(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x, 1e-14);
+        assert::close(d.inverse(1e-10), -6.3661977226758137e+09, 1e-15 * 6.4e9);
+        for &x in &[-1e6, -10.0, -1.0, 0.0, 2.0, 10.0, 1e3] {
+            assert::close(d.inverse(d.distribution(x)), x, 1e-12 * (1.0 + x.abs()));
+        }
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(1.0, 2.0).median(), 1.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 2.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        use source::Source;
+
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(1.0, 2.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        for &x in &[-1.0, 1.0, 3.0] {
+            let p = d.distribution(x);
+            let frequency = samples.iter().filter(|&&y| y <= x).count() as f64 / n as f64;
+            assert!((frequency - p).abs() < 5.0 * (p * (1.0 - p) / n as f64).sqrt());
+        }
+
+        struct Sequence(Vec<u64>);
+        impl Source for Sequence {
+            fn read_u64(&mut self) -> u64 { self.0.remove(0) }
+        }
+
+        let mut source = Sequence(vec![0, ::std::u64::MAX, 1 << 63]);
+        assert_eq!(d.sample(&mut source), 1.0);
+        assert!(source.0.is_empty());
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        assert_eq!(new!(1.0, 2.0).support(), (NEG_INFINITY, INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Cauchy::try_new(1.0, 2.0).is_ok());
+        assert_eq!(Cauchy::try_new(1.0, 0.0).err(), Some("gamma must be positive"));
+    }
+
+    #[test]
+    fn two_sided_p() {
+        let d = new!(1.0, 2.0);
+        assert::close(d.two_sided_p(4.0), 3.7433408362199766e-01, 1e-15);
+        assert::close(d.two_sided_p(-2.0), 3.7433408362199766e-01, 1e-15);
+        assert_eq!(d.two_sided_p(1.0), 1.0);
+    }
+}
@@ -0,0 +1,281 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A chi distribution.
+///
+/// The distribution is that of the square root of a chi-squared variate. The
+/// Maxwell–Boltzmann distribution of speeds is the special case `k = 3`.
+#[derive(Clone, Copy)]
+pub struct Chi {
+    k: u64,
+    ln_norm: f64,
+}
+
+impl Chi {
+    /// Create a chi distribution with `k` degrees of freedom.
+    ///
+    /// It should hold that `k > 0`.
+    #[inline]
+    pub fn new(k: u64) -> Self {
+        use special::Gamma;
+        use std::f64::consts::LN_2;
+        should!(k > 0);
+        let half = 0.5 * k as f64;
+        Chi { k: k, ln_norm: (half - 1.0) * LN_2 + Gamma::ln_gamma(half).0 }
+    }
+
+    /// Create a chi distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(k: u64) -> Result<Self, &'static str> {
+        must!(k > 0, "k must be positive");
+        Ok(Chi::new(k))
+    }
+
+    /// Return the degrees of freedom.
+    #[inline(always)]
+    pub fn k(&self) -> u64 { self.k }
+}
+
+impl fmt::Display for Chi {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Chi(k={})", self.k)
+    }
+}
+
+impl distribution::Continuous for Chi {
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if self.k == 1 { (-self.ln_norm).exp() } else { 0.0 }
+        } else {
+            ((self.k as f64 - 1.0) * x.ln() - 0.5 * x * x - self.ln_norm).exp()
+        }
+    }
+}
+
+impl distribution::Distribution for Chi {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x <= 0.0 {
+            0.0
+        } else {
+            (0.5 * x * x).inc_gamma(0.5 * self.k as f64)
+        }
+    }
+}
+
+impl distribution::Entropy for Chi {
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        use std::f64::consts::LN_2;
+        let k = self.k as f64;
+        Gamma::ln_gamma(0.5 * k).0 + 0.5 * (k - LN_2 - (k - 1.0) * (0.5 * k).digamma())
+    }
+}
+
+impl distribution::Kurtosis for Chi {
+    fn kurtosis(&self) -> f64 {
+        use distribution::{Mean, Skewness, Variance};
+        let (mean, variance) = (self.mean(), self.variance());
+        2.0 * (1.0 - mean * variance.sqrt() * self.skewness() - variance) / variance
+    }
+}
+
+impl distribution::Mean for Chi {
+    fn mean(&self) -> f64 {
+        use special::Gamma;
+        use std::f64::consts::SQRT_2;
+        let half = 0.5 * self.k as f64;
+        SQRT_2 * (Gamma::ln_gamma(half + 0.5).0 - Gamma::ln_gamma(half).0).exp()
+    }
+}
+
+impl distribution::Modes for Chi {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![(self.k as f64 - 1.0).sqrt()]
+    }
+}
+
+impl distribution::Parametric for Chi {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for Chi {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as the square root of a chi-squared variate.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::chisquared;
+        chisquared::sample(self.k as f64, source).sqrt()
+    }
+}
+
+impl distribution::Skewness for Chi {
+    fn skewness(&self) -> f64 {
+        use distribution::{Mean, Variance};
+        let (mean, variance) = (self.mean(), self.variance());
+        mean * (1.0 - 2.0 * variance) / (variance * variance.sqrt())
+    }
+}
+
+impl distribution::Support for Chi {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for Chi {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use distribution::Mean;
+        let mean = self.mean();
+        self.k as f64 - mean * mean
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($k:expr) => (Chi::new($k));
+    );
+
+    #[test]
+    fn density() {
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0];
+
+        let d = new!(3);
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 4.8333514600356148e-02,
+            1.7603266338214973e-01, 4.8394144903828668e-01, 5.8282918049651278e-01,
+            4.3192773210550439e-01, 7.9773271414884134e-02, 4.2825672244763309e-03,
+            7.4335975736714881e-05,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1);
+        let p = vec![
+            0.0000000000000000e+00, 7.9788456080286536e-01, 7.7333623360569836e-01,
+            7.0413065352859894e-01, 4.8394144903828668e-01, 2.5903519133178343e-01,
+            1.0798193302637610e-01, 8.8636968238760151e-03, 2.6766045152977068e-04,
+            2.9734390294685954e-06,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(3).to_string(), "Chi(k=3)");
+    }
+
+    #[test]
+    fn distribution() {
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0];
+
+        let d = new!(3);
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 4.0785929644228452e-03,
+            3.0859595783726729e-02, 1.9874804309879920e-01, 4.7783281046460868e-01,
+            7.3853587005088939e-01, 9.7070911346511179e-01, 9.9886601571021472e-01,
+            9.9998455950170895e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+
+        let d = new!(8);
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 3.8755839433498093e-08,
+            9.2064137445972086e-06, 1.7516225562908237e-03, 2.7627815050724001e-02,
+            1.4287653950145296e-01, 6.5770404416540895e-01, 9.5761988800831599e-01,
+            9.9844544215698894e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(3).entropy(), 0.99615419810620565, 1e-14);
+        assert::close(new!(8).entropy(), 1.0487740394367806, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(3).kurtosis(), 0.10816384281629415, 1e-13);
+        assert::close(new!(8).kurtosis(), 0.013644038253292431, 1e-11);
+    }
+
+    #[test]
+    fn maxwell() {
+        use std::f64::consts::PI;
+        let d = new!(3);
+        assert::close(d.mean(), 2.0 * (2.0 / PI).sqrt(), 1e-15);
+        assert::close(d.variance(), (3.0 * PI - 8.0) / PI, 1e-15);
+        assert::close(d.skewness(), 2.0 * 2f64.sqrt() * (16.0 - 5.0 * PI) /
+                                    (3.0 * PI - 8.0).powf(1.5), 1e-14);
+        assert::close(d.kurtosis(), 4.0 * (-96.0 + 40.0 * PI - 3.0 * PI * PI) /
+                                    (3.0 * PI - 8.0).powi(2), 1e-13);
+        assert_eq!(d.modes(), vec![2f64.sqrt()]);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(1).mean(), 0.79788456080286541, 1e-15);
+        assert::close(new!(8).mean(), 2.7416246753776568, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1).modes(), vec![0.0]);
+        assert_eq!(new!(10).modes(), vec![3.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(3).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(3);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(1).skewness(), 0.99527174643115601, 1e-14);
+        assert::close(new!(8).skewness(), 0.2692088597795097, 1e-13);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(3).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Chi::try_new(3).is_ok());
+        assert_eq!(Chi::try_new(0).err(), Some("k must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(1).variance(), 0.36338022763241867, 1e-15);
+        assert::close(new!(8).variance(), 0.48349413936035796, 1e-14);
+    }
+}
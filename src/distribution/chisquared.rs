@@ -0,0 +1,250 @@
+use distribution::{self, Gamma};
+use source::Source;
+use std::fmt;
+
+/// A chi-squared distribution.
+///
+/// The distribution is the gamma distribution with shape `k / 2` and scale
+/// `2`, and it is implemented as such.
+#[derive(Clone, Copy)]
+pub struct Chisquared {
+    k: u64,
+    gamma: Gamma,
+}
+
+impl Chisquared {
+    /// Create a chi-squared distribution with `k` degrees of freedom.
+    ///
+    /// It should hold that `k > 0`.
+    #[inline]
+    pub fn new(k: u64) -> Self {
+        should!(k > 0);
+        Chisquared { k: k, gamma: Gamma::new(0.5 * k as f64, 2.0) }
+    }
+
+    /// Create a chi-squared distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(k: u64) -> Result<Self, &'static str> {
+        must!(k > 0, "k must be positive");
+        Ok(Chisquared::new(k))
+    }
+
+    /// Return the degrees of freedom.
+    #[inline(always)]
+    pub fn k(&self) -> u64 { self.k }
+}
+
+impl fmt::Display for Chisquared {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Chisquared(k={})", self.k)
+    }
+}
+
+impl distribution::Continuous for Chisquared {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        self.gamma.density(x)
+    }
+
+    #[inline]
+    fn ln_density(&self, x: f64) -> f64 {
+        self.gamma.ln_density(x)
+    }
+}
+
+impl distribution::Distribution for Chisquared {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        self.gamma.distribution(x)
+    }
+}
+
+impl distribution::Entropy for Chisquared {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        self.gamma.entropy()
+    }
+}
+
+impl distribution::Inverse for Chisquared {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        self.gamma.inverse(p)
+    }
+}
+
+impl distribution::Kurtosis for Chisquared {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        12.0 / self.k as f64
+    }
+}
+
+impl distribution::Mean for Chisquared {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.k as f64
+    }
+}
+
+impl distribution::Median for Chisquared {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.gamma.median()
+    }
+}
+
+impl distribution::Modes for Chisquared {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![if self.k > 2 { self.k as f64 - 2.0 } else { 0.0 }]
+    }
+}
+
+impl distribution::Parametric for Chisquared {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for Chisquared {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        sample(self.k as f64, source)
+    }
+}
+
+impl distribution::Skewness for Chisquared {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        (8.0 / self.k as f64).sqrt()
+    }
+}
+
+impl distribution::Support for Chisquared {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for Chisquared {
+    #[inline]
+    fn variance(&self) -> f64 {
+        2.0 * self.k as f64
+    }
+}
+
+/// Draw a sample from the chi-squared distribution with `k` degrees of
+/// freedom, where `k` need not be an integer.
+#[inline]
+pub fn sample<S: Source>(k: f64, source: &mut S) -> f64 {
+    use distribution::gamma;
+    2.0 * gamma::sample(0.5 * k, source)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($k:expr) => (Chisquared::new($k));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(3);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 2.1969564473386119e-01,
+            2.4197072451914334e-01, 2.0755374871029736e-01, 7.3224912809632434e-02,
+            8.5003666025203414e-03,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(3).to_string(), "Chisquared(k=3)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 8.1108588345324140e-02,
+            1.9874804309879920e-01, 4.2759329552912018e-01, 8.2820285570326690e-01,
+            9.8143386453695680e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(3);
+        for &x in &[0.5, 1.0, 2.0, 5.0, 10.0] {
+            assert::close(d.inverse(d.distribution(x)), x, 1e-12);
+        }
+    }
+
+    #[test]
+    fn k() {
+        for k in 1..11 {
+            assert_eq!(new!(k).k(), k);
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(5).mean(), 5.0);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(3).median(), 2.3659738843753383, 1e-12);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1).modes(), vec![0.0]);
+        assert_eq!(new!(5).modes(), vec![3.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(3).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(5);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(3).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Chisquared::try_new(3).is_ok());
+        assert_eq!(Chisquared::try_new(0).err(), Some("k must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(5).variance(), 10.0);
+    }
+}
@@ -0,0 +1,320 @@
+use distribution;
+use source::Source;
+use std::fmt;
+use std::ops::RangeFrom;
+
+/// A Conway–Maxwell–Poisson distribution.
+///
+/// The mass at `k` is `λ^k / (k!)^ν / Z(λ, ν)` where `Z(λ, ν) = Σ_j λ^j /
+/// (j!)^ν` is the normalizing constant. The case `ν = 1` is the Poisson
+/// distribution, and the distribution is over-dispersed for `ν < 1` and
+/// under-dispersed for `ν > 1`.
+#[derive(Clone, Copy)]
+pub struct ComPoisson {
+    lambda: f64,
+    nu: f64,
+    ln_lambda: f64,
+    ln_z: f64,
+    limit: usize,
+}
+
+impl ComPoisson {
+    /// Create a Conway–Maxwell–Poisson distribution with rate parameter
+    /// `lambda` and dispersion parameter `nu`.
+    ///
+    /// The normalizing constant is computed once on creation by summing the
+    /// series until a geometric bound on the remainder falls below `1e-17`
+    /// relative to the partial sum. The terms beyond the truncation point are
+    /// treated as zero by all methods other than `mass` and `ln_mass`. The
+    /// number of terms grows as `λ^(1 / ν)`.
+    ///
+    /// It should hold that `lambda > 0` and `nu > 0`.
+    pub fn new(lambda: f64, nu: f64) -> Self {
+        const TOLERANCE: f64 = 1e-17;
+        should!(lambda > 0.0 && nu > 0.0);
+        let ln_lambda = lambda.ln();
+        let (mut max, mut sum, mut k) = (0.0, 1.0, 0);
+        loop {
+            k += 1;
+            let term = ln_term(ln_lambda, nu, k);
+            if term > max {
+                sum = sum * (max - term).exp() + 1.0;
+                max = term;
+            } else {
+                sum += (term - max).exp();
+            }
+            let ratio = lambda / ((k + 1) as f64).powf(nu);
+            if ratio < 1.0 && (term - max).exp() * ratio / (1.0 - ratio) < TOLERANCE * sum {
+                break;
+            }
+        }
+        ComPoisson { lambda: lambda, nu: nu, ln_lambda: ln_lambda, ln_z: max + sum.ln(), limit: k }
+    }
+
+    /// Create a Conway–Maxwell–Poisson distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(lambda: f64, nu: f64) -> Result<Self, &'static str> {
+        must!(lambda > 0.0 && lambda.is_finite(), "lambda must be positive");
+        must!(nu > 0.0 && nu.is_finite(), "nu must be positive");
+        Ok(ComPoisson::new(lambda, nu))
+    }
+
+    /// Return the rate parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 { self.lambda }
+
+    /// Return the dispersion parameter.
+    #[inline(always)]
+    pub fn nu(&self) -> f64 { self.nu }
+
+    /// Return the natural logarithm of the normalizing constant `Z(λ, ν)`.
+    #[inline(always)]
+    pub fn ln_normalizer(&self) -> f64 { self.ln_z }
+
+    // Compute `Σ_k f(k) p(k)` over the truncated support.
+    fn expectation<F>(&self, f: F) -> f64 where F: Fn(f64) -> f64 {
+        use distribution::Discrete;
+        (0..(self.limit + 1)).rev().fold(0.0, |sum, k| sum + f(k as f64) * self.mass(k))
+    }
+}
+
+impl fmt::Display for ComPoisson {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "ComPoisson(λ={}, ν={})", self.lambda, self.nu)
+    }
+}
+
+impl distribution::Discrete for ComPoisson {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        (ln_term(self.ln_lambda, self.nu, x) - self.ln_z).exp()
+    }
+
+    #[inline]
+    fn ln_mass(&self, x: usize) -> f64 {
+        ln_term(self.ln_lambda, self.nu, x) - self.ln_z
+    }
+}
+
+impl distribution::DiscreteSupport for ComPoisson {
+    type Iter = RangeFrom<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (0, ::std::i64::MAX)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        0..
+    }
+}
+
+impl distribution::Distribution for ComPoisson {
+    type Value = usize;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::Discrete;
+        if x < 0.0 {
+            return 0.0;
+        }
+        let x = (x.floor() as usize).min(self.limit);
+        (0..(x + 1)).rev().fold(0.0, |sum, k| sum + self.mass(k)).min(1.0)
+    }
+
+    fn complementary(&self, x: f64) -> f64 {
+        use distribution::Discrete;
+        if x < 0.0 {
+            return 1.0;
+        }
+        if x >= self.limit as f64 {
+            return 0.0;
+        }
+        let x = x.floor() as usize;
+        ((x + 1)..(self.limit + 1)).rev().fold(0.0, |sum, k| sum + self.mass(k)).min(1.0)
+    }
+}
+
+impl distribution::Mean for ComPoisson {
+    /// Compute the mean by summing the truncated series.
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.expectation(|k| k)
+    }
+}
+
+impl distribution::Parametric for ComPoisson {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for ComPoisson {
+    /// Draw a sample by inversion over the cumulative distribution function.
+    fn sample<S>(&self, source: &mut S) -> usize where S: Source {
+        use distribution::Discrete;
+        let u = source.read::<f64>();
+        let mut sum = 0.0;
+        for k in 0..self.limit {
+            sum += self.mass(k);
+            if u < sum {
+                return k;
+            }
+        }
+        self.limit
+    }
+}
+
+impl distribution::Variance for ComPoisson {
+    /// Compute the variance by summing the truncated series.
+    #[inline]
+    fn variance(&self) -> f64 {
+        use distribution::Mean;
+        let mean = self.mean();
+        self.expectation(|k| (k - mean) * (k - mean))
+    }
+}
+
+// Compute the logarithm of the unnormalized mass `λ^k / (k!)^ν`.
+#[inline]
+fn ln_term(ln_lambda: f64, nu: f64, k: usize) -> f64 {
+    use special::Gamma;
+    k as f64 * ln_lambda - nu * Gamma::ln_gamma(k as f64 + 1.0).0
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($lambda:expr, $nu:expr) => (ComPoisson::new($lambda, $nu));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(2.0, 0.5);
+        for &x in &[-1.0, 0.0, 2.5, 5.0, 10.0, 30.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+        assert_eq!(new!(3.0, 2.0).complementary(1000.0), 0.0);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(2.0, 0.5).to_string(), "ComPoisson(λ=2, ν=0.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 0.5);
+        let p = vec![
+            4.3747173243413900e-02, 1.3124151973024170e-01, 2.5497721116288435e-01,
+            3.9785488067688579e-01, 5.4073255019088717e-01, 6.6852622279090979e-01,
+            7.7286931953302540e-01, 8.5174528667759453e-01, 9.0751901791816669e-01,
+            9.4470150541188147e-01,
+        ];
+        assert::close(&(0..10).map(|x| d.distribution(x as f64)).collect::<Vec<_>>(), &p, 1e-15);
+        assert::close(&(0..10).map(|x| d.distribution(x as f64 + 0.5)).collect::<Vec<_>>(), &p,
+                      1e-15);
+        assert_eq!(d.distribution(-0.5), 0.0);
+        assert::close(d.distribution(1e6), 1.0, 1e-15);
+
+        let (d, e) = (new!(5.0, 1.0), Poisson::new(5.0));
+        for x in 0..20 {
+            assert::close(d.distribution(x as f64), e.distribution(x as f64), 1e-14);
+        }
+    }
+
+    #[test]
+    fn ln_mass() {
+        let d = new!(3.0, 2.0);
+        for x in 0..10 {
+            assert::close(d.ln_mass(x), d.mass(x).ln(), 1e-14);
+        }
+    }
+
+    #[test]
+    fn ln_normalizer() {
+        assert::close(new!(2.0, 0.5).ln_normalizer(), 3.1293282798450424, 1e-14);
+        assert::close(new!(3.0, 2.0).ln_normalizer(), 1.9683698226598292, 1e-15);
+        assert::close(new!(50.0, 1.5).ln_normalizer(), 19.046551676258161, 1e-13);
+        assert::close(new!(5.0, 1.0).ln_normalizer(), 5.0, 1e-14);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(2.0, 0.5);
+        let p = vec![
+            4.3747173243413900e-02, 8.7494346486827801e-02, 1.2373569143264265e-01,
+            1.4287766951400141e-01, 1.4287766951400141e-01, 1.2779367260002258e-01,
+            1.0434309674211567e-01, 7.8875967144569126e-02, 5.5773731240572152e-02,
+            3.7182487493714771e-02,
+        ];
+        assert::close(&(0..10).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(3.0, 2.0);
+        let p = vec![
+            1.3968438102449166e-01, 4.1905314307347502e-01, 3.1428985730510622e-01,
+            1.0476328576836876e-01, 1.9643116081569139e-02, 2.3571739297882969e-03,
+            1.9643116081569141e-04, 1.2026397600960698e-05, 5.6373738754503276e-07,
+            2.0879162501667879e-08,
+        ];
+        assert::close(&(0..10).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let (d, e) = (new!(5.0, 1.0), Poisson::new(5.0));
+        for x in 0..20 {
+            assert::close(d.mass(x), e.mass(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0, 0.5).mean(), 4.5544239321855446, 1e-14);
+        assert::close(new!(3.0, 2.0).mean(), 1.4535485249622122, 1e-15);
+        assert::close(new!(50.0, 1.5).mean(), 13.403623720387332, 1e-13);
+        assert::close(new!(5.0, 1.0).mean(), 5.0, 1e-14);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.0, 0.5).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        for &(lambda, nu) in &[(2.0, 0.5), (3.0, 2.0), (50.0, 1.5)] {
+            let d = new!(lambda, nu);
+            let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+            let mean = samples.iter().fold(0.0, |a, &b| a + b as f64) / n as f64;
+            assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.0, 0.5).support(), (0, ::std::i64::MAX));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(ComPoisson::try_new(2.0, 0.5).is_ok());
+        assert_eq!(ComPoisson::try_new(0.0, 0.5).err(), Some("lambda must be positive"));
+        assert_eq!(ComPoisson::try_new(2.0, -1.0).err(), Some("nu must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0, 0.5).variance(), 7.9215841567020524, 1e-13);
+        assert::close(new!(3.0, 2.0).variance(), 0.88719668558017717, 1e-14);
+        assert::close(new!(50.0, 1.5).variance(), 9.04932508022892, 1e-12);
+        assert::close(new!(5.0, 1.0).variance(), 5.0, 1e-13);
+
+        let d = new!(2.0, 0.5);
+        assert!(d.variance() > d.mean());
+        let d = new!(3.0, 2.0);
+        assert!(d.variance() < d.mean());
+    }
+}
@@ -0,0 +1,191 @@
+use source::Source;
+use std::fmt;
+
+/// A Dirichlet distribution.
+///
+/// The distribution is multivariate, and its outcomes are points of the
+/// standard simplex, that is, vectors with nonnegative components summing up
+/// to one. Since the scalar traits of the module do not apply, the
+/// distribution is operated via the methods defined on the type itself.
+#[derive(Clone)]
+pub struct Dirichlet {
+    alpha: Vec<f64>,
+    sum: f64,
+    ln_beta: f64,
+}
+
+impl Dirichlet {
+    /// Create a Dirichlet distribution with concentration parameters `alpha`.
+    ///
+    /// It should hold that `alpha[i] > 0` and that there are at least two
+    /// parameters.
+    pub fn new(alpha: &[f64]) -> Self {
+        use special::Gamma;
+        should!(alpha.len() > 1 && alpha.iter().all(|&alpha| alpha > 0.0));
+        let sum = alpha.iter().fold(0.0, |sum, &alpha| sum + alpha);
+        let ln_beta = alpha.iter().fold(0.0, |sum, &alpha| sum + Gamma::ln_gamma(alpha).0) -
+            Gamma::ln_gamma(sum).0;
+        Dirichlet { alpha: alpha.to_vec(), sum: sum, ln_beta: ln_beta }
+    }
+
+    /// Create a Dirichlet distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(alpha: &[f64]) -> Result<Self, &'static str> {
+        must!(alpha.len() > 1, "alpha must have at least two elements");
+        must!(alpha.iter().all(|&alpha| alpha > 0.0), "alpha must be positive");
+        Ok(Dirichlet::new(alpha))
+    }
+
+    /// Return the number of components.
+    #[inline(always)]
+    pub fn k(&self) -> usize { self.alpha.len() }
+
+    /// Return the concentration parameters.
+    #[inline(always)]
+    pub fn alpha(&self) -> &[f64] { &self.alpha }
+
+    /// Compute the probability density function.
+    ///
+    /// The density is zero if `x` is not a point of the simplex, that is, if
+    /// its dimension is wrong, if some of its components are negative, or if
+    /// they do not sum up to one.
+    pub fn density(&self, x: &[f64]) -> f64 {
+        const EPSILON: f64 = 1e-12;
+        if x.len() != self.alpha.len() || x.iter().any(|&x| x < 0.0) ||
+           (x.iter().fold(0.0, |sum, &x| sum + x) - 1.0).abs() > EPSILON {
+            return 0.0;
+        }
+        self.alpha.iter().zip(x).fold(-self.ln_beta, |sum, (&alpha, &x)| {
+            if alpha == 1.0 { sum } else { sum + (alpha - 1.0) * x.ln() }
+        }).exp()
+    }
+
+    /// Compute the expected value.
+    pub fn mean(&self) -> Vec<f64> {
+        self.alpha.iter().map(|&alpha| alpha / self.sum).collect()
+    }
+
+    /// Compute the variances of the components.
+    pub fn variance(&self) -> Vec<f64> {
+        let norm = self.sum * self.sum * (self.sum + 1.0);
+        self.alpha.iter().map(|&alpha| alpha * (self.sum - alpha) / norm).collect()
+    }
+
+    /// Compute the covariance matrix.
+    ///
+    /// The matrix is returned as a vector of rows.
+    pub fn covariance(&self) -> Vec<Vec<f64>> {
+        let norm = self.sum * self.sum * (self.sum + 1.0);
+        self.alpha.iter().map(|&alpha_i| {
+            self.alpha.iter().map(|&alpha_j| -alpha_i * alpha_j / norm).collect::<Vec<_>>()
+        }).enumerate().map(|(i, mut row)| {
+            row[i] = self.alpha[i] * (self.sum - self.alpha[i]) / norm;
+            row
+        }).collect()
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by normalizing independent gamma variates with
+    /// shape parameters `alpha` and unit scale.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<f64> where S: Source {
+        use distribution::gamma;
+        let mut x = self.alpha.iter().map(|&alpha| gamma::sample(alpha, source))
+                                     .collect::<Vec<_>>();
+        let sum = x.iter().fold(0.0, |sum, &x| sum + x);
+        for x in x.iter_mut() {
+            *x /= sum;
+        }
+        x
+    }
+}
+
+impl fmt::Display for Dirichlet {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Dirichlet(α={:?})", self.alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($alpha:expr) => (Dirichlet::new(&$alpha));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!([1.5, 2.0, 3.5]);
+        let x = vec![
+            vec![0.2, 0.3, 0.5], vec![0.1, 0.1, 0.8], vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0],
+        ];
+        let p = vec![5.7979252344369064, 4.4252347571754080, 3.0180492912240899];
+
+        assert::close(&x.iter().map(|x| d.density(x)).collect::<Vec<_>>(), &p, 1e-13);
+
+        assert_eq!(d.density(&[0.2, 0.3, 0.6]), 0.0);
+        assert_eq!(d.density(&[-0.2, 0.7, 0.5]), 0.0);
+        assert_eq!(d.density(&[0.5, 0.5]), 0.0);
+
+        let d = new!([1.0, 1.0, 1.0]);
+        assert::close(d.density(&[0.0, 0.25, 0.75]), 2.0, 1e-14);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!([1.0, 2.5]).to_string(), "Dirichlet(α=[1.0, 2.5])");
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!([1.0, 3.0]).mean(), vec![0.25, 0.75]);
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Dirichlet::try_new(&[1.0, 2.0]).is_ok());
+        assert_eq!(Dirichlet::try_new(&[1.0]).err(),
+                   Some("alpha must have at least two elements"));
+        assert_eq!(Dirichlet::try_new(&[1.0, -2.0]).err(), Some("alpha must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!([1.0, 3.0]).variance(), vec![0.0375, 0.0375]);
+    }
+
+    #[test]
+    fn covariance() {
+        let d = new!([1.0, 2.0, 2.0]);
+        let c = d.covariance();
+        let v = d.variance();
+        for i in 0..3 {
+            assert_eq!(c[i][i], v[i]);
+            for j in 0..3 {
+                assert_eq!(c[i][j], c[j][i]);
+            }
+        }
+        assert::close(c[0][1], -2.0 / 150.0, 1e-15);
+        assert::close(c[1][2], -4.0 / 150.0, 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let d = new!([1.5, 2.0, 3.5]);
+        let n = 10000;
+        let mut mean = vec![0.0; 3];
+        for _ in 0..n {
+            let x = d.sample(&mut source);
+            assert::close(x.iter().fold(0.0, |sum, &x| sum + x), 1.0, 1e-14);
+            assert!(x.iter().all(|&x| x >= 0.0));
+            for i in 0..3 {
+                mean[i] += x[i] / n as f64;
+            }
+        }
+        assert::close(&mean, &d.mean(), 1e-2);
+    }
+}
@@ -0,0 +1,285 @@
+use distribution;
+use source::Source;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// A discrete uniform distribution.
+#[derive(Clone, Copy)]
+pub struct DiscreteUniform {
+    a: i64,
+    b: i64,
+    n: f64,
+}
+
+impl DiscreteUniform {
+    /// Create a discrete uniform distribution on the integers of interval
+    /// `[a, b]`.
+    ///
+    /// It should hold that `a <= b`.
+    #[inline]
+    pub fn new(a: i64, b: i64) -> Self {
+        should!(a <= b);
+        DiscreteUniform { a: a, b: b, n: (b as f64 - a as f64) + 1.0 }
+    }
+
+    /// Create a discrete uniform distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(a: i64, b: i64) -> Result<Self, &'static str> {
+        must!(a <= b, "a must not exceed b");
+        Ok(DiscreteUniform::new(a, b))
+    }
+
+    /// Return the left endpoint of the support.
+    #[inline(always)]
+    pub fn a(&self) -> i64 { self.a }
+
+    /// Return the right endpoint of the support.
+    #[inline(always)]
+    pub fn b(&self) -> i64 { self.b }
+}
+
+impl fmt::Display for DiscreteUniform {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "DiscreteUniform(a={}, b={})", self.a, self.b)
+    }
+}
+
+impl distribution::Discrete for DiscreteUniform {
+    #[inline]
+    fn mass(&self, x: i64) -> f64 {
+        if x < self.a || x > self.b {
+            0.0
+        } else {
+            1.0 / self.n
+        }
+    }
+}
+
+impl distribution::DiscreteSupport for DiscreteUniform {
+    type Iter = RangeInclusive<i64>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (self.a, self.b)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        self.a..=self.b
+    }
+}
+
+impl distribution::Distribution for DiscreteUniform {
+    type Value = i64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        if x < self.a as f64 {
+            0.0
+        } else if x >= self.b as f64 {
+            1.0
+        } else {
+            (x.floor() - self.a as f64 + 1.0) / self.n
+        }
+    }
+}
+
+impl distribution::Entropy for DiscreteUniform {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        self.n.ln()
+    }
+}
+
+impl distribution::Inverse for DiscreteUniform {
+    fn inverse(&self, p: f64) -> i64 {
+        should!(0.0 <= p && p <= 1.0);
+        if p <= 0.0 {
+            return self.a;
+        }
+        let k = (p * self.n).ceil() - 1.0;
+        if k >= self.n - 1.0 { self.b } else { self.a + k as i64 }
+    }
+}
+
+impl distribution::Kurtosis for DiscreteUniform {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let n2 = self.n * self.n;
+        -6.0 * (n2 + 1.0) / (5.0 * (n2 - 1.0))
+    }
+}
+
+impl distribution::Mean for DiscreteUniform {
+    #[inline]
+    fn mean(&self) -> f64 {
+        (self.a as f64 + self.b as f64) / 2.0
+    }
+}
+
+impl distribution::Median for DiscreteUniform {
+    #[inline]
+    fn median(&self) -> f64 {
+        (self.a as f64 + self.b as f64) / 2.0
+    }
+}
+
+impl distribution::Modes for DiscreteUniform {
+    #[inline]
+    fn modes(&self) -> Vec<i64> {
+        (self.a..(self.b + 1)).collect()
+    }
+}
+
+impl distribution::Parametric for DiscreteUniform {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for DiscreteUniform {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by rejection in order to avoid the modulo bias,
+    /// and every integer of `[a, b]` is equally likely.
+    fn sample<S>(&self, source: &mut S) -> i64 where S: Source {
+        let n = (self.b.wrapping_sub(self.a) as u64).wrapping_add(1);
+        if n == 0 {
+            return source.read::<u64>() as i64;
+        }
+        let zone = (::std::u64::MAX / n) * n;
+        loop {
+            let x = source.read::<u64>();
+            if x < zone {
+                return self.a.wrapping_add((x % n) as i64);
+            }
+        }
+    }
+}
+
+impl distribution::Skewness for DiscreteUniform {
+    #[inline]
+    fn skewness(&self) -> f64 { 0.0 }
+}
+
+impl distribution::Variance for DiscreteUniform {
+    #[inline]
+    fn variance(&self) -> f64 {
+        (self.n * self.n - 1.0) / 12.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    macro_rules! new(
+        ($a:expr, $b:expr) => (DiscreteUniform::new($a, $b));
+    );
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(-2, 5).to_string(), "DiscreteUniform(a=-2, b=5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(-1, 2);
+        let p = vec![0.0, 0.25, 0.5, 0.75, 1.0, 1.0];
+
+        let x = (-2..4).map(|x| d.distribution(x as f64)).collect::<Vec<_>>();
+        assert_eq!(&x, &p);
+
+        let x = (-2..4).map(|x| d.distribution(x as f64 + 0.5)).collect::<Vec<_>>();
+        assert_eq!(&x, &p);
+    }
+
+    #[test]
+    fn entropy() {
+        use std::f64::consts::LN_2;
+        assert_eq!(new!(1, 2).entropy(), LN_2);
+        assert_eq!(new!(3, 3).entropy(), 0.0);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(-1, 2);
+        let p = vec![0.0, 0.1, 0.25, 0.2500001, 0.5, 0.75, 0.9, 1.0];
+        let x = vec![-1, -1, -1, 0, 0, 1, 2, 2];
+        assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x);
+    }
+
+    #[test]
+    fn iter_support() {
+        let d = new!(-2, 3);
+        assert_eq!(d.iter_support().collect::<Vec<_>>(), vec![-2, -1, 0, 1, 2, 3]);
+        assert::close(d.iter_support().fold(0.0, |sum, x| sum + d.mass(x)), 1.0, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(1, 6).kurtosis(), -1.2685714285714285);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(-1, 2);
+        assert_eq!(&(-2..4).map(|x| d.mass(x)).collect::<Vec<_>>(),
+                   &[0.0, 0.25, 0.25, 0.25, 0.25, 0.0]);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1, 6).mean(), 3.5);
+        assert_eq!(new!(-3, 3).mean(), 0.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(-1, 3).median(), 1.0);
+        assert_eq!(new!(2, 5).median(), 3.5);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(-1, 2).modes(), vec![-1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(-2, 3).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut counts = [0; 6];
+        for x in Independent(&new!(1, 6), &mut source::default()).take(60000) {
+            assert!(1 <= x && x <= 6);
+            counts[x as usize - 1] += 1;
+        }
+        assert!(counts.iter().all(|&count| 9500 < count && count < 10500));
+
+        let d = new!(::std::i64::MIN, ::std::i64::MAX);
+        assert!(Independent(&d, &mut source::default()).take(100).any(|x| x < 0));
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(1, 6).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(-2, 3).support(), (-2, 3));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(DiscreteUniform::try_new(-1, -1).is_ok());
+        assert_eq!(DiscreteUniform::try_new(2, 1).err(), Some("a must not exceed b"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(1, 6).variance(), 35.0 / 12.0);
+    }
+}
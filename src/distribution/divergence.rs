@@ -0,0 +1,78 @@
+//! Divergences between distributions.
+//!
+//! The Kullback–Leibler divergence `KL(p ‖ q)` is computed in closed form for
+//! pairs of distributions of the same family.
+
+use distribution::{Beta, Exponential, Gamma, Gaussian};
+
+/// Compute the Kullback–Leibler divergence between two beta distributions.
+///
+/// It should hold that the distributions have the same support, since the
+/// divergence is infinite otherwise.
+pub fn kl_beta(p: &Beta, q: &Beta) -> f64 {
+    use special::{Beta, Gamma};
+    should!(p.a() == q.a() && p.b() == q.b());
+    let (alpha_p, beta_p, alpha_q, beta_q) = (p.alpha(), p.beta(), q.alpha(), q.beta());
+    alpha_q.ln_beta(beta_q) - alpha_p.ln_beta(beta_p) +
+        (alpha_p - alpha_q) * alpha_p.digamma() + (beta_p - beta_q) * beta_p.digamma() +
+        (alpha_q - alpha_p + beta_q - beta_p) * (alpha_p + beta_p).digamma()
+}
+
+/// Compute the Kullback–Leibler divergence between two exponential
+/// distributions.
+pub fn kl_exponential(p: &Exponential, q: &Exponential) -> f64 {
+    let ratio = q.lambda() / p.lambda();
+    ratio - ratio.ln() - 1.0
+}
+
+/// Compute the Kullback–Leibler divergence between two gamma distributions.
+pub fn kl_gamma(p: &Gamma, q: &Gamma) -> f64 {
+    use special::Gamma;
+    let (k_p, theta_p, k_q, theta_q) = (p.k(), p.theta(), q.k(), q.theta());
+    (k_p - k_q) * k_p.digamma() - Gamma::ln_gamma(k_p).0 + Gamma::ln_gamma(k_q).0 +
+        k_q * (theta_q / theta_p).ln() + k_p * (theta_p - theta_q) / theta_q
+}
+
+/// Compute the Kullback–Leibler divergence between two Gaussian
+/// distributions, which is `ln(σq / σp) + (σp² + (μp - μq)²) / (2σq²) - 1/2`.
+pub fn kl_gaussian(p: &Gaussian, q: &Gaussian) -> f64 {
+    let (mu_p, sigma_p, mu_q, sigma_q) = (p.mu(), p.sigma(), q.mu(), q.sigma());
+    (sigma_q / sigma_p).ln() +
+        (sigma_p * sigma_p + (mu_p - mu_q) * (mu_p - mu_q)) / (2.0 * sigma_q * sigma_q) - 0.5
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    use super::{kl_beta, kl_exponential, kl_gamma, kl_gaussian};
+
+    #[test]
+    fn beta() {
+        let (p, q) = (Beta::new(2.0, 3.0, 0.0, 1.0), Beta::new(0.5, 1.5, 0.0, 1.0));
+        assert::close(kl_beta(&p, &q), 0.43648935507745517, 1e-14);
+        assert::close(kl_beta(&p, &p), 0.0, 1e-15);
+    }
+
+    #[test]
+    fn exponential() {
+        let (p, q) = (Exponential::new(2.0), Exponential::new(0.5));
+        assert::close(kl_exponential(&p, &q), 0.63629436111989062, 1e-15);
+        assert_eq!(kl_exponential(&p, &p), 0.0);
+    }
+
+    #[test]
+    fn gamma() {
+        let (p, q) = (Gamma::new(2.0, 3.0), Gamma::new(1.5, 4.0));
+        assert::close(kl_gamma(&p, &q), 0.022133038591659739, 1e-15);
+        assert::close(kl_gamma(&p, &p), 0.0, 1e-15);
+    }
+
+    #[test]
+    fn gaussian() {
+        let (p, q) = (Gaussian::new(1.0, 2.0), Gaussian::new(-0.5, 3.0));
+        assert::close(kl_gaussian(&p, &q), 0.25268733033038660, 1e-15);
+        assert_eq!(kl_gaussian(&p, &p), 0.0);
+    }
+}
@@ -0,0 +1,189 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// An empirical distribution.
+///
+/// The distribution places equal mass on each of a set of observed samples.
+#[derive(Clone)]
+pub struct Empirical {
+    data: Vec<f64>,
+}
+
+impl Empirical {
+    /// Create an empirical distribution from `data`.
+    ///
+    /// It should hold that `data` is nonempty and contains no NaNs.
+    pub fn from_samples(mut data: Vec<f64>) -> Self {
+        should!(!data.is_empty() && data.iter().all(|x| !x.is_nan()));
+        data.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        Empirical { data: data }
+    }
+
+    /// Create an empirical distribution from `data` or return an error if the
+    /// data are invalid.
+    #[inline]
+    pub fn try_from_samples(data: Vec<f64>) -> Result<Self, &'static str> {
+        must!(!data.is_empty(), "data must be nonempty");
+        must!(data.iter().all(|x| !x.is_nan()), "data must not contain NaNs");
+        Ok(Empirical::from_samples(data))
+    }
+
+    /// Return the sorted samples.
+    #[inline(always)]
+    pub fn data(&self) -> &[f64] { &self.data }
+}
+
+impl fmt::Display for Empirical {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Empirical(n={})", self.data.len())
+    }
+}
+
+impl distribution::Distribution for Empirical {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is a step function, which is evaluated via a binary search
+    /// over the sorted samples.
+    fn distribution(&self, x: f64) -> f64 {
+        use std::cmp::Ordering::{Greater, Less};
+        let count = match self.data.binary_search_by(|&y| if y <= x { Less } else { Greater }) {
+            Ok(i) | Err(i) => i,
+        };
+        count as f64 / self.data.len() as f64
+    }
+}
+
+impl distribution::Inverse for Empirical {
+    /// Compute the inverse of the cumulative distribution function.
+    ///
+    /// The quantile is interpolated linearly between adjacent order
+    /// statistics, so that `0` and `1` correspond to the smallest and the
+    /// largest samples, respectively.
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        let n = self.data.len();
+        let h = (n - 1) as f64 * p;
+        let i = h.floor() as usize;
+        if i + 1 >= n {
+            return self.data[n - 1];
+        }
+        self.data[i] + (h - i as f64) * (self.data[i + 1] - self.data[i])
+    }
+}
+
+impl distribution::Mean for Empirical {
+    fn mean(&self) -> f64 {
+        self.data.iter().fold(0.0, |sum, &x| sum + x) / self.data.len() as f64
+    }
+}
+
+impl distribution::Median for Empirical {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Sample for Empirical {
+    /// Draw a sample.
+    ///
+    /// The sample is one of the observed samples chosen uniformly at random,
+    /// which corresponds to bootstrap resampling.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        let n = self.data.len();
+        let i = (source.read::<f64>() * n as f64) as usize;
+        self.data[if i < n { i } else { n - 1 }]
+    }
+}
+
+impl distribution::Variance for Empirical {
+    /// Compute the variance.
+    ///
+    /// The variance is that of the distribution itself, that is, the sum of
+    /// squared deviations is divided by the number of samples.
+    fn variance(&self) -> f64 {
+        use distribution::Mean;
+        let mean = self.mean();
+        self.data.iter().fold(0.0, |sum, &x| sum + (x - mean) * (x - mean)) /
+            self.data.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+
+    macro_rules! new(
+        ($data:expr) => (Empirical::from_samples($data.to_vec()));
+    );
+
+    #[test]
+    fn display() {
+        assert_eq!(new!([3.0, 1.0, 2.0]).to_string(), "Empirical(n=3)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!([3.0, 1.0, 2.0, 2.0, 5.0]);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 5.0, 6.0];
+        let p = vec![0.0, 0.2, 0.2, 0.6, 0.6, 0.8, 0.8, 1.0, 1.0];
+        assert_eq!(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!([3.0, 1.0, 2.0, 2.0, 5.0]);
+        let p = vec![0.0, 0.125, 0.25, 0.5, 0.625, 0.875, 1.0];
+        let x = vec![1.0, 1.5, 2.0, 2.0, 2.5, 4.0, 5.0];
+        assert_eq!(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x);
+        assert_eq!(new!([42.0]).inverse(0.3), 42.0);
+    }
+
+    #[test]
+    fn iqr() {
+        assert_eq!(new!([3.0, 1.0, 2.0, 2.0, 5.0]).iqr(), 1.0);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!([3.0, 1.0, 2.0, 2.0, 5.0]).mean(), 2.6);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!([3.0, 1.0, 2.0, 2.0, 5.0]).median(), 2.0);
+        assert_eq!(new!([4.0, 1.0, 2.0, 3.0]).median(), 2.5);
+    }
+
+    #[test]
+    fn quartiles() {
+        assert_eq!(new!([3.0, 1.0, 2.0, 2.0, 5.0]).quartiles(), (2.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn sample() {
+        let data = [3.0, 1.0, 2.0, 2.0, 5.0];
+        let d = new!(data);
+        let samples = Independent(&d, &mut source::default()).take(1000).collect::<Vec<_>>();
+        assert!(samples.iter().all(|x| data.contains(x)));
+        let twos = samples.iter().filter(|&&x| x == 2.0).count() as f64 / 1000.0;
+        assert!((twos - 0.4).abs() < 0.1);
+    }
+
+    #[test]
+    fn try_from_samples() {
+        assert!(Empirical::try_from_samples(vec![1.0, 2.0]).is_ok());
+        assert_eq!(Empirical::try_from_samples(vec![1.0, ::std::f64::NAN]).err(),
+                   Some("data must not contain NaNs"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).variance(), 4.0);
+    }
+}
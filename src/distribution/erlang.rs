@@ -0,0 +1,269 @@
+use distribution::{self, Gamma};
+use source::Source;
+use std::fmt;
+
+/// An Erlang distribution.
+///
+/// The distribution is the gamma distribution with an integer shape `k` and
+/// scale `1 / lambda`, and it is implemented as such. Both parameters are
+/// stored as given, so that they are returned exactly.
+#[derive(Clone, Copy)]
+pub struct Erlang {
+    k: u64,
+    lambda: f64,
+    gamma: Gamma,
+}
+
+impl Erlang {
+    /// Create an Erlang distribution with shape parameter `k` and rate
+    /// parameter `lambda`.
+    ///
+    /// It should hold that `k > 0` and `lambda > 0`.
+    #[inline]
+    pub fn new(k: u64, lambda: f64) -> Self {
+        should!(k > 0 && lambda > 0.0);
+        Erlang { k: k, lambda: lambda, gamma: Gamma::from_rate(k as f64, lambda) }
+    }
+
+    /// Create an Erlang distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(k: u64, lambda: f64) -> Result<Self, &'static str> {
+        must!(k > 0, "k must be positive");
+        must!(lambda > 0.0, "lambda must be positive");
+        Ok(Erlang::new(k, lambda))
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn k(&self) -> u64 { self.k }
+
+    /// Return the rate parameter.
+    #[inline(always)]
+    pub fn l(&self) -> f64 { self.lambda }
+
+    /// Return the rate parameter, which is the same as `l`.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 { self.lambda }
+}
+
+impl fmt::Display for Erlang {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Erlang(k={}, λ={})", self.k, self.lambda)
+    }
+}
+
+impl distribution::Continuous for Erlang {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        self.gamma.density(x)
+    }
+
+    #[inline]
+    fn ln_density(&self, x: f64) -> f64 {
+        self.gamma.ln_density(x)
+    }
+}
+
+impl distribution::Distribution for Erlang {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        self.gamma.distribution(x)
+    }
+}
+
+impl distribution::Entropy for Erlang {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        self.gamma.entropy()
+    }
+}
+
+impl distribution::Inverse for Erlang {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        self.gamma.inverse(p)
+    }
+}
+
+impl distribution::Kurtosis for Erlang {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        6.0 / self.k as f64
+    }
+}
+
+impl distribution::Mean for Erlang {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.k as f64 / self.lambda
+    }
+}
+
+impl distribution::Median for Erlang {
+    #[inline]
+    fn median(&self) -> f64 {
+        self.gamma.median()
+    }
+}
+
+impl distribution::Modes for Erlang {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![(self.k - 1) as f64 / self.lambda]
+    }
+}
+
+impl distribution::Parametric for Erlang {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for Erlang {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        self.gamma.sample(source)
+    }
+}
+
+impl distribution::Skewness for Erlang {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        2.0 / (self.k as f64).sqrt()
+    }
+}
+
+impl distribution::Support for Erlang {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for Erlang {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.k as f64 / (self.lambda * self.lambda)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($k:expr, $lambda:expr) => (Erlang::new($k, $lambda));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(3, 0.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 1.2168762235490701e-02,
+            3.7908166232039589e-02, 9.1969860292860584e-02, 1.2825781034984188e-01,
+            4.2112168744284167e-02,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(3, 0.5).to_string(), "Erlang(k=3, λ=0.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3, 0.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 2.1614966897625125e-03,
+            1.4387677966970687e-02, 8.0301397071394193e-02, 4.5618688411667047e-01,
+            8.7534798051691887e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(3, 0.5);
+        for &x in &[0.5, 1.0, 2.0, 5.0, 10.0] {
+            assert::close(d.inverse(d.distribution(x)), x, 1e-12);
+        }
+    }
+
+    #[test]
+    fn k() {
+        for k in 1..11 {
+            assert_eq!(new!(k, 0.5).k(), k);
+        }
+    }
+
+    #[test]
+    fn l() {
+        for &lambda in &[0.1, 0.3, 0.5, 0.7, 1.0, 3.0, 1e-3, 1e3] {
+            assert_eq!(new!(3, lambda).l(), lambda);
+        }
+    }
+
+    #[test]
+    fn lambda() {
+        for &lambda in &[0.1, 0.3, 0.5, 0.7, 1.0, 3.0, 1e-3, 1e3] {
+            assert_eq!(new!(3, lambda).lambda(), lambda);
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(3, 0.5).mean(), 6.0);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(3, 0.5).median(), 5.3481206274471206, 1e-12);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1, 0.5).modes(), vec![0.0]);
+        assert_eq!(new!(3, 0.5).modes(), vec![4.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(3, 0.5).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(3, 0.5);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(3, 0.5).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Erlang::try_new(3, 0.5).is_ok());
+        assert_eq!(Erlang::try_new(0, 0.5).err(), Some("k must be positive"));
+        assert_eq!(Erlang::try_new(3, 0.0).err(), Some("lambda must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(3, 0.5).variance(), 12.0);
+    }
+}
@@ -1,5 +1,6 @@
 use source::Source;
 use distribution;
+use std::fmt;
 
 /// An exponential distribution.
 #[derive(Clone, Copy)]
@@ -17,11 +18,56 @@ impl Exponential {
         Exponential { lambda: lambda }
     }
 
+    /// Create an exponential distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(lambda: f64) -> Result<Self, &'static str> {
+        must!(lambda > 0.0, "lambda must be positive");
+        Ok(Exponential::new(lambda))
+    }
+
+    /// Create an exponential distribution with scale `beta`, which corresponds
+    /// to the rate `1 / beta`.
+    ///
+    /// It should hold that `beta > 0`.
+    #[inline]
+    pub fn from_scale(beta: f64) -> Self {
+        should!(beta > 0.0);
+        Exponential::new(1.0 / beta)
+    }
+
+    /// Fit an exponential distribution to `samples` by maximum likelihood.
+    ///
+    /// The rate is the reciprocal of the sample mean. It should hold that
+    /// `samples` is nonempty and that all samples are positive; otherwise, the
+    /// estimated rate is not a valid parameter.
+    pub fn fit(samples: &[f64]) -> Self {
+        should!(!samples.is_empty());
+        should!(samples.iter().all(|&x| x > 0.0));
+        let sum = samples.iter().fold(0.0, |sum, &x| sum + x);
+        Exponential::new(samples.len() as f64 / sum)
+    }
+
     /// Return the rate parameter.
     #[inline(always)]
     pub fn lambda(&self) -> f64 { self.lambda }
 }
 
+impl fmt::Display for Exponential {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Exponential(λ={})", self.lambda)
+    }
+}
+
+impl distribution::CharacteristicFunction for Exponential {
+    #[inline]
+    fn characteristic(&self, t: f64) -> (f64, f64) {
+        let lambda = self.lambda;
+        let norm = lambda * lambda + t * t;
+        (lambda * lambda / norm, lambda * t / norm)
+    }
+}
+
 impl distribution::Continuous for Exponential {
     #[inline]
     fn density(&self, x: f64) -> f64 {
@@ -31,6 +77,15 @@ impl distribution::Continuous for Exponential {
             self.lambda * (-self.lambda * x).exp()
         }
     }
+
+    #[inline]
+    fn ln_density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            ::std::f64::NEG_INFINITY
+        } else {
+            self.lambda.ln() - self.lambda * x
+        }
+    }
 }
 
 impl distribution::Distribution for Exponential {
@@ -44,6 +99,15 @@ impl distribution::Distribution for Exponential {
             -(-self.lambda * x).exp_m1()
         }
     }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            (-self.lambda * x).exp()
+        }
+    }
 }
 
 impl distribution::Entropy for Exponential {
@@ -88,6 +152,39 @@ impl distribution::Modes for Exponential {
     }
 }
 
+impl distribution::Moment for Exponential {
+    #[inline]
+    fn raw_moment(&self, n: u32) -> f64 {
+        (1..(n + 1)).fold(1.0, |product, i| product * i as f64) / self.lambda.powi(n as i32)
+    }
+
+    /// Compute the central moment of order `n`.
+    ///
+    /// The central moment is `!n / λ^n` where `!n` is the subfactorial.
+    fn central_moment(&self, n: u32) -> f64 {
+        (1..(n + 1)).fold(1.0, |subfactorial, i| {
+            i as f64 * subfactorial + if i % 2 == 0 { 1.0 } else { -1.0 }
+        }) / self.lambda.powi(n as i32)
+    }
+}
+
+impl distribution::Parametric for Exponential {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Reliability for Exponential {
+    #[inline]
+    fn hazard(&self, x: f64) -> f64 {
+        if x < 0.0 { 0.0 } else { self.lambda }
+    }
+
+    #[inline]
+    fn cumulative_hazard(&self, x: f64) -> f64 {
+        if x <= 0.0 { 0.0 } else { self.lambda * x }
+    }
+}
+
 impl distribution::Sample for Exponential {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
@@ -100,6 +197,14 @@ impl distribution::Skewness for Exponential {
     fn skewness(&self) -> f64 { 2.0 }
 }
 
+impl distribution::Support for Exponential {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
 impl distribution::Variance for Exponential {
     #[inline]
     fn variance(&self) -> f64 {
@@ -121,6 +226,23 @@ mod tests {
         ($lambda:expr) => (Exponential::new($lambda));
     );
 
+    #[test]
+    fn characteristic() {
+        let d = new!(2.0);
+        assert_eq!(d.characteristic(0.0), (1.0, 0.0));
+        assert_eq!(d.characteristic(-1.5), (0.64, -0.48));
+        assert_eq!(d.characteristic(2.0), (0.5, 0.5));
+    }
+
+    #[test]
+    fn complementary() {
+        let d = new!(2.0);
+        for &x in &[-1.0, 0.0, 0.25, 0.5, 1.0, 2.0, 5.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+        assert_eq!(d.complementary(100.0), (-200.0f64).exp());
+    }
+
     #[test]
     fn density() {
         let d = new!(2.0);
@@ -135,6 +257,11 @@ mod tests {
         assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
     }
 
+    #[test]
+    fn display() {
+        assert_eq!(new!(2.0).to_string(), "Exponential(λ=2)");
+    }
+
     #[test]
     fn distribution() {
         let d = new!(2.0);
@@ -154,6 +281,32 @@ mod tests {
     fn entropy() {
         use std::f64::consts::E;
         assert_eq!(new!(E).entropy(), 0.0);
+        assert_eq!(new!(1.0).entropy(), 1.0);
+        assert::close(new!(2.0).entropy(), 0.30685281944005469, 1e-15);
+    }
+
+    #[test]
+    fn expectation() {
+        let d = new!(2.0);
+        assert::close(d.expectation(|x| x), d.mean(), 1e-15);
+        assert::close(d.expectation(|x| x * x), 0.5, 1e-15);
+        assert::close(d.expectation(|x| (-x).exp()), 2.0 / 3.0, 1e-15);
+    }
+
+    #[test]
+    fn fit() {
+        assert_eq!(Exponential::fit(&[0.5, 1.0, 1.5, 3.0]).lambda(), 2.0 / 3.0);
+
+        let mut source = source::default();
+        let samples = Independent(&new!(2.0), &mut source).take(10000).collect::<Vec<_>>();
+        assert!((Exponential::fit(&samples).lambda() - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn from_scale() {
+        assert_eq!(Exponential::from_scale(0.5).lambda(), 2.0);
+        assert_eq!(Exponential::from_scale(4.0).lambda(), 0.25);
+        assert_eq!(Exponential::from_scale(4.0).mean(), 4.0);
     }
 
     #[test]
@@ -173,6 +326,15 @@ mod tests {
         ];
 
         assert::close(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x, 1e-14);
+
+        for &lambda in &[0.5, 2.0, 10.0] {
+            use std::f64::consts::LN_2;
+            let d = new!(lambda);
+            assert_eq!(d.inverse(0.0), 0.0);
+            assert_eq!(d.inverse(1.0), INFINITY);
+            assert::close(d.inverse(0.5), LN_2 / lambda, 1e-15);
+            assert::close(d.inverse(0.5), d.median(), 1e-15);
+        }
     }
 
     #[test]
@@ -180,6 +342,19 @@ mod tests {
         assert_eq!(new!(2.0).kurtosis(), 6.0);
     }
 
+    #[test]
+    fn ln_density() {
+        use std::f64::NEG_INFINITY;
+
+        let d = new!(2.0);
+        for &x in &[0.0, 0.5, 1.0, 6.0, 12.0] {
+            assert::close(d.ln_density(x), d.density(x).ln(), 1e-14);
+        }
+        assert_eq!(d.ln_density(-1.0), NEG_INFINITY);
+        assert_eq!(d.density(1000.0), 0.0);
+        assert::close(d.ln_density(1000.0), 2f64.ln() - 2000.0, 1e-12);
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(2.0).mean(), 0.5);
@@ -196,11 +371,54 @@ mod tests {
         assert_eq!(new!(2.0).modes(), vec![0.0]);
     }
 
+    #[test]
+    fn moment() {
+        let d = new!(2.0);
+        assert_eq!(d.central_moment(0), 1.0);
+        assert_eq!(d.central_moment(1), 0.0);
+        assert_eq!(d.central_moment(2), d.variance());
+        assert_eq!(d.central_moment(3), 0.25);
+        assert_eq!(d.central_moment(4), 9.0 / 16.0);
+        assert_eq!(d.raw_moment(1), d.mean());
+        assert_eq!(d.raw_moment(3), 0.75);
+        assert_eq!(d.raw_moment(5), 3.75);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.0).num_parameters(), 1);
+    }
+
+    #[test]
+    fn reliability() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 2.0, 5.0];
+        for &x in &x {
+            let survival = 1.0 - d.distribution(x);
+            assert::close(d.survival(x), survival, 1e-15);
+            assert::close(d.hazard(x), d.density(x) / survival, 1e-11);
+            assert::close(d.cumulative_hazard(x), -survival.ln(), 1e-11);
+        }
+        assert_eq!(d.hazard(100.0), 2.0);
+        assert_eq!(d.cumulative_hazard(100.0), 200.0);
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(2.0).skewness(), 2.0);
     }
 
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Exponential::try_new(2.0).is_ok());
+        assert_eq!(Exponential::try_new(-2.0).err(), Some("lambda must be positive"));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(2.0).variance(), 0.25);
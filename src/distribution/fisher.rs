@@ -0,0 +1,283 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Fisher–Snedecor distribution.
+#[derive(Clone, Copy)]
+pub struct Fisher {
+    d1: u64,
+    d2: u64,
+    ln_beta: f64,
+}
+
+impl Fisher {
+    /// Create a Fisher–Snedecor distribution with `d1` and `d2` degrees of
+    /// freedom.
+    ///
+    /// It should hold that `d1 > 0` and `d2 > 0`.
+    #[inline]
+    pub fn new(d1: u64, d2: u64) -> Self {
+        use special::Beta;
+        should!(d1 > 0 && d2 > 0);
+        Fisher { d1: d1, d2: d2, ln_beta: (0.5 * d1 as f64).ln_beta(0.5 * d2 as f64) }
+    }
+
+    /// Create a Fisher–Snedecor distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(d1: u64, d2: u64) -> Result<Self, &'static str> {
+        must!(d1 > 0, "d1 must be positive");
+        must!(d2 > 0, "d2 must be positive");
+        Ok(Fisher::new(d1, d2))
+    }
+
+    /// Return the first degrees of freedom.
+    #[inline(always)]
+    pub fn d1(&self) -> u64 { self.d1 }
+
+    /// Return the second degrees of freedom.
+    #[inline(always)]
+    pub fn d2(&self) -> u64 { self.d2 }
+}
+
+impl fmt::Display for Fisher {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Fisher(d1={}, d2={})", self.d1, self.d2)
+    }
+}
+
+impl distribution::Continuous for Fisher {
+    fn density(&self, x: f64) -> f64 {
+        use std::f64::INFINITY;
+        if x < 0.0 {
+            return 0.0;
+        }
+        if x == 0.0 {
+            return match self.d1 {
+                1 => INFINITY,
+                2 => 1.0,
+                _ => 0.0,
+            };
+        }
+        let (d1, d2) = (self.d1 as f64, self.d2 as f64);
+        (0.5 * d1 * (d1 / d2).ln() + (0.5 * d1 - 1.0) * x.ln() -
+            0.5 * (d1 + d2) * (d1 * x / d2).ln_1p() - self.ln_beta).exp()
+    }
+}
+
+impl distribution::Distribution for Fisher {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The implementation is based on the incomplete beta function.
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Beta;
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let (d1, d2) = (self.d1 as f64, self.d2 as f64);
+        (d1 * x / (d1 * x + d2)).inc_beta(0.5 * d1, 0.5 * d2, self.ln_beta)
+    }
+}
+
+impl distribution::Kurtosis for Fisher {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined (NaN) for `d2 <= 8`.
+    fn kurtosis(&self) -> f64 {
+        if self.d2 <= 8 {
+            return ::std::f64::NAN;
+        }
+        let (d1, d2) = (self.d1 as f64, self.d2 as f64);
+        12.0 * (d1 * (5.0 * d2 - 22.0) * (d1 + d2 - 2.0) + (d2 - 4.0) * (d2 - 2.0).powi(2)) /
+            (d1 * (d2 - 6.0) * (d2 - 8.0) * (d1 + d2 - 2.0))
+    }
+}
+
+impl distribution::Mean for Fisher {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite for `d2 <= 2`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.d2 <= 2 {
+            return ::std::f64::INFINITY;
+        }
+        let d2 = self.d2 as f64;
+        d2 / (d2 - 2.0)
+    }
+}
+
+impl distribution::Modes for Fisher {
+    fn modes(&self) -> Vec<f64> {
+        if self.d1 <= 2 {
+            return vec![0.0];
+        }
+        let (d1, d2) = (self.d1 as f64, self.d2 as f64);
+        vec![(d1 - 2.0) / d1 * d2 / (d2 + 2.0)]
+    }
+}
+
+impl distribution::Parametric for Fisher {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for Fisher {
+    /// Draw a sample.
+    ///
+    /// The sample is the ratio of two chi-squared variates with `d1` and `d2`
+    /// degrees of freedom, each divided by its degrees of freedom.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::chisquared;
+        let (d1, d2) = (self.d1 as f64, self.d2 as f64);
+        let x1 = chisquared::sample(d1, source);
+        let x2 = chisquared::sample(d2, source);
+        (x1 / d1) / (x2 / d2)
+    }
+}
+
+impl distribution::Skewness for Fisher {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined (NaN) for `d2 <= 6`.
+    fn skewness(&self) -> f64 {
+        if self.d2 <= 6 {
+            return ::std::f64::NAN;
+        }
+        let (d1, d2) = (self.d1 as f64, self.d2 as f64);
+        (2.0 * d1 + d2 - 2.0) * (8.0 * (d2 - 4.0)).sqrt() /
+            ((d2 - 6.0) * (d1 * (d1 + d2 - 2.0)).sqrt())
+    }
+}
+
+impl distribution::Support for Fisher {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for Fisher {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite for `2 < d2 <= 4` and undefined (NaN) for
+    /// `d2 <= 2`.
+    fn variance(&self) -> f64 {
+        use std::f64::{INFINITY, NAN};
+        if self.d2 <= 2 {
+            return NAN;
+        }
+        if self.d2 <= 4 {
+            return INFINITY;
+        }
+        let (d1, d2) = (self.d1 as f64, self.d2 as f64);
+        2.0 * d2 * d2 * (d1 + d2 - 2.0) / (d1 * (d2 - 2.0).powi(2) * (d2 - 4.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+    use std::f64::INFINITY;
+
+    macro_rules! new(
+        ($d1:expr, $d2:expr) => (Fisher::new($d1, $d2));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(5, 12);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 5.3084064121188237e-01,
+            6.9777612387194987e-01, 6.3474058594657223e-01, 5.1059115319443894e-01,
+            2.9223471786064009e-01, 1.6137688341358862e-01, 5.1997085805380844e-02,
+            1.8888796541517060e-02, 3.4395135492178840e-03,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-14);
+
+        assert_eq!(new!(1, 12).density(0.0), INFINITY);
+        assert_eq!(new!(2, 12).density(0.0), 1.0);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(2, 5).to_string(), "Fisher(d1=2, d2=5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(5, 12);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 6.8190920222013874e-02,
+            2.2923615040316776e-01, 3.9830986226276470e-01, 5.4180330048575329e-01,
+            7.3888323431746087e-01, 8.4907392090706300e-01, 9.4479510058057296e-01,
+            9.7716732505886819e-01, 9.9476183752969709e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(5, 12).kurtosis(), 24.333333333333333, 1e-13);
+        assert!(new!(5, 8).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(5, 12).mean(), 1.2);
+        assert_eq!(new!(5, 2).mean(), INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(5, 12).modes()[0], 0.51428571428571429, 1e-15);
+        assert_eq!(new!(2, 12).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(3, 5).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(5, 12);
+        let sum = Independent(&d, &mut source).take(n).fold(0.0, |a, b| a + b);
+        assert!((sum / n as f64 - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(5, 12).skewness(), 3.0792014356780041, 1e-14);
+        assert!(new!(5, 6).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(3, 5).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Fisher::try_new(2, 3).is_ok());
+        assert_eq!(Fisher::try_new(2, 0).err(), Some("d2 must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(5, 12).variance(), 1.08, 1e-15);
+        assert_eq!(new!(5, 4).variance(), INFINITY);
+        assert!(new!(5, 2).variance().is_nan());
+    }
+}
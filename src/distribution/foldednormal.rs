@@ -0,0 +1,237 @@
+use distribution::{self, Gaussian};
+use source::Source;
+use std::fmt;
+
+/// A folded normal distribution.
+///
+/// The distribution is that of `|X|` where `X` follows the Gaussian
+/// distribution with mean `μ` and standard deviation `σ`. The case `μ = 0` is
+/// the half-normal distribution.
+#[derive(Clone, Copy)]
+pub struct FoldedNormal {
+    mu: f64,
+    sigma: f64,
+    gaussian: Gaussian,
+}
+
+impl FoldedNormal {
+    /// Create a folded normal distribution with location parameter `mu` and
+    /// scale parameter `sigma`.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64) -> Self {
+        should!(sigma > 0.0);
+        FoldedNormal { mu: mu, sigma: sigma, gaussian: Gaussian::new(mu, sigma) }
+    }
+
+    /// Create a folded normal distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(mu: f64, sigma: f64) -> Result<Self, &'static str> {
+        must!(mu.is_finite(), "mu must be finite");
+        must!(sigma > 0.0, "sigma must be positive");
+        Ok(FoldedNormal::new(mu, sigma))
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 { self.mu }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 { self.sigma }
+}
+
+impl fmt::Display for FoldedNormal {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "FoldedNormal(μ={}, σ={})", self.mu, self.sigma)
+    }
+}
+
+impl distribution::Continuous for FoldedNormal {
+    /// Compute the probability density function, which is `f(x) + f(-x)` for
+    /// `x ≥ 0` where `f` is the density of the underlying Gaussian
+    /// distribution.
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            self.gaussian.density(x) + self.gaussian.density(-x)
+        }
+    }
+}
+
+impl distribution::Distribution for FoldedNormal {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function, which is `F(x) - F(-x)`
+    /// where `F` is the cumulative distribution function of the underlying
+    /// Gaussian distribution.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            self.gaussian.distribution(x) - self.gaussian.distribution(-x)
+        }
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            self.gaussian.complementary(x) + self.gaussian.distribution(-x)
+        }
+    }
+}
+
+impl distribution::Mean for FoldedNormal {
+    /// Compute the mean, which is `σ √(2 / π) exp(-μ² / (2σ²)) + μ erf(μ / (σ
+    /// √2))`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        use special::Error;
+        use std::f64::consts::{PI, SQRT_2};
+        let z = self.mu / self.sigma;
+        self.sigma * (2.0 / PI).sqrt() * (-0.5 * z * z).exp() + self.mu * Error::erf(z / SQRT_2)
+    }
+}
+
+impl distribution::Parametric for FoldedNormal {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for FoldedNormal {
+    /// Draw a sample as the absolute value of a sample of the underlying
+    /// Gaussian distribution.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        self.gaussian.sample(source).abs()
+    }
+}
+
+impl distribution::Support for FoldedNormal {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for FoldedNormal {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use distribution::Mean;
+        let mean = self.mean();
+        self.mu * self.mu + self.sigma * self.sigma - mean * mean
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $sigma:expr) => (FoldedNormal::new($mu, $sigma));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(1.5, 2.0);
+        for &x in &[-1.0, 0.0, 0.5, 2.0, 5.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.5, 2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 3.0113743215480443e-01, 2.9701802564172142e-01,
+            2.8465860109593555e-01, 2.3647271781468035e-01, 1.6643854199523592e-01,
+            4.4153183441905643e-02, 2.3872514486620327e-05,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let (d, e) = (new!(0.0, 2.0), HalfNormal::new(2.0));
+        for &x in &x {
+            assert::close(d.density(x), e.density(x), 1e-15);
+        }
+        let (d, e) = (new!(-1.5, 2.0), new!(1.5, 2.0));
+        for &x in &x {
+            assert::close(d.density(x), e.density(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.5, 2.0).to_string(), "FoldedNormal(μ=1.5, σ=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.5, 2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 1.4988228479452984e-01,
+            2.9564390065022100e-01, 5.5864716881910659e-01, 7.6114817496808707e-01,
+            9.5936381809379212e-01, 9.9998930701205258e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let (d, e) = (new!(0.0, 2.0), HalfNormal::new(2.0));
+        for &x in &x {
+            assert::close(d.distribution(x), e.distribution(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(1.5, 2.0).mean(), 2.0246676714886132, 1e-15);
+        assert::close(new!(-1.5, 2.0).mean(), 2.0246676714886132, 1e-15);
+        assert::close(new!(0.0, 2.0).mean(), HalfNormal::new(2.0).mean(), 1e-15);
+        assert::close(new!(50.0, 2.0).mean(), 50.0, 1e-12);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.5, 2.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        for &mu in &[0.0, 1.5, -3.0] {
+            let d = new!(mu, 2.0);
+            let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+            assert!(samples.iter().all(|&x| x >= 0.0));
+            let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+            assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(1.5, 2.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(FoldedNormal::try_new(-1.5, 2.0).is_ok());
+        assert_eq!(FoldedNormal::try_new(::std::f64::NAN, 2.0).err(), Some("mu must be finite"));
+        assert_eq!(FoldedNormal::try_new(1.5, 0.0).err(), Some("sigma must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(1.5, 2.0).variance(), 2.1507208200288779, 1e-14);
+        assert::close(new!(0.0, 2.0).variance(), HalfNormal::new(2.0).variance(), 1e-15);
+    }
+}
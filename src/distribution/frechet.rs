@@ -0,0 +1,317 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Fréchet distribution.
+///
+/// The distribution is the type-II extreme value distribution, which arises
+/// as the limiting distribution of the maxima of heavy-tailed samples. Its
+/// moments of order `n` exist only for `α > n`; the corresponding methods
+/// return infinity otherwise.
+#[derive(Clone, Copy)]
+pub struct Frechet {
+    alpha: f64,
+    s: f64,
+    m: f64,
+}
+
+impl Frechet {
+    /// Create a Fréchet distribution with shape parameter `alpha`, scale
+    /// parameter `s`, and location parameter `m`.
+    ///
+    /// It should hold that `alpha > 0` and `s > 0`.
+    #[inline]
+    pub fn new(alpha: f64, s: f64, m: f64) -> Self {
+        should!(alpha > 0.0 && s > 0.0);
+        Frechet { alpha: alpha, s: s, m: m }
+    }
+
+    /// Create a Fréchet distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(alpha: f64, s: f64, m: f64) -> Result<Self, &'static str> {
+        must!(alpha > 0.0, "alpha must be positive");
+        must!(s > 0.0, "s must be positive");
+        must!(m.is_finite(), "m must be finite");
+        Ok(Frechet::new(alpha, s, m))
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 { self.alpha }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn s(&self) -> f64 { self.s }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn m(&self) -> f64 { self.m }
+
+    // Γ(1 - i / α)
+    #[inline(always)]
+    fn gamma(&self, i: f64) -> f64 {
+        use special::Gamma;
+        Gamma::gamma(1.0 - i / self.alpha)
+    }
+}
+
+impl fmt::Display for Frechet {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Frechet(α={}, s={}, m={})", self.alpha, self.s, self.m)
+    }
+}
+
+impl distribution::Continuous for Frechet {
+    fn density(&self, x: f64) -> f64 {
+        if x <= self.m {
+            0.0
+        } else {
+            let z = (x - self.m) / self.s;
+            let t = z.powf(-self.alpha);
+            self.alpha / self.s * t / z * (-t).exp()
+        }
+    }
+}
+
+impl distribution::Distribution for Frechet {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= self.m {
+            0.0
+        } else {
+            (-((x - self.m) / self.s).powf(-self.alpha)).exp()
+        }
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        if x <= self.m {
+            1.0
+        } else {
+            -(-((x - self.m) / self.s).powf(-self.alpha)).exp_m1()
+        }
+    }
+}
+
+impl distribution::Inverse for Frechet {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        self.m + self.s * (-p.ln()).powf(-1.0 / self.alpha)
+    }
+}
+
+impl distribution::Kurtosis for Frechet {
+    /// Compute the excess kurtosis, which is infinite for `α ≤ 4`.
+    fn kurtosis(&self) -> f64 {
+        if self.alpha <= 4.0 {
+            return ::std::f64::INFINITY;
+        }
+        let (g1, g2) = (self.gamma(1.0), self.gamma(2.0));
+        let (g3, g4) = (self.gamma(3.0), self.gamma(4.0));
+        (g4 - 4.0 * g3 * g1 + 3.0 * g2 * g2) / (g2 - g1 * g1).powi(2) - 6.0
+    }
+}
+
+impl distribution::Mean for Frechet {
+    /// Compute the mean, which is infinite for `α ≤ 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.alpha <= 1.0 {
+            return ::std::f64::INFINITY;
+        }
+        self.m + self.s * self.gamma(1.0)
+    }
+}
+
+impl distribution::Median for Frechet {
+    #[inline]
+    fn median(&self) -> f64 {
+        use std::f64::consts::LN_2;
+        self.m + self.s * LN_2.powf(-1.0 / self.alpha)
+    }
+}
+
+impl distribution::Modes for Frechet {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.m + self.s * (self.alpha / (1.0 + self.alpha)).powf(1.0 / self.alpha)]
+    }
+}
+
+impl distribution::Parametric for Frechet {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 3 }
+}
+
+impl distribution::Sample for Frechet {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        self.m + self.s * (-source.read::<f64>().ln()).powf(-1.0 / self.alpha)
+    }
+}
+
+impl distribution::Skewness for Frechet {
+    /// Compute the skewness, which is infinite for `α ≤ 3`.
+    fn skewness(&self) -> f64 {
+        if self.alpha <= 3.0 {
+            return ::std::f64::INFINITY;
+        }
+        let (g1, g2, g3) = (self.gamma(1.0), self.gamma(2.0), self.gamma(3.0));
+        (g3 - 3.0 * g1 * g2 + 2.0 * g1 * g1 * g1) / (g2 - g1 * g1).powf(1.5)
+    }
+}
+
+impl distribution::Support for Frechet {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (self.m, INFINITY)
+    }
+}
+
+impl distribution::Variance for Frechet {
+    /// Compute the variance, which is infinite for `α ≤ 2`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        if self.alpha <= 2.0 {
+            return ::std::f64::INFINITY;
+        }
+        let g1 = self.gamma(1.0);
+        self.s * self.s * (self.gamma(2.0) - g1 * g1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($alpha:expr, $s:expr, $m:expr) => (Frechet::new($alpha, $s, $m));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(3.0, 2.0, 1.0);
+        for &x in &[0.0, 1.0, 1.5, 3.0, 10.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+        assert::close(d.complementary(1e6), 8.0000240000480000e-18, 1e-30);
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(3.0, 2.0, 1.0);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 20.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 6.1586338197067696e-26,
+            8.0511030696602849e-03, 5.5181916175716350e-01, 2.2031617161656483e-01,
+            3.6019391981980009e-02, 1.8394597877107244e-04,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(3.0, 2.0, 1.0).to_string(), "Frechet(α=3, s=2, m=1)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.0, 2.0, 1.0);
+        let x = vec![0.0, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 20.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 1.6038108905486379e-28,
+            3.3546262790251185e-04, 3.6787944117144233e-01, 7.4356707920590637e-01,
+            9.3800499953072947e-01, 9.9883432914270542e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::INFINITY;
+
+        let d = new!(3.0, 2.0, 1.0);
+        for &x in &[1.5, 2.0, 3.0, 4.0, 6.0] {
+            assert::close(d.inverse(d.distribution(x)), x, 1e-13);
+        }
+        assert_eq!(d.inverse(0.0), 1.0);
+        assert_eq!(d.inverse(1.0), INFINITY);
+    }
+
+    #[test]
+    fn kurtosis() {
+        use std::f64::INFINITY;
+        assert::close(new!(5.0, 2.0, 1.0).kurtosis(), 45.091512125815761, 1e-10);
+        assert_eq!(new!(4.0, 2.0, 1.0).kurtosis(), INFINITY);
+        assert_eq!(new!(0.5, 2.0, 1.0).kurtosis(), INFINITY);
+    }
+
+    #[test]
+    fn mean() {
+        use std::f64::INFINITY;
+        assert::close(new!(3.0, 2.0, 1.0).mean(), 3.7082358788528009, 1e-14);
+        assert_eq!(new!(1.0, 2.0, 1.0).mean(), INFINITY);
+        assert_eq!(new!(0.5, 2.0, 1.0).mean(), INFINITY);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(3.0, 2.0, 1.0).median(), 3.2598945526747802, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(3.0, 2.0, 1.0).modes()[0], 2.8171205928321394, 1e-15);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(3.0, 2.0, 1.0).num_parameters(), 3);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(5.0, 2.0, 1.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 1.0));
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn skewness() {
+        use std::f64::INFINITY;
+        assert::close(new!(4.0, 2.0, 1.0).skewness(), 5.605138216895865, 1e-12);
+        assert_eq!(new!(3.0, 2.0, 1.0).skewness(), INFINITY);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(3.0, 2.0, 1.0).support(), (1.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Frechet::try_new(3.0, 2.0, 1.0).is_ok());
+        assert_eq!(Frechet::try_new(0.0, 2.0, 1.0).err(), Some("alpha must be positive"));
+        assert_eq!(Frechet::try_new(3.0, 0.0, 1.0).err(), Some("s must be positive"));
+        assert_eq!(Frechet::try_new(3.0, 2.0, ::std::f64::NAN).err(), Some("m must be finite"));
+    }
+
+    #[test]
+    fn variance() {
+        use std::f64::INFINITY;
+        assert::close(new!(3.0, 2.0, 1.0).variance(), 3.3812125633253882, 1e-14);
+        assert_eq!(new!(2.0, 2.0, 1.0).variance(), INFINITY);
+    }
+}
@@ -1,24 +1,101 @@
 use distribution;
 use source::Source;
+use std::fmt;
 
 /// A gamma distribution.
 #[derive(Clone, Copy)]
 pub struct Gamma {
     k: f64,
     theta: f64,
-    norm: f64,
+    ln_norm: f64,
 }
 
 impl Gamma {
     /// Create a gamma distribution with shape parameter `k` and scale parameter
     /// `theta`.
     ///
-    /// It should hold that `k > 0` and `theta > 0`.
+    /// Note that `theta` is the scale and not the rate; for the shape–rate
+    /// parametrization, see `from_rate`. It should hold that `k > 0` and
+    /// `theta > 0`.
     #[inline]
     pub fn new(k: f64, theta: f64) -> Self {
         use special::Gamma as SpecialGamma;
         should!(k > 0.0 && theta > 0.0);
-        Gamma { k: k, theta: theta, norm: k.gamma() * theta.powf(k) }
+        Gamma { k: k, theta: theta, ln_norm: -(k * theta.ln() + SpecialGamma::ln_gamma(k).0) }
+    }
+
+    /// Create a gamma distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(k: f64, theta: f64) -> Result<Self, &'static str> {
+        must!(k > 0.0, "k must be positive");
+        must!(theta > 0.0, "theta must be positive");
+        Ok(Gamma::new(k, theta))
+    }
+
+    /// Create a gamma distribution with shape parameter `k` and rate parameter
+    /// `beta`, which corresponds to the scale parameter `1 / beta`.
+    ///
+    /// It should hold that `k > 0` and `beta > 0`.
+    #[inline]
+    pub fn from_rate(k: f64, beta: f64) -> Self {
+        should!(beta > 0.0);
+        Gamma::new(k, 1.0 / beta)
+    }
+
+    /// Fit a gamma distribution to `samples` by maximum likelihood.
+    ///
+    /// The shape parameter is the root of `ln(k) - ψ(k) = ln(m) - l`, where
+    /// `ψ` is the digamma function, `m` is the sample mean, and `l` is the
+    /// sample mean of the logarithms. The equation is solved by the Newton
+    /// method starting from the approximation due to Minka, and the scale
+    /// parameter is then `m / k`. It should hold that there are at least two
+    /// distinct samples and that all of them are positive.
+    ///
+    /// ## References
+    ///
+    /// 1. T. P. Minka, “Estimating a Gamma distribution,” Microsoft Research,
+    ///    2002.
+    pub fn fit(samples: &[f64]) -> Self {
+        use special::Gamma as SpecialGamma;
+
+        const EPSILON: f64 = 1e-14;
+        const MAX_ITERATIONS: usize = 100;
+
+        should!(samples.len() > 1);
+        should!(samples.iter().all(|&x| x > 0.0));
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().fold(0.0, |sum, &x| sum + x) / n;
+        let s = mean.ln() - samples.iter().fold(0.0, |sum, &x| sum + x.ln()) / n;
+        should!(s > 0.0);
+
+        let mut k = (3.0 - s + ((s - 3.0) * (s - 3.0) + 24.0 * s).sqrt()) / (12.0 * s);
+        for _ in 0..MAX_ITERATIONS {
+            let f = k.ln() - k.digamma() - s;
+            let mut next = k - f / (1.0 / k - trigamma(k));
+            if next <= 0.0 {
+                next = 0.5 * k;
+            }
+            let done = (next - k).abs() <= EPSILON * next;
+            k = next;
+            if done {
+                break;
+            }
+        }
+        Gamma::new(k, mean / k)
+    }
+
+    /// Return the distribution of the sum of two independent variables
+    /// distributed according to `self` and `other`, respectively.
+    ///
+    /// The sum is gamma with the shape parameters added provided that the
+    /// scale parameters are equal, which should hold; for different scale
+    /// parameters, the sum is not a gamma distribution.
+    #[inline]
+    pub fn sum(&self, other: &Gamma) -> Gamma {
+        should!(self.theta == other.theta);
+        Gamma::new(self.k + other.k, self.theta)
     }
 
     /// Return the shape parameter.
@@ -30,12 +107,34 @@ impl Gamma {
     pub fn theta(&self) -> f64 { self.theta }
 }
 
+impl fmt::Display for Gamma {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Gamma(k={}, θ={})", self.k, self.theta)
+    }
+}
+
 impl distribution::Continuous for Gamma {
     fn density(&self, x: f64) -> f64 {
         if x <= 0.0 {
             0.0
         } else {
-            x.powf(self.k - 1.0) * (-x / self.theta).exp() / self.norm
+            ((self.k - 1.0) * x.ln() - x / self.theta + self.ln_norm).exp()
+        }
+    }
+
+    fn density_into(&self, xs: &[f64], out: &mut [f64]) {
+        should!(xs.len() == out.len());
+        let (a, b, c) = (self.k - 1.0, -1.0 / self.theta, self.ln_norm);
+        for (&x, y) in xs.iter().zip(out.iter_mut()) {
+            *y = if x <= 0.0 { 0.0 } else { (a * x.ln() + b * x + c).exp() };
+        }
+    }
+
+    fn ln_density(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            ::std::f64::NEG_INFINITY
+        } else {
+            (self.k - 1.0) * x.ln() - x / self.theta + self.ln_norm
         }
     }
 }
@@ -60,6 +159,16 @@ impl distribution::Entropy for Gamma {
     }
 }
 
+impl distribution::Inverse for Gamma {
+    /// Compute the inverse of the cumulative distribution function.
+    ///
+    /// See `gamma::inverse` for the details.
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        self.theta * inverse(self.k, p)
+    }
+}
+
 impl distribution::Kurtosis for Gamma {
     #[inline]
     fn kurtosis(&self) -> f64 {
@@ -74,6 +183,18 @@ impl distribution::Mean for Gamma {
     }
 }
 
+impl distribution::Median for Gamma {
+    /// Compute the median.
+    ///
+    /// The median has no closed form, and it is computed by inverting the
+    /// cumulative distribution function at `0.5`.
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
 impl distribution::Modes for Gamma {
     fn modes(&self) -> Vec<f64> {
         if self.k >= 1.0 {
@@ -84,6 +205,42 @@ impl distribution::Modes for Gamma {
     }
 }
 
+impl distribution::Moment for Gamma {
+    #[inline]
+    fn raw_moment(&self, n: u32) -> f64 {
+        (0..n).fold(1.0, |product, i| product * (self.k + i as f64)) * self.theta.powi(n as i32)
+    }
+
+    /// Compute the central moment of order `n`.
+    ///
+    /// The central moments are computed recursively from the cumulants,
+    /// which are `k θ^i (i - 1)!`.
+    fn central_moment(&self, n: u32) -> f64 {
+        let n = n as usize;
+        let mut moments = vec![1.0, 0.0];
+        let mut cumulants = vec![0.0, self.k * self.theta];
+        for i in 2..(n + 1) {
+            let cumulant = cumulants[i - 1] * (i - 1) as f64 * self.theta;
+            cumulants.push(cumulant);
+            let mut moment = 0.0;
+            let mut coefficient = 1.0;
+            for j in 0..(i - 1) {
+                moment += coefficient * cumulants[i - j] * moments[j];
+                coefficient *= (i - 1 - j) as f64 / (j + 1) as f64;
+            }
+            moments.push(moment);
+        }
+        moments[n]
+    }
+}
+
+impl distribution::Parametric for Gamma {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Reliability for Gamma {}
+
 impl distribution::Sample for Gamma {
     /// Draw a sample.
     ///
@@ -105,6 +262,14 @@ impl distribution::Skewness for Gamma {
     }
 }
 
+impl distribution::Support for Gamma {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
 impl distribution::Variance for Gamma {
     #[inline]
     fn variance(&self) -> f64 {
@@ -112,6 +277,57 @@ impl distribution::Variance for Gamma {
     }
 }
 
+/// Compute the inverse cumulative distribution function of the standard Gamma
+/// distribution.
+///
+/// The Newton–Raphson method is applied to the regularized incomplete gamma
+/// function starting from the Wilson–Hilferty approximation. The root is kept
+/// in a bracket, and a step leaving the bracket is replaced by a bisection or,
+/// if the bracket is unbounded, by doubling, which keeps the iterate
+/// positive.
+pub fn inverse(k: f64, p: f64) -> f64 {
+    use distribution::gaussian;
+    use special::Gamma;
+    use std::f64::INFINITY;
+
+    should!(k > 0.0 && 0.0 <= p && p <= 1.0);
+
+    const EPSILON: f64 = 1e-15;
+    const MAX_ITERATIONS: usize = 1000;
+
+    if p <= 0.0 {
+        return 0.0;
+    }
+    if p >= 1.0 {
+        return INFINITY;
+    }
+
+    let ln_gamma = Gamma::ln_gamma(k).0;
+    let mut x = {
+        let c = 1.0 / (9.0 * k);
+        let x = k * (1.0 - c + gaussian::inverse(p) * c.sqrt()).powi(3);
+        if x > 0.0 { x } else { ((p.ln() + ln_gamma + k.ln()) / k).exp() }
+    };
+    let (mut a, mut b) = (0.0, INFINITY);
+    for _ in 0..MAX_ITERATIONS {
+        let f = x.inc_gamma(k) - p;
+        if f == 0.0 {
+            break;
+        }
+        if f < 0.0 { a = x } else { b = x }
+        let mut next = x - f / ((k - 1.0) * x.ln() - x - ln_gamma).exp();
+        if !(a < next && next < b) {
+            next = if b == INFINITY { 2.0 * x } else { 0.5 * (a + b) };
+        }
+        let done = (next - x).abs() <= EPSILON * next;
+        x = next;
+        if done {
+            break;
+        }
+    }
+    x
+}
+
 /// Draw a sample from the standard Gamma distribution.
 pub fn sample<S: Source>(k: f64, source: &mut S) -> f64 {
     use distribution::gaussian;
@@ -151,6 +367,23 @@ pub fn sample<S: Source>(k: f64, source: &mut S) -> f64 {
     }
 }
 
+/// Compute the trigamma function, which is the derivative of the digamma
+/// function, for `x > 0`.
+///
+/// The argument is shifted upward via the recurrence relation, and the
+/// asymptotic expansion is used afterward.
+fn trigamma(x: f64) -> f64 {
+    should!(x > 0.0);
+    let (mut x, mut sum) = (x, 0.0);
+    while x < 10.0 {
+        sum += 1.0 / (x * x);
+        x += 1.0;
+    }
+    let y = 1.0 / (x * x);
+    sum + 1.0 / x + y / 2.0 + y / x * (1.0 / 6.0 - y * (1.0 / 30.0 - y * (1.0 / 42.0 -
+        y * (1.0 / 30.0 - y * (5.0 / 66.0 - y * (691.0 / 2730.0 - y * 7.0 / 6.0))))))
+}
+
 #[cfg(test)]
 mod tests {
     use assert;
@@ -177,7 +410,24 @@ mod tests {
             1.4325000668200492e-02, 8.3250881130958170e-03,
         ];
 
-        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-14);
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(250.0, 0.5);
+        assert::close(d.density(125.0), 5.0445832369060278e-02, 1e-12);
+    }
+
+    #[test]
+    fn density_into() {
+        let d = new!(9.0, 0.5);
+        let x = (-4..60).map(|i| 0.25 * i as f64).collect::<Vec<_>>();
+        let mut p = vec![0.0; x.len()];
+        d.density_into(&x, &mut p);
+        assert::close(&p, &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(9.0, 0.5).to_string(), "Gamma(k=9, θ=0.5)");
     }
 
     #[test]
@@ -206,26 +456,186 @@ mod tests {
         assert_eq!(new!(1.0, 1.0 / 5.0).entropy(), Exponential::new(5.0).entropy());
     }
 
+    #[test]
+    fn fit() {
+        let d = Gamma::fit(&[0.5, 1.0, 2.5, 4.0, 7.5]);
+        assert::close(d.k(), 1.3726917999380837, 1e-14);
+        assert::close(d.theta(), 2.2583365036054182, 1e-14);
+
+        let mut source = source::default();
+        let samples = Independent(&new!(3.0, 2.0), &mut source).take(10000).collect::<Vec<_>>();
+        let d = Gamma::fit(&samples);
+        assert!((d.k() - 3.0).abs() < 0.2);
+        assert!((d.theta() - 2.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn from_rate() {
+        let d = Gamma::from_rate(3.0, 0.5);
+        assert_eq!((d.k(), d.theta()), (3.0, 2.0));
+        let d = Gamma::from_rate(2.0, 4.0);
+        assert_eq!((d.k(), d.theta()), (2.0, 0.25));
+        assert_eq!(d.mean(), 0.5);
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::INFINITY;
+
+        let p = vec![1e-10, 0.01, 0.25, 0.5, 0.75, 0.99, 0.999];
+        let x = vec![
+            (0.1, vec![
+                6.0730483624079604e-101, 6.0730483624078982e-21, 5.7917132949696066e-07,
+                5.9339110446022614e-04, 3.5306358073558301e-02, 1.5884778179295052e+00,
+                3.3636770117187544e+00,
+            ]),
+            (0.5, vec![
+                7.8539816339744824e-21, 7.8543928954850987e-05, 5.0765522133810775e-02,
+                2.2746821155978639e-01, 6.6165184846573299e-01, 3.3174483005106077e+00,
+                5.4137830853313664e+00,
+            ]),
+            (1.0, vec![
+                1.0000000000500000e-10, 1.0050335853501442e-02, 2.8768207245178090e-01,
+                6.9314718055994529e-01, 1.3862943611198906e+00, 4.6051701859880918e+00,
+                6.9077552789821368e+00,
+            ]),
+            (9.0, vec![
+                3.3191995582920975e-01, 3.5074554505862903e+00, 6.8376451751991461e+00,
+                8.6689511843703713e+00, 1.0802444897864083e+01, 1.7402652867352536e+01,
+                2.1156198165839982e+01,
+            ]),
+            (100.0, vec![
+                4.8883092053795956e+01, 7.8215983053795824e+01, 9.3085833837121740e+01,
+                9.9666864919315486e+01, 1.0655109252697643e+02, 1.2472256149072081e+02,
+                1.3377026391137861e+02,
+            ]),
+            (1000.0, vec![
+                8.1179876271756075e+02, 9.2790815979664251e+02, 9.7849296649469284e+02,
+                9.9966668642696516e+02, 1.0211436879071974e+03, 1.0750328320864351e+03,
+                1.1005780982933145e+03,
+            ]),
+        ];
+
+        for &(k, ref x) in &x {
+            let d = new!(k, 2.0);
+            for (&p, &x) in p.iter().zip(x) {
+                assert::close(d.inverse(p), 2.0 * x, 1e-12 * 2.0 * x);
+            }
+            assert_eq!(d.inverse(0.0), 0.0);
+            assert_eq!(d.inverse(1.0), INFINITY);
+        }
+    }
+
     #[test]
     fn kurtosis() {
         assert_eq!(new!(3.0, 1.5).kurtosis(), 2.0);
     }
 
+    #[test]
+    fn ln_density() {
+        use std::f64::NEG_INFINITY;
+
+        let d = new!(9.0, 0.5);
+        for &x in &[0.5, 1.0, 4.0, 9.0, 20.0] {
+            assert::close(d.ln_density(x), d.density(x).ln(), 1e-13);
+        }
+        assert_eq!(d.ln_density(0.0), NEG_INFINITY);
+        assert_eq!(d.density(1000.0), 0.0);
+        assert::close(d.ln_density(1000.0), -1949.1042360458486, 1e-10);
+    }
+
     #[test]
     fn mean() {
         assert_eq!(new!(9.0, 0.5).mean(), 4.5);
     }
 
+    #[test]
+    fn median() {
+        assert::close(new!(2.0, 2.0).median(), 3.3566939800333213, 1e-12);
+    }
+
     #[test]
     fn modes() {
         assert_eq!(new!(5.5, 1.5).modes(), vec![6.75]);
     }
 
+    #[test]
+    fn moment() {
+        let d = new!(9.0, 0.5);
+        assert_eq!(d.central_moment(0), 1.0);
+        assert_eq!(d.central_moment(1), 0.0);
+        assert_eq!(d.central_moment(2), d.variance());
+        assert::close(&(3..7).map(|n| d.central_moment(n)).collect::<Vec<_>>(),
+                      &[2.25, 18.5625, 57.375, 352.265625], 1e-12);
+        assert_eq!(d.raw_moment(1), d.mean());
+        assert_eq!(d.raw_moment(2), 22.5);
+        assert::close(d.raw_moment(5), 4826.25, 1e-10);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.0, 3.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn reliability() {
+        let d = new!(9.0, 0.5);
+        let x = vec![-1.0, 0.0, 1.0, 2.5, 4.5, 7.0, 10.0];
+        for &x in &x {
+            let survival = 1.0 - d.distribution(x);
+            assert_eq!(d.survival(x), survival);
+            assert_eq!(d.hazard(x), d.density(x) / survival);
+            assert_eq!(d.cumulative_hazard(x), -survival.ln());
+        }
+
+        let d = new!(1.0, 0.5);
+        let e = Exponential::new(2.0);
+        for &x in &[0.25, 1.0, 3.0] {
+            assert::close(d.survival(x), e.survival(x), 1e-15);
+            assert::close(d.hazard(x), e.hazard(x), 1e-13);
+            assert::close(d.cumulative_hazard(x), e.cumulative_hazard(x), 1e-14);
+        }
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(4.0, 1.5).skewness(), 1.0);
     }
 
+    #[test]
+    fn sum() {
+        let d = new!(1.5, 2.0).sum(&new!(2.0, 2.0));
+        assert_eq!((d.k(), d.theta()), (3.5, 2.0));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic]
+    fn sum_different_scales() {
+        let _ = new!(1.5, 2.0).sum(&new!(2.0, 3.0));
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.0, 3.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn trigamma() {
+        use super::trigamma;
+        let x = vec![0.1, 1.0, 6.0, 100.0];
+        let y = vec![
+            101.43329915079276, 1.6449340668482264, 0.18132295573711532, 0.010050166663333571,
+        ];
+        assert::close(&x.iter().map(|&x| trigamma(x)).collect::<Vec<_>>(), &y, 1e-13);
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Gamma::try_new(9.0, 0.5).is_ok());
+        assert_eq!(Gamma::try_new(9.0, ::std::f64::NAN).err(), Some("theta must be positive"));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(9.0, 0.5).variance(), 2.25);
@@ -1,5 +1,6 @@
 use distribution;
 use source::Source;
+use std::fmt;
 
 /// A Gaussian distribution.
 #[derive(Clone, Copy)]
@@ -9,6 +10,34 @@ pub struct Gaussian {
     norm: f64,
 }
 
+/// A method of sampling from a Gaussian distribution.
+///
+/// The methods differ in speed and in how many values they read from the
+/// source per sample, which matters for reproducing a reference stream and for
+/// coupling with low-discrepancy sources.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GaussianMethod {
+    /// The ziggurat method.
+    ///
+    /// Each attempt reads one 64-bit integer, and the vast majority of samples
+    /// are accepted at that point. An attempt that falls outside the layers
+    /// reads one more uniform, or two in the tail, and might be rejected, in
+    /// which case a new attempt is made.
+    Ziggurat,
+    /// The polar Box–Muller method due to Marsaglia.
+    ///
+    /// Each attempt reads two uniforms and is accepted with probability `π/4`,
+    /// which gives about 2.55 uniforms per sample on average. The method yields
+    /// a pair of independent variates, only the first of which is returned.
+    Polar,
+    /// The inversion method.
+    ///
+    /// Each sample reads one uniform, which is passed to the inverse of the
+    /// cumulative distribution function. A uniform equal to zero or one, which
+    /// would map to an infinite value, is rejected and another one is read.
+    Inverse,
+}
+
 impl Gaussian {
     /// Create a Gaussian distribution with mean `mu` and standard deviation
     /// `sigma`.
@@ -21,6 +50,53 @@ impl Gaussian {
         Gaussian { mu: mu, sigma: sigma, norm: (2.0 * PI).sqrt() * sigma }
     }
 
+    /// Create a Gaussian distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(mu: f64, sigma: f64) -> Result<Self, &'static str> {
+        must!(sigma > 0.0, "sigma must be positive");
+        Ok(Gaussian::new(mu, sigma))
+    }
+
+    /// Create a standard Gaussian distribution, that is, one with mean zero and
+    /// standard deviation one.
+    ///
+    /// The computations for the standard distribution skip the shifting and
+    /// scaling, which is also the case when `new` is called with the same
+    /// parameters.
+    #[inline]
+    pub fn standard() -> Self {
+        Gaussian::new(0.0, 1.0)
+    }
+
+    /// Fit a Gaussian distribution to `samples` by maximum likelihood.
+    ///
+    /// The standard deviation is the biased one, that is, the sum of squared
+    /// deviations is divided by the number of samples. It should hold that
+    /// there are at least two distinct samples.
+    pub fn fit(samples: &[f64]) -> Self {
+        let (mean, m2, n) = welford(samples);
+        Gaussian::new(mean, (m2 / n).sqrt())
+    }
+
+    /// Fit a Gaussian distribution to `samples` using the unbiased sample
+    /// variance.
+    ///
+    /// It should hold that there are at least two distinct samples.
+    pub fn fit_unbiased(samples: &[f64]) -> Self {
+        let (mean, m2, n) = welford(samples);
+        Gaussian::new(mean, (m2 / (n - 1.0)).sqrt())
+    }
+
+    /// Return the distribution of the sum of two independent variables
+    /// distributed according to `self` and `other`, respectively.
+    ///
+    /// The sum is Gaussian with the means and the variances added.
+    #[inline]
+    pub fn sum(&self, other: &Gaussian) -> Gaussian {
+        Gaussian::new(self.mu + other.mu, self.sigma.hypot(other.sigma))
+    }
+
     /// Return the mean.
     #[inline(always)]
     pub fn mu(&self) -> f64 { self.mu }
@@ -28,6 +104,28 @@ impl Gaussian {
     /// Return the standard deviation.
     #[inline(always)]
     pub fn sigma(&self) -> f64 { self.sigma }
+
+    /// Draw a sample using a particular method.
+    ///
+    /// `sample` corresponds to `GaussianMethod::Ziggurat`.
+    pub fn sample_with<S>(&self, method: GaussianMethod, source: &mut S) -> f64
+        where S: Source
+    {
+        let z = match method {
+            GaussianMethod::Ziggurat => sample(source),
+            GaussianMethod::Polar => polar(source),
+            GaussianMethod::Inverse => loop {
+                let u = source.read::<f64>();
+                if 0.0 < u && u < 1.0 {
+                    break inverse(u);
+                }
+            },
+        };
+        if self.is_standard() { z } else { self.sigma * z + self.mu }
+    }
+
+    #[inline(always)]
+    fn is_standard(&self) -> bool { self.mu == 0.0 && self.sigma == 1.0 }
 }
 
 impl Default for Gaussian {
@@ -37,19 +135,70 @@ impl Default for Gaussian {
     }
 }
 
+impl fmt::Display for Gaussian {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Gaussian(μ={}, σ={})", self.mu, self.sigma)
+    }
+}
+
+impl distribution::CharacteristicFunction for Gaussian {
+    fn characteristic(&self, t: f64) -> (f64, f64) {
+        let modulus = (-0.5 * (self.sigma * t).powi(2)).exp();
+        let argument = self.mu * t;
+        (modulus * argument.cos(), modulus * argument.sin())
+    }
+}
+
 impl distribution::Continuous for Gaussian {
     fn density(&self, x: f64) -> f64 {
-        (-(x - self.mu).powi(2) / (2.0 * self.sigma * self.sigma)).exp() / self.norm
+        if self.is_standard() {
+            (-(x * x) / 2.0).exp() / self.norm
+        } else {
+            (-(x - self.mu).powi(2) / (2.0 * self.sigma * self.sigma)).exp() / self.norm
+        }
+    }
+
+    fn density_into(&self, xs: &[f64], out: &mut [f64]) {
+        should!(xs.len() == out.len());
+        let (mu, a, b) = (self.mu, -0.5 / (self.sigma * self.sigma), 1.0 / self.norm);
+        for (&x, y) in xs.iter().zip(out.iter_mut()) {
+            *y = (a * (x - mu) * (x - mu)).exp() * b;
+        }
+    }
+
+    #[inline]
+    fn ln_density(&self, x: f64) -> f64 {
+        -(x - self.mu).powi(2) / (2.0 * self.sigma * self.sigma) - self.norm.ln()
     }
 }
 
 impl distribution::Distribution for Gaussian {
     type Value = f64;
 
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is computed via the complementary error function, which
+    /// retains the relative accuracy in the left tail.
+    #[inline]
     fn distribution(&self, x: f64) -> f64 {
-        use special::Error;
-        use std::f64::consts::SQRT_2;
-        (1.0 + ((x - self.mu) / (self.sigma * SQRT_2)).erf()) / 2.0
+        if self.is_standard() {
+            standard_tail(-x, 0.0)
+        } else {
+            tail(self.mu - x, self.sigma)
+        }
+    }
+
+    /// Compute the complementary cumulative distribution function.
+    ///
+    /// The function is computed via the complementary error function, which
+    /// retains the relative accuracy in the right tail.
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        if self.is_standard() {
+            standard_tail(x, 0.0)
+        } else {
+            tail(x - self.mu, self.sigma)
+        }
     }
 }
 
@@ -73,7 +222,11 @@ impl distribution::Inverse for Gaussian {
     /// 2. http://people.sc.fsu.edu/~jburkardt/c_src/asa241/asa241.html
     #[inline(always)]
     fn inverse(&self, p: f64) -> f64 {
-        self.mu + self.sigma * inverse(p)
+        if self.is_standard() {
+            inverse(p)
+        } else {
+            self.mu + self.sigma * inverse(p)
+        }
     }
 }
 
@@ -99,6 +252,21 @@ impl distribution::Modes for Gaussian {
     }
 }
 
+impl distribution::Moment for Gaussian {
+    fn central_moment(&self, n: u32) -> f64 {
+        if n % 2 == 1 {
+            return 0.0;
+        }
+        (1..(n / 2 + 1)).fold(1.0, |product, i| product * (2 * i - 1) as f64) *
+            self.sigma.powi(n as i32)
+    }
+}
+
+impl distribution::Parametric for Gaussian {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
 impl distribution::Sample for Gaussian {
     /// Draw a sample.
     ///
@@ -111,7 +279,7 @@ impl distribution::Sample for Gaussian {
     /// 2. D. Eddelbuettel, “Ziggurat Revisited,” 2014.
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
-        self.sigma * sample(source) + self.mu
+        self.sample_with(GaussianMethod::Ziggurat, source)
     }
 }
 
@@ -120,6 +288,16 @@ impl distribution::Skewness for Gaussian {
     fn skewness(&self) -> f64 { 0.0 }
 }
 
+impl distribution::Support for Gaussian {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        (NEG_INFINITY, INFINITY)
+    }
+}
+
+impl distribution::Symmetric for Gaussian {}
+
 impl distribution::Variance for Gaussian {
     #[inline]
     fn variance(&self) -> f64 {
@@ -237,6 +415,19 @@ pub fn sample<S: Source>(source: &mut S) -> f64 {
     }
 }
 
+// Draw a sample from the standard Gaussian distribution using the polar
+// method.
+fn polar<S: Source>(source: &mut S) -> f64 {
+    loop {
+        let u = 2.0 * source.read::<f64>() - 1.0;
+        let v = 2.0 * source.read::<f64>() - 1.0;
+        let s = u * u + v * v;
+        if 0.0 < s && s < 1.0 {
+            return u * (-2.0 * s.ln() / s).sqrt();
+        }
+    }
+}
+
 const R: f64 = 3.44428647676;
 
 const K: [u32; 128] = [
@@ -344,6 +535,53 @@ const W: [f64; 128] = [
     1.83813550477e-07, 1.92166040885e-07, 2.05295471952e-07, 2.22600839893e-07,
 ];
 
+// Compute 0.5 erfc(d / (σ √2)). The rounding error of the argument, which is
+// amplified by the steepness of the function in the tail, is compensated by a
+// first-order correction.
+#[inline]
+fn tail(d: f64, sigma: f64) -> f64 {
+    let t = d / sigma;
+    standard_tail(t, (-t).mul_add(sigma, d) / sigma)
+}
+
+// Compute 0.5 erfc((t + t_error) / √2), where t_error is the rounding error of
+// t. For an infinite t, the error terms are not defined, and the limits are
+// returned directly.
+fn standard_tail(t: f64, t_error: f64) -> f64 {
+    use special::Error;
+    use std::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+
+    // The rounding error of FRAC_1_SQRT_2.
+    const FRAC_1_SQRT_2_ERROR: f64 = -4.8336466567264567e-17;
+
+    if t.is_infinite() {
+        return if t > 0.0 { 0.0 } else { 1.0 };
+    }
+    let z = t * FRAC_1_SQRT_2;
+    let z_error = t.mul_add(FRAC_1_SQRT_2, -z) + t * FRAC_1_SQRT_2_ERROR +
+        t_error * FRAC_1_SQRT_2;
+    0.5 * (Error::erfc(z) - z_error * FRAC_2_SQRT_PI * (-z * z).exp())
+}
+
+/// Compute the mean, the sum of squared deviations, and the number of samples
+/// in a single pass.
+///
+/// ## References
+///
+/// 1. B. P. Welford, “Note on a method for calculating corrected sums of
+///    squares and products,” Technometrics, vol. 4, no. 3, pp. 419–420, 1962.
+fn welford(samples: &[f64]) -> (f64, f64, f64) {
+    should!(samples.len() > 1);
+    let (mut mean, mut m2, mut n) = (0.0, 0.0, 0.0);
+    for &x in samples {
+        n += 1.0;
+        let delta = x - mean;
+        mean += delta / n;
+        m2 += delta * (x - mean);
+    }
+    (mean, m2, n)
+}
+
 #[cfg(test)]
 mod tests {
     use assert;
@@ -353,6 +591,43 @@ mod tests {
         ($mu:expr, $sigma:expr) => (Gaussian::new($mu, $sigma));
     );
 
+    #[test]
+    fn characteristic() {
+        let d = new!(1.0, 2.0);
+        let t = vec![-1.5, 0.0, 0.5, 2.0];
+        let phi = vec![
+            (7.8581932845145950e-04, -1.1081168353098496e-02), (1.0, 0.0),
+            (5.3228073021567080e-01, 2.9078628821269187e-01),
+            (-1.3960171138142146e-04, 3.0503530434793514e-04),
+        ];
+        for (&t, &(re, im)) in t.iter().zip(&phi) {
+            let (x, y) = d.characteristic(t);
+            assert::close(x, re, 1e-15);
+            assert::close(y, im, 1e-15);
+        }
+    }
+
+    #[test]
+    fn complementary() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+
+        let d = new!(1.0, 2.0);
+        for &x in &[-4.0, -1.0, 0.0, 1.0, 2.5, 4.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+
+        let d = new!(0.0, 1.0);
+        for &x in &[5.0, 10.0, 15.0, 20.0, 30.0, 37.5] {
+            assert_eq!(d.complementary(x), d.distribution(-x));
+        }
+        assert!((d.complementary(10.0) - 7.6198530241605255e-24).abs() <= 1e-15 * 7.6e-24);
+
+        for d in &[new!(0.0, 1.0), new!(1.0, 2.0)] {
+            assert_eq!(d.complementary(NEG_INFINITY), 1.0);
+            assert_eq!(d.complementary(INFINITY), 0.0);
+        }
+    }
+
     #[test]
     fn density() {
         let d = new!(1.0, 2.0);
@@ -372,8 +647,38 @@ mod tests {
         assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-14);
     }
 
+    #[test]
+    fn density_into() {
+        let d = new!(1.0, 2.0);
+        let x = (-40..41).map(|i| 0.25 * i as f64).collect::<Vec<_>>();
+        let mut p = vec![0.0; x.len()];
+        d.density_into(&x, &mut p);
+        assert::close(&p, &x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(0.0, 1.0).to_string(), "Gaussian(μ=0, σ=1)");
+    }
+
+    #[test]
+    fn ln_density() {
+        use std::f64::consts::PI;
+
+        let d = new!(1.0, 2.0);
+        for &x in &[-5.0, -1.0, 0.0, 1.0, 2.5, 10.0] {
+            assert::close(d.ln_density(x), d.density(x).ln(), 1e-14);
+        }
+
+        let x = 1.0 + 40.0 * 2.0;
+        assert_eq!(d.density(x), 0.0);
+        assert::close(d.ln_density(x), -800.0 - (2.0 * (2.0 * PI).sqrt()).ln(), 1e-12);
+    }
+
     #[test]
     fn distribution() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+
         let d = new!(1.0, 2.0);
         let x = vec![
             -4.0, -3.5, -3.0, -2.5, -2.0, -1.5, -1.0, -0.5,
@@ -389,6 +694,23 @@ mod tests {
         ];
 
         assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+
+        let d = new!(0.0, 1.0);
+        let x = vec![-5.0, -10.0, -15.0, -20.0, -30.0, -37.5];
+        let p = vec![
+            2.8665157187919391e-07, 7.6198530241605255e-24, 3.6709661993127508e-51,
+            2.7536241186062337e-89, 4.9067139271481872e-198, 4.6053530095819552e-308,
+        ];
+        for (&x, &p) in x.iter().zip(&p) {
+            assert!((d.distribution(x) - p).abs() <= 1e-15 * p);
+        }
+        assert!((new!(1.0, 2.0).distribution(-29.0) - p[2]).abs() <= 1e-15 * p[2]);
+        assert!((new!(0.0, 0.25).distribution(-2.5) - p[1]).abs() <= 1e-15 * p[1]);
+
+        for d in &[new!(0.0, 1.0), new!(1.0, 2.0)] {
+            assert_eq!(d.distribution(NEG_INFINITY), 0.0);
+            assert_eq!(d.distribution(INFINITY), 1.0);
+        }
     }
 
     #[test]
@@ -397,6 +719,36 @@ mod tests {
         assert_eq!(new!(0.0, 1.0).entropy(), ((2.0 * PI).ln() + 1.0) / 2.0);
     }
 
+    #[test]
+    fn expectation() {
+        let d = new!(1.0, 2.0);
+        assert::close(d.expectation(|x| x), d.mean(), 1e-14);
+        assert::close(d.expectation(|x| x * x), 5.0, 1e-14);
+        assert::close(d.expectation(|x| x.powi(3)), 13.0, 1e-13);
+        assert::close(d.expectation(|x| x.cos()), 1f64.cos() * (-2f64).exp(), 1e-15);
+
+        let d = new!(1e3, 1e-3);
+        assert::close(d.expectation(|_| 1.0), 1.0, 1e-10);
+        assert::close(d.expectation(|x| x), 1e3, 1e-7);
+    }
+
+    #[test]
+    fn fit() {
+        let samples = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+
+        let d = Gaussian::fit(&samples);
+        assert_eq!((d.mu(), d.sigma()), (5.0, 2.0));
+
+        let d = Gaussian::fit_unbiased(&samples);
+        assert_eq!(d.mu(), 5.0);
+        assert::close(d.sigma(), (32.0f64 / 7.0).sqrt(), 1e-15);
+
+        let samples = samples.iter().map(|&x| 1e9 + x).collect::<Vec<_>>();
+        let d = Gaussian::fit(&samples);
+        assert_eq!(d.mu(), 1e9 + 5.0);
+        assert::close(d.sigma(), 2.0, 1e-8);
+    }
+
     #[test]
     fn inverse() {
         use std::f64::{INFINITY, NEG_INFINITY};
@@ -417,6 +769,20 @@ mod tests {
         ];
 
         assert::close(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x, 1e-14);
+
+        let d = new!(0.0, 1.0);
+        assert::close(d.inverse(0.975), 1.959963984540054, 1e-15);
+        assert::close(d.inverse(0.025), -1.959963984540054, 1e-15);
+        assert::close(d.inverse(1e-10), -6.361340902404056, 1e-14);
+        for &p in &[0.01, 0.3, 0.7, 0.99] {
+            assert::close(d.distribution(d.inverse(p)), p, 1e-15);
+        }
+    }
+
+    #[test]
+    fn iqr() {
+        assert::close(new!(0.0, 1.0).iqr(), 1.3489795003921635, 1e-15);
+        assert::close(new!(3.0, 2.0).iqr(), 2.0 * 1.3489795003921635, 1e-15);
     }
 
     #[test]
@@ -432,6 +798,10 @@ mod tests {
     #[test]
     fn median() {
         assert_eq!(new!(0.0, 2.0).median(), 0.0);
+        for &(mu, sigma) in &[(0.0, 1.0), (1.0, 2.0), (-3.0, 0.5)] {
+            let d = new!(mu, sigma);
+            assert::close(d.median(), d.inverse(0.5), 1e-15);
+        }
     }
 
     #[test]
@@ -439,11 +809,150 @@ mod tests {
         assert_eq!(new!(2.0, 5.0).modes(), vec![2.0]);
     }
 
+    #[test]
+    fn moment() {
+        let d = new!(1.0, 2.0);
+        assert_eq!(d.central_moment(2), d.variance());
+        assert_eq!(d.central_moment(3), 0.0);
+        assert_eq!(d.central_moment(4), 48.0);
+        assert_eq!(d.central_moment(6), 15.0 * 64.0);
+        assert_eq!(d.raw_moment(0), 1.0);
+        assert_eq!(d.raw_moment(1), 1.0);
+        assert_eq!(d.raw_moment(2), 5.0);
+        assert_eq!(d.raw_moment(3), 13.0);
+        assert_eq!(d.raw_moment(4), 1.0 + 6.0 * 4.0 + 48.0);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn quartiles() {
+        let (q1, q2, q3) = new!(0.0, 1.0).quartiles();
+        assert::close(q1, -0.67448975019608174, 1e-15);
+        assert_eq!(q2, 0.0);
+        assert::close(q3, 0.67448975019608174, 1e-15);
+    }
+
+    #[test]
+    fn sample_with() {
+        use super::{inverse, GaussianMethod};
+        use source::Source;
+
+        let d = new!(1.0, 2.0);
+        for &method in &[GaussianMethod::Ziggurat, GaussianMethod::Polar,
+                         GaussianMethod::Inverse] {
+            let mut source = source::Xorshift128Plus::new([42, 69]);
+            let n = 100000;
+            let samples = (0..n).map(|_| d.sample_with(method, &mut source)).collect::<Vec<_>>();
+            let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+            let variance = samples.iter().fold(0.0, |a, b| a + (b - mean).powi(2)) / n as f64;
+            assert!((mean - 1.0).abs() < 0.02);
+            assert!((variance - 4.0).abs() < 0.05);
+        }
+
+        let mut source1 = source::Xorshift128Plus::new([42, 69]);
+        let mut source2 = source::Xorshift128Plus::new([42, 69]);
+        for _ in 0..1000 {
+            assert_eq!(d.sample(&mut source1),
+                       d.sample_with(GaussianMethod::Ziggurat, &mut source2));
+        }
+
+        let mut source1 = source::Xorshift128Plus::new([42, 69]);
+        let mut source2 = source::Xorshift128Plus::new([42, 69]);
+        for _ in 0..1000 {
+            assert_eq!(d.sample_with(GaussianMethod::Inverse, &mut source1),
+                       2.0 * inverse(source2.read::<f64>()) + 1.0);
+        }
+
+        let mut source1 = source::Xorshift128Plus::new([42, 69]);
+        let mut source2 = source::Xorshift128Plus::new([42, 69]);
+        for _ in 0..1000 {
+            let z = loop {
+                let u = 2.0 * source2.read::<f64>() - 1.0;
+                let v = 2.0 * source2.read::<f64>() - 1.0;
+                let s = u * u + v * v;
+                if 0.0 < s && s < 1.0 {
+                    break u * (-2.0 * s.ln() / s).sqrt();
+                }
+            };
+            assert_eq!(d.sample_with(GaussianMethod::Polar, &mut source1), 2.0 * z + 1.0);
+        }
+
+        struct Sequence(Vec<u64>);
+        impl Source for Sequence {
+            fn read_u64(&mut self) -> u64 { self.0.remove(0) }
+        }
+
+        let mut source = Sequence(vec![0, ::std::u64::MAX, 1 << 63]);
+        assert_eq!(d.sample_with(GaussianMethod::Inverse, &mut source), 1.0);
+        assert!(source.0.is_empty());
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(0.0, 2.0).skewness(), 0.0);
     }
 
+    #[test]
+    fn standard() {
+        use super::{inverse, sample, tail};
+
+        let d = Gaussian::standard();
+        assert_eq!((d.mu(), d.sigma()), (0.0, 1.0));
+        for &x in &[-40.0, -10.0, -1.5, -0.1, 0.0, 0.3, 1.0, 2.5, 8.0, 40.0] {
+            let norm = (2.0 * ::std::f64::consts::PI).sqrt();
+            assert_eq!(d.density(x), (-(x - 0.0f64).powi(2) / 2.0).exp() / norm);
+            assert_eq!(d.distribution(x), tail(0.0 - x, 1.0));
+            assert_eq!(d.complementary(x), tail(x - 0.0, 1.0));
+        }
+        for &p in &[0.0, 1e-300, 0.02, 0.3, 0.5, 0.7, 0.98, 1.0] {
+            assert_eq!(d.inverse(p), 0.0 + 1.0 * inverse(p));
+        }
+
+        let mut source1 = source::Xorshift128Plus::new([42, 69]);
+        let mut source2 = source::Xorshift128Plus::new([42, 69]);
+        for _ in 0..1000 {
+            assert_eq!(d.sample(&mut source1), 1.0 * sample(&mut source2) + 0.0);
+        }
+    }
+
+    #[test]
+    fn sum() {
+        let d = new!(1.0, 3.0).sum(&new!(-3.0, 4.0));
+        assert_eq!((d.mu(), d.sigma()), (-2.0, 5.0));
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        assert_eq!(new!(1.0, 2.0).support(), (NEG_INFINITY, INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Gaussian::try_new(1.0, 2.0).is_ok());
+        assert_eq!(Gaussian::try_new(1.0, 0.0).err(), Some("sigma must be positive"));
+        assert_eq!(Gaussian::try_new(1.0, ::std::f64::NAN).err(), Some("sigma must be positive"));
+    }
+
+    #[test]
+    fn two_sided_p() {
+        let d = new!(0.0, 1.0);
+        assert::close(d.two_sided_p(1.96), 0.049995790296440868, 1e-16);
+        assert::close(d.two_sided_p(-1.96), 0.049995790296440868, 1e-16);
+        assert::close(d.two_sided_p(2.5758293035489004), 0.01, 1e-16);
+        assert::close(d.two_sided_p(1.0), 0.31731050786291410, 1e-15);
+        assert::close(d.two_sided_p(-3.0), 2.6997960632601891e-03, 1e-17);
+        assert::close(d.two_sided_p(10.0) / 1.5239706048321052e-23, 1.0, 1e-14);
+        assert_eq!(d.two_sided_p(0.0), 1.0);
+
+        let d = new!(1.0, 2.0);
+        assert::close(d.two_sided_p(1.0 + 2.0 * 1.96), 0.049995790296440868, 1e-16);
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(0.0, 2.0).variance(), 4.0);
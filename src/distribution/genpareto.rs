@@ -0,0 +1,351 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A generalized Pareto distribution.
+///
+/// The distribution is the limiting distribution of the excesses over a high
+/// threshold. With `z = (x - μ) / σ`, the survival function is `(1 + ξz)^(-1
+/// / ξ)`, which becomes `e^-z` in the limit `ξ → 0`. The support is `[μ, ∞)`
+/// for `ξ ≥ 0` and `[μ, μ - σ / ξ]` for `ξ < 0`.
+#[derive(Clone, Copy)]
+pub struct GeneralizedPareto {
+    mu: f64,
+    sigma: f64,
+    xi: f64,
+}
+
+impl GeneralizedPareto {
+    /// Create a generalized Pareto distribution with location parameter `mu`,
+    /// scale parameter `sigma`, and shape parameter `xi`.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64, xi: f64) -> Self {
+        should!(sigma > 0.0 && xi.is_finite());
+        GeneralizedPareto { mu: mu, sigma: sigma, xi: xi }
+    }
+
+    /// Create a generalized Pareto distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(mu: f64, sigma: f64, xi: f64) -> Result<Self, &'static str> {
+        must!(mu.is_finite(), "mu must be finite");
+        must!(sigma > 0.0, "sigma must be positive");
+        must!(xi.is_finite(), "xi must be finite");
+        Ok(GeneralizedPareto::new(mu, sigma, xi))
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 { self.mu }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 { self.sigma }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn xi(&self) -> f64 { self.xi }
+
+    // Compute the cumulative hazard `ln(1 + ξz) / ξ`, which is `z` for `ξ = 0`,
+    // or return `None` if `x` lies outside the support.
+    #[inline]
+    fn cumulative_hazard(&self, x: f64) -> Option<f64> {
+        let z = (x - self.mu) / self.sigma;
+        if z < 0.0 {
+            None
+        } else if self.xi == 0.0 {
+            Some(z)
+        } else if self.xi * z <= -1.0 {
+            None
+        } else {
+            Some((self.xi * z).ln_1p() / self.xi)
+        }
+    }
+}
+
+impl fmt::Display for GeneralizedPareto {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "GeneralizedPareto(μ={}, σ={}, ξ={})", self.mu, self.sigma, self.xi)
+    }
+}
+
+impl distribution::Continuous for GeneralizedPareto {
+    /// Compute the probability density function, which is `(1 + ξz)^(-1 / ξ -
+    /// 1) / σ`, or `e^-z / σ` for `ξ = 0`.
+    fn density(&self, x: f64) -> f64 {
+        let z = (x - self.mu) / self.sigma;
+        if z < 0.0 || self.xi * z <= -1.0 {
+            0.0
+        } else if self.xi == 0.0 {
+            (-z).exp() / self.sigma
+        } else {
+            (-(1.0 + 1.0 / self.xi) * (self.xi * z).ln_1p()).exp() / self.sigma
+        }
+    }
+}
+
+impl distribution::Distribution for GeneralizedPareto {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        match self.cumulative_hazard(x) {
+            Some(h) => -(-h).exp_m1(),
+            None => if x < self.mu { 0.0 } else { 1.0 },
+        }
+    }
+
+    fn complementary(&self, x: f64) -> f64 {
+        match self.cumulative_hazard(x) {
+            Some(h) => (-h).exp(),
+            None => if x < self.mu { 1.0 } else { 0.0 },
+        }
+    }
+}
+
+impl distribution::Inverse for GeneralizedPareto {
+    /// Compute the inverse of the cumulative distribution function, which is
+    /// `μ + σ ((1 - p)^-ξ - 1) / ξ`, or `μ - σ ln(1 - p)` for `ξ = 0`.
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        let h = -(-p).ln_1p();
+        if self.xi == 0.0 {
+            self.mu + self.sigma * h
+        } else {
+            self.mu + self.sigma * (self.xi * h).exp_m1() / self.xi
+        }
+    }
+}
+
+impl distribution::Mean for GeneralizedPareto {
+    /// Compute the mean, which is infinite for `ξ ≥ 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.xi >= 1.0 {
+            return ::std::f64::INFINITY;
+        }
+        self.mu + self.sigma / (1.0 - self.xi)
+    }
+}
+
+impl distribution::Median for GeneralizedPareto {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Parametric for GeneralizedPareto {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 3 }
+}
+
+impl distribution::Sample for GeneralizedPareto {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Support for GeneralizedPareto {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        if self.xi < 0.0 {
+            (self.mu, self.mu - self.sigma / self.xi)
+        } else {
+            (self.mu, INFINITY)
+        }
+    }
+}
+
+impl distribution::Variance for GeneralizedPareto {
+    /// Compute the variance, which is infinite for `ξ ≥ 1 / 2`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        let xi = self.xi;
+        if xi >= 0.5 {
+            return ::std::f64::INFINITY;
+        }
+        self.sigma * self.sigma / ((1.0 - xi) * (1.0 - xi) * (1.0 - 2.0 * xi))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $sigma:expr, $xi:expr) => (GeneralizedPareto::new($mu, $sigma, $xi));
+    );
+
+    #[test]
+    fn complementary() {
+        for &xi in &[-0.5, 0.0, 0.5] {
+            let d = new!(1.0, 2.0, xi);
+            for &x in &[0.0, 1.0, 1.5, 3.0, 4.0, 5.0, 10.0] {
+                assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+            }
+        }
+    }
+
+    #[test]
+    fn continuity() {
+        let x = vec![1.0, 1.5, 2.0, 3.0, 5.0, 10.0];
+        let e = new!(1.0, 2.0, 0.0);
+        for &xi in &[1e-6, -1e-6, 1e-10, -1e-10] {
+            let d = new!(1.0, 2.0, xi);
+            let tolerance = 1e2 * (xi as f64).abs();
+            for &x in &x {
+                assert::close(d.density(x), e.density(x), tolerance);
+                assert::close(d.distribution(x), e.distribution(x), tolerance);
+            }
+            for &p in &[0.1, 0.5, 0.9] {
+                assert::close(d.inverse(p), e.inverse(p), tolerance);
+            }
+            assert::close(d.mean(), e.mean(), tolerance);
+            assert::close(d.variance(), e.variance(), tolerance);
+        }
+    }
+
+    #[test]
+    fn density() {
+        let x = vec![0.0, 1.0, 1.5, 3.0, 4.0, 5.0, 10.0];
+
+        let d = new!(1.0, 2.0, 0.5);
+        let p = vec![
+            0.0000000000000000e+00, 5.0000000000000000e-01, 3.5116598079561040e-01,
+            1.4814814814814814e-01, 9.3294460641399415e-02, 6.2500000000000000e-02,
+            1.4565316340464270e-02,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.0, 2.0, -0.5);
+        let p = vec![
+            0.0000000000000000e+00, 5.0000000000000000e-01, 4.3750000000000000e-01,
+            2.5000000000000000e-01, 1.2500000000000000e-01, 0.0000000000000000e+00,
+            0.0000000000000000e+00,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.0, 2.0, 0.0);
+        let e = Exponential::new(0.5);
+        for &x in &x {
+            assert::close(d.density(x), e.density(x - 1.0), 1e-15);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 2.0, -0.5).to_string(), "GeneralizedPareto(μ=1, σ=2, ξ=-0.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let x = vec![0.0, 1.0, 1.5, 3.0, 4.0, 5.0, 10.0];
+
+        let d = new!(1.0, 2.0, 0.5);
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 2.0987654320987653e-01,
+            5.5555555555555558e-01, 6.7346938775510201e-01, 7.5000000000000000e-01,
+            9.0532544378698221e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.0, 2.0, -0.5);
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 2.3437500000000000e-01,
+            7.5000000000000000e-01, 9.3750000000000000e-01, 1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.0, 2.0, 0.0);
+        let e = Exponential::new(0.5);
+        for &x in &x {
+            assert::close(d.distribution(x), e.distribution(x - 1.0), 1e-15);
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::INFINITY;
+
+        for &xi in &[-0.5, 0.0, 0.5] {
+            let d = new!(1.0, 2.0, xi);
+            for &x in &[1.5, 2.0, 3.0, 4.5] {
+                assert::close(d.inverse(d.distribution(x)), x, 1e-14);
+            }
+            assert_eq!(d.inverse(0.0), 1.0);
+        }
+        assert_eq!(new!(1.0, 2.0, 0.5).inverse(1.0), INFINITY);
+        assert_eq!(new!(1.0, 2.0, 0.0).inverse(1.0), INFINITY);
+        assert_eq!(new!(1.0, 2.0, -0.5).inverse(1.0), 5.0);
+    }
+
+    #[test]
+    fn mean() {
+        use std::f64::INFINITY;
+        assert_eq!(new!(1.0, 2.0, 0.5).mean(), 5.0);
+        assert_eq!(new!(1.0, 2.0, 0.0).mean(), 3.0);
+        assert::close(new!(1.0, 2.0, -0.5).mean(), 1.0 + 2.0 / 1.5, 1e-15);
+        assert_eq!(new!(1.0, 2.0, 1.0).mean(), INFINITY);
+        assert_eq!(new!(1.0, 2.0, 2.0).mean(), INFINITY);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(1.0, 2.0, 0.5).median(), 1.0 + 4.0 * (2f64.sqrt() - 1.0), 1e-15);
+        assert::close(new!(1.0, 2.0, 0.0).median(), 1.0 + 2.0 * 2f64.ln(), 1e-15);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0, 0.5).num_parameters(), 3);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        for &xi in &[-0.5, 0.0, 0.1] {
+            let d = new!(1.0, 2.0, xi);
+            let (a, b) = d.support();
+            let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+            assert!(samples.iter().all(|&x| a <= x && x <= b));
+            let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+            assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::INFINITY;
+        assert_eq!(new!(1.0, 2.0, 0.5).support(), (1.0, INFINITY));
+        assert_eq!(new!(1.0, 2.0, 0.0).support(), (1.0, INFINITY));
+        assert_eq!(new!(1.0, 2.0, -0.5).support(), (1.0, 5.0));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(GeneralizedPareto::try_new(1.0, 2.0, -0.5).is_ok());
+        assert_eq!(GeneralizedPareto::try_new(::std::f64::NAN, 2.0, 0.5).err(),
+                   Some("mu must be finite"));
+        assert_eq!(GeneralizedPareto::try_new(1.0, 0.0, 0.5).err(), Some("sigma must be positive"));
+        assert_eq!(GeneralizedPareto::try_new(1.0, 2.0, ::std::f64::NAN).err(),
+                   Some("xi must be finite"));
+    }
+
+    #[test]
+    fn variance() {
+        use std::f64::INFINITY;
+        assert::close(new!(1.0, 2.0, 0.25).variance(), 4.0 / (0.5625 * 0.5), 1e-14);
+        assert_eq!(new!(1.0, 2.0, 0.0).variance(), 4.0);
+        assert_eq!(new!(1.0, 2.0, 0.5).variance(), INFINITY);
+    }
+}
@@ -0,0 +1,253 @@
+use distribution;
+use source::Source;
+use std::fmt;
+use std::ops::RangeFrom;
+
+/// A geometric distribution.
+///
+/// The distribution models the number of Bernoulli trials needed to get the
+/// first success, including the successful trial itself. Therefore, the
+/// support is `{1, 2, 3, …}`.
+#[derive(Clone, Copy)]
+pub struct Geometric {
+    p: f64,
+    q: f64,
+}
+
+impl Geometric {
+    /// Create a geometric distribution with success probability `p`.
+    ///
+    /// It should hold that `p > 0` and `p <= 1`.
+    #[inline]
+    pub fn new(p: f64) -> Self {
+        should!(p > 0.0 && p <= 1.0);
+        Geometric { p: p, q: 1.0 - p }
+    }
+
+    /// Create a geometric distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(p: f64) -> Result<Self, &'static str> {
+        must!(p > 0.0 && p <= 1.0, "p must be in (0, 1]");
+        Ok(Geometric::new(p))
+    }
+
+    /// Return the success probability.
+    #[inline(always)]
+    pub fn p(&self) -> f64 { self.p }
+
+    /// Return the failure probability.
+    #[inline(always)]
+    pub fn q(&self) -> f64 { self.q }
+}
+
+impl fmt::Display for Geometric {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Geometric(p={})", self.p)
+    }
+}
+
+impl distribution::Discrete for Geometric {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x == 0 {
+            0.0
+        } else {
+            self.q.powi(x as i32 - 1) * self.p
+        }
+    }
+}
+
+impl distribution::DiscreteSupport for Geometric {
+    type Iter = RangeFrom<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (1, ::std::i64::MAX)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        1..
+    }
+}
+
+impl distribution::Distribution for Geometric {
+    type Value = usize;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            0.0
+        } else {
+            -(x.floor() * (-self.p).ln_1p()).exp_m1()
+        }
+    }
+}
+
+impl distribution::Entropy for Geometric {
+    fn entropy(&self) -> f64 {
+        if self.q == 0.0 {
+            0.0
+        } else {
+            (-self.q * self.q.ln() - self.p * self.p.ln()) / self.p
+        }
+    }
+}
+
+impl distribution::Kurtosis for Geometric {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        6.0 + self.p * self.p / self.q
+    }
+}
+
+impl distribution::Mean for Geometric {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.p.recip()
+    }
+}
+
+impl distribution::Modes for Geometric {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Parametric for Geometric {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for Geometric {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> usize where S: Source {
+        if self.q == 0.0 {
+            return 1;
+        }
+        let x = (source.read::<f64>().ln() / (-self.p).ln_1p()).ceil();
+        if x < 1.0 { 1 } else { x as usize }
+    }
+}
+
+impl distribution::Skewness for Geometric {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        (2.0 - self.p) / self.q.sqrt()
+    }
+}
+
+impl distribution::Variance for Geometric {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.q / (self.p * self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($p:expr) => (Geometric::new($p));
+    );
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(0.2).to_string(), "Geometric(p=0.2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(0.25);
+        let p = vec![
+            0.0, 0.0, 0.25, 0.4375, 0.578125, 0.68359375, 0.7626953125, 0.822021484375,
+            0.86651611328125, 0.8998870849609375,
+        ];
+
+        let x = (-1..9).map(|x| d.distribution(x as f64)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+
+        let x = (-1..9).map(|x| d.distribution(x as f64 + 0.5)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(0.25).entropy(), 2.2493405784752334, 1e-15);
+        assert_eq!(new!(1.0).entropy(), 0.0);
+    }
+
+    #[test]
+    fn iter_support() {
+        let d = new!(0.25);
+        assert_eq!(d.iter_support().take(3).collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(0.25).kurtosis(), 6.0833333333333333, 1e-15);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(0.25);
+        let p = vec![
+            0.0, 0.25, 0.1875, 0.140625, 0.10546875, 0.0791015625, 0.059326171875,
+            0.04449462890625, 0.0333709716796875,
+        ];
+
+        assert::close(&(0..9).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(0.25).mean(), 4.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(0.25).modes(), vec![1]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(0.25).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+
+        assert!(Independent(&new!(0.25), &mut source).take(1000).all(|x| x >= 1));
+        assert!(Independent(&new!(1.0), &mut source).take(100).all(|x| x == 1));
+
+        let n = 10000;
+        let sum = Independent(&new!(0.25), &mut source).take(n).fold(0, |a, b| a + b);
+        assert!((sum as f64 / n as f64 - 4.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(0.25).skewness(), 2.0207259421636902, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(0.25).support(), (1, ::std::i64::MAX));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Geometric::try_new(1.0).is_ok());
+        assert_eq!(Geometric::try_new(0.0).err(), Some("p must be in (0, 1]"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(0.25).variance(), 12.0);
+    }
+}
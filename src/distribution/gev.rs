@@ -0,0 +1,406 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A generalized extreme value distribution.
+///
+/// The distribution is the limiting distribution of the maxima of blocks of
+/// observations. With `z = (x - μ) / σ`, the cumulative distribution function
+/// is `exp(-(1 + ξz)^(-1 / ξ))`, which unifies the Gumbel (`ξ = 0`), Fréchet
+/// (`ξ > 0`), and reversed Weibull (`ξ < 0`) distributions.
+#[derive(Clone, Copy)]
+pub struct Gev {
+    mu: f64,
+    sigma: f64,
+    xi: f64,
+}
+
+impl Gev {
+    /// Create a generalized extreme value distribution with location parameter
+    /// `mu`, scale parameter `sigma`, and shape parameter `xi`.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64, xi: f64) -> Self {
+        should!(sigma > 0.0 && xi.is_finite());
+        Gev { mu: mu, sigma: sigma, xi: xi }
+    }
+
+    /// Create a generalized extreme value distribution or return an error if
+    /// the parameters are invalid.
+    #[inline]
+    pub fn try_new(mu: f64, sigma: f64, xi: f64) -> Result<Self, &'static str> {
+        must!(mu.is_finite(), "mu must be finite");
+        must!(sigma > 0.0, "sigma must be positive");
+        must!(xi.is_finite(), "xi must be finite");
+        Ok(Gev::new(mu, sigma, xi))
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 { self.mu }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 { self.sigma }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn xi(&self) -> f64 { self.xi }
+
+    // Compute `ln(1 + ξz) / ξ`, which is `z` for `ξ = 0`, so that the
+    // cumulative distribution function is `exp(-e^-y)`. Outside the support,
+    // the result is infinite with the sign of the side it lies on.
+    #[inline]
+    fn reduce(&self, x: f64) -> f64 {
+        use std::f64::INFINITY;
+        let z = (x - self.mu) / self.sigma;
+        if self.xi == 0.0 {
+            z
+        } else if self.xi * z <= -1.0 {
+            if self.xi > 0.0 { -INFINITY } else { INFINITY }
+        } else {
+            (self.xi * z).ln_1p() / self.xi
+        }
+    }
+}
+
+impl fmt::Display for Gev {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Gev(μ={}, σ={}, ξ={})", self.mu, self.sigma, self.xi)
+    }
+}
+
+impl distribution::Continuous for Gev {
+    /// Compute the probability density function, which is `t^(ξ + 1) e^-t /
+    /// σ` where `t = (1 + ξz)^(-1 / ξ)`.
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        let y = self.reduce(x);
+        if y.is_infinite() {
+            0.0
+        } else {
+            (-(1.0 + self.xi) * y - (-y).exp()).exp() / self.sigma
+        }
+    }
+}
+
+impl distribution::Distribution for Gev {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        (-(-self.reduce(x)).exp()).exp()
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        -(-(-self.reduce(x)).exp()).exp_m1()
+    }
+}
+
+impl distribution::Inverse for Gev {
+    /// Compute the inverse of the cumulative distribution function, which is
+    /// `μ + σ ((-ln p)^-ξ - 1) / ξ`, or `μ - σ ln(-ln p)` for `ξ = 0`.
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        let y = -(-p.ln()).ln();
+        if self.xi == 0.0 {
+            self.mu + self.sigma * y
+        } else {
+            self.mu + self.sigma * (self.xi * y).exp_m1() / self.xi
+        }
+    }
+}
+
+impl distribution::Mean for Gev {
+    /// Compute the mean, which is `μ + σ (Γ(1 - ξ) - 1) / ξ`, or `μ + σγ` for
+    /// `ξ = 0` where `γ` is the Euler–Mascheroni constant, and which is
+    /// infinite for `ξ ≥ 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.xi >= 1.0 {
+            return ::std::f64::INFINITY;
+        }
+        self.mu + self.sigma * moments(self.xi).0
+    }
+}
+
+impl distribution::Median for Gev {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Parametric for Gev {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 3 }
+}
+
+impl distribution::Sample for Gev {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Support for Gev {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        if self.xi > 0.0 {
+            (self.mu - self.sigma / self.xi, INFINITY)
+        } else if self.xi < 0.0 {
+            (-INFINITY, self.mu - self.sigma / self.xi)
+        } else {
+            (-INFINITY, INFINITY)
+        }
+    }
+}
+
+impl distribution::Variance for Gev {
+    /// Compute the variance, which is `σ² (Γ(1 - 2ξ) - Γ²(1 - ξ)) / ξ²`, or
+    /// `σ² π² / 6` for `ξ = 0`, and which is infinite for `ξ ≥ 1 / 2`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        if self.xi >= 0.5 {
+            return ::std::f64::INFINITY;
+        }
+        self.sigma * self.sigma * moments(self.xi).1
+    }
+}
+
+// Compute `(Γ(1 - ξ) - 1) / ξ` and `(Γ(1 - 2ξ) - Γ²(1 - ξ)) / ξ²`.
+//
+// Near zero, both are evaluated via the Taylor series `ln Γ(1 - x) = γx + Σ
+// ζ(k) x^k / k`, which avoids the cancellation and tends to `γ` and `ζ(2) =
+// π² / 6` as `ξ → 0`.
+fn moments(xi: f64) -> (f64, f64) {
+    use special::Gamma;
+
+    const EULER_MASCHERONI: f64 = 0.5772156649015329;
+    const ZETA: [f64; 12] = [
+        1.6449340668482264e+00, 1.2020569031595942e+00, 1.0823232337111381e+00,
+        1.0369277551433700e+00, 1.0173430619844492e+00, 1.0083492773819229e+00,
+        1.0040773561979444e+00, 1.0020083928260821e+00, 1.0009945751278180e+00,
+        1.0004941886041194e+00, 1.0002460865533080e+00, 1.0001227133475785e+00,
+    ];
+
+    if xi.abs() >= 1e-2 {
+        let (first, second) = (Gamma::gamma(1.0 - xi), Gamma::gamma(1.0 - 2.0 * xi));
+        return ((first - 1.0) / xi, (second - first * first) / (xi * xi));
+    }
+
+    // The sums are `ln Γ(1 - ξ) / ξ` and `(ln Γ(1 - 2ξ) - 2 ln Γ(1 - ξ)) / ξ²`.
+    let (mut first, mut second, mut power) = (EULER_MASCHERONI, 0.0, 1.0);
+    for (i, &zeta) in ZETA.iter().enumerate() {
+        let k = (i + 2) as f64;
+        first += zeta * power * xi / k;
+        second += zeta * (2f64.powi(i as i32 + 2) - 2.0) * power / k;
+        power *= xi;
+    }
+    let exprel = |y: f64| if y == 0.0 { 1.0 } else { y.exp_m1() / y };
+    (first * exprel(first * xi),
+     (2.0 * first * xi).exp() * second * exprel(second * xi * xi))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $sigma:expr, $xi:expr) => (Gev::new($mu, $sigma, $xi));
+    );
+
+    #[test]
+    fn complementary() {
+        for &xi in &[-0.5, 0.0, 0.5] {
+            let d = new!(1.0, 2.0, xi);
+            for &x in &[-3.0, -1.0, 0.0, 1.0, 3.0, 5.0, 10.0] {
+                assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+            }
+        }
+        assert::close(new!(1.0, 2.0, 0.0).complementary(81.0), 4.2483542552915889e-18, 1e-30);
+    }
+
+    #[test]
+    fn continuity() {
+        let e = new!(1.0, 2.0, 0.0);
+        for &xi in &[1e-3, -1e-3, 1e-6, -1e-6, 1e-10, -1e-10] {
+            let d = new!(1.0, 2.0, xi);
+            let tolerance = 1e2 * (xi as f64).abs();
+            for &x in &[-3.0, -1.0, 0.0, 1.0, 3.0, 5.0, 10.0] {
+                assert::close(d.density(x), e.density(x), tolerance);
+                assert::close(d.distribution(x), e.distribution(x), tolerance);
+            }
+            for &p in &[0.1, 0.5, 0.9] {
+                assert::close(d.inverse(p), e.inverse(p), tolerance);
+            }
+            assert::close(d.mean(), e.mean(), tolerance);
+            assert::close(d.variance(), e.variance(), tolerance);
+        }
+    }
+
+    #[test]
+    fn density() {
+        let x = vec![-3.0, -1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+
+        let d = new!(1.0, 2.0, 0.5);
+        let p = vec![
+            0.0000000000000000e+00, 7.3262555554936715e-02, 2.0031207751830055e-01,
+            2.0216411687591443e-01, 1.8393972058572117e-01, 1.3498686055502043e-01,
+            9.4989687174808088e-02, 4.8675048941962805e-02, 1.3249615765535341e-02,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.0, 2.0, -0.5);
+        let p = vec![
+            1.8315638888734179e-02, 7.9049418421398246e-02, 1.3100711696943615e-01,
+            1.5866041032777120e-01, 1.8393972058572117e-01, 2.1366855927409611e-01,
+            1.9470019576785122e-01, 0.0000000000000000e+00, 0.0000000000000000e+00,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let (d, e) = (new!(1.0, 2.0, 0.0), Gumbel::new(1.0, 2.0));
+        for &x in &x {
+            assert::close(d.density(x), e.density(x), 1e-15);
+        }
+        let (d, e) = (new!(1.0, 2.0, 0.5), Frechet::new(2.0, 4.0, -3.0));
+        for &x in &x {
+            assert::close(d.density(x), e.density(x), 1e-15);
+        }
+        let (d, e) = (new!(1.0, 2.0, -0.5), Weibull::new(4.0, 2.0));
+        for &x in &x {
+            assert::close(d.density(x), e.density(5.0 - x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 2.0, -0.5).to_string(), "Gev(μ=1, σ=2, ξ=-0.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let x = vec![-3.0, -1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+
+        let d = new!(1.0, 2.0, 0.5);
+        let p = vec![
+            0.0000000000000000e+00, 1.8315638888734179e-02, 1.6901331540606607e-01,
+            2.7086832847046349e-01, 3.6787944117144233e-01, 5.2729242404304855e-01,
+            6.4118038842995462e-01, 7.7880078307140488e-01, 9.0966893240253577e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.0, 2.0, -0.5);
+        let p = vec![
+            1.8315638888734179e-02, 1.0539922456186433e-01, 2.0961138715109781e-01,
+            2.8206295169381546e-01, 3.6787944117144233e-01, 5.6978282473092301e-01,
+            7.7880078307140488e-01, 1.0000000000000000e+00, 1.0000000000000000e+00,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let (d, e) = (new!(1.0, 2.0, 0.0), Gumbel::new(1.0, 2.0));
+        for &x in &x {
+            assert::close(d.distribution(x), e.distribution(x), 1e-15);
+        }
+        let (d, e) = (new!(1.0, 2.0, 0.5), Frechet::new(2.0, 4.0, -3.0));
+        for &x in &x {
+            assert::close(d.distribution(x), e.distribution(x), 1e-15);
+        }
+        let (d, e) = (new!(1.0, 2.0, -0.5), Weibull::new(4.0, 2.0));
+        for &x in &x {
+            assert::close(d.distribution(x), e.complementary(5.0 - x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::INFINITY;
+
+        for &xi in &[-0.5, 0.0, 0.5] {
+            let d = new!(1.0, 2.0, xi);
+            for &x in &[-1.0, 0.0, 1.0, 2.0, 4.5] {
+                assert::close(d.inverse(d.distribution(x)), x, 1e-13);
+            }
+        }
+        assert_eq!(new!(1.0, 2.0, 0.5).inverse(0.0), -3.0);
+        assert_eq!(new!(1.0, 2.0, 0.5).inverse(1.0), INFINITY);
+        assert_eq!(new!(1.0, 2.0, 0.0).inverse(0.0), -INFINITY);
+        assert_eq!(new!(1.0, 2.0, 0.0).inverse(1.0), INFINITY);
+        assert_eq!(new!(1.0, 2.0, -0.5).inverse(0.0), -INFINITY);
+        assert_eq!(new!(1.0, 2.0, -0.5).inverse(1.0), 5.0);
+    }
+
+    #[test]
+    fn mean() {
+        use std::f64::INFINITY;
+        assert::close(new!(1.0, 2.0, 0.25).mean(), 2.8033336197214211, 1e-14);
+        assert::close(new!(1.0, 2.0, 0.02).mean(), 2.1947355812511105, 1e-14);
+        assert::close(new!(1.0, 2.0, 0.005).mean(), 2.1643675103759037, 1e-15);
+        assert::close(new!(1.0, 2.0, 0.0).mean(), 2.1544313298030655, 1e-15);
+        assert::close(new!(1.0, 2.0, -0.005).mean(), 2.1445858995928861, 1e-15);
+        assert::close(new!(1.0, 2.0, -0.5).mean(), 1.455092298188968, 1e-15);
+        assert_eq!(new!(1.0, 2.0, 1.0).mean(), INFINITY);
+        assert_eq!(new!(1.0, 2.0, 2.0).mean(), INFINITY);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(1.0, 2.0, 0.5).median(), 1.8044896351457991, 1e-15);
+        assert::close(new!(1.0, 2.0, 0.0).median(), Gumbel::new(1.0, 2.0).median(), 1e-15);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0, 0.5).num_parameters(), 3);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        for &xi in &[-0.5, 0.0, 0.5] {
+            let d = new!(1.0, 2.0, xi);
+            let (a, b) = d.support();
+            let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+            assert!(samples.iter().all(|&x| a <= x && x <= b));
+            let median = d.median();
+            let below = samples.iter().filter(|&&x| x <= median).count() as f64 / n as f64;
+            assert!((below - 0.5).abs() < 5.0 * (0.25 / n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::INFINITY;
+        assert_eq!(new!(1.0, 2.0, 0.5).support(), (-3.0, INFINITY));
+        assert_eq!(new!(1.0, 2.0, 0.0).support(), (-INFINITY, INFINITY));
+        assert_eq!(new!(1.0, 2.0, -0.5).support(), (-INFINITY, 5.0));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Gev::try_new(1.0, 2.0, -0.5).is_ok());
+        assert_eq!(Gev::try_new(::std::f64::NAN, 2.0, 0.5).err(), Some("mu must be finite"));
+        assert_eq!(Gev::try_new(1.0, 0.0, 0.5).err(), Some("sigma must be positive"));
+        assert_eq!(Gev::try_new(1.0, 2.0, ::std::f64::INFINITY).err(), Some("xi must be finite"));
+    }
+
+    #[test]
+    fn variance() {
+        use std::f64::INFINITY;
+        assert::close(new!(1.0, 2.0, 0.25).variance(), 17.331696398392722, 1e-13);
+        assert::close(new!(1.0, 2.0, 0.02).variance(), 6.9436193165733666, 1e-12);
+        assert::close(new!(1.0, 2.0, 0.005).variance(), 6.6669830573049298, 1e-14);
+        assert::close(new!(1.0, 2.0, 0.0).variance(), 6.5797362673929056, 1e-14);
+        assert::close(new!(1.0, 2.0, -0.005).variance(), 6.4948334337278499, 1e-14);
+        assert::close(new!(1.0, 2.0, -0.5).variance(), 3.4336293856408271, 1e-14);
+        assert_eq!(new!(1.0, 2.0, 0.5).variance(), INFINITY);
+    }
+}
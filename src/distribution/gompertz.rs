@@ -0,0 +1,274 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Gompertz distribution.
+///
+/// The hazard function of the distribution is `η b e^(bx)`, which grows
+/// exponentially with age, so that it is a classical model of adult human
+/// mortality. The parameter `η` is the shape, and `b` is the scale.
+#[derive(Clone, Copy)]
+pub struct Gompertz {
+    eta: f64,
+    b: f64,
+}
+
+impl Gompertz {
+    /// Create a Gompertz distribution with shape parameter `eta` and scale
+    /// parameter `b`.
+    ///
+    /// It should hold that `eta > 0` and `b > 0`.
+    #[inline]
+    pub fn new(eta: f64, b: f64) -> Self {
+        should!(eta > 0.0 && b > 0.0);
+        Gompertz { eta: eta, b: b }
+    }
+
+    /// Create a Gompertz distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(eta: f64, b: f64) -> Result<Self, &'static str> {
+        must!(eta > 0.0, "eta must be positive");
+        must!(b > 0.0, "b must be positive");
+        Ok(Gompertz::new(eta, b))
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn eta(&self) -> f64 { self.eta }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn b(&self) -> f64 { self.b }
+}
+
+impl fmt::Display for Gompertz {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Gompertz(η={}, b={})", self.eta, self.b)
+    }
+}
+
+impl distribution::Continuous for Gompertz {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            let y = self.b * x;
+            self.eta * self.b * (y - self.eta * y.exp_m1()).exp()
+        }
+    }
+}
+
+impl distribution::Distribution for Gompertz {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function, which is `1 - exp(-η
+    /// (e^(bx) - 1))`.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            -(-self.eta * (self.b * x).exp_m1()).exp_m1()
+        }
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            (-self.eta * (self.b * x).exp_m1()).exp()
+        }
+    }
+}
+
+impl distribution::Inverse for Gompertz {
+    /// Compute the inverse of the cumulative distribution function, which is
+    /// `ln(1 - ln(1 - p) / η) / b`.
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        (-(-p).ln_1p() / self.eta).ln_1p() / self.b
+    }
+}
+
+impl distribution::Median for Gompertz {
+    #[inline]
+    fn median(&self) -> f64 {
+        use std::f64::consts::LN_2;
+        (LN_2 / self.eta).ln_1p() / self.b
+    }
+}
+
+impl distribution::Modes for Gompertz {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        if self.eta < 1.0 {
+            vec![-self.eta.ln() / self.b]
+        } else {
+            vec![0.0]
+        }
+    }
+}
+
+impl distribution::Parametric for Gompertz {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Reliability for Gompertz {
+    #[inline]
+    fn hazard(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            self.eta * self.b * (self.b * x).exp()
+        }
+    }
+
+    #[inline]
+    fn cumulative_hazard(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            self.eta * (self.b * x).exp_m1()
+        }
+    }
+}
+
+impl distribution::Sample for Gompertz {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Support for Gompertz {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($eta:expr, $b:expr) => (Gompertz::new($eta, $b));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(0.5, 2.0);
+        for &x in &[-1.0, 0.0, 0.25, 1.0, 2.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 1.0, 1.5, 2.0];
+        let p = vec![
+            0.0000000000000000e+00, 1.0000000000000000e+00, 1.0934071218197507e+00,
+            1.1920081008676060e+00, 1.1512624072988991e+00, 3.0284684919220323e-01,
+            1.4404950185375705e-03, 1.2545487088450366e-10,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(0.5, 2.0).to_string(), "Gompertz(η=0.5, b=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 1.0, 1.5, 2.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 1.0479396373168687e-01,
+            2.7701054019796761e-01, 5.7647422896119160e-01, 9.5901413588725748e-01,
+            9.9992828197602845e-01, 9.9999999999770217e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::INFINITY;
+
+        let d = new!(0.5, 2.0);
+        for &x in &[0.1, 0.25, 0.5, 1.0] {
+            assert::close(d.inverse(d.distribution(x)), x, 1e-14);
+        }
+        assert_eq!(d.inverse(0.0), 0.0);
+        assert_eq!(d.inverse(1.0), INFINITY);
+    }
+
+    #[test]
+    fn median() {
+        let d = new!(0.5, 2.0);
+        assert::close(d.distribution(d.median()), 0.5, 1e-15);
+        assert::close(d.median(), 0.43487084309597196, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(0.5, 2.0).modes()[0], 0.5 * 2f64.ln(), 1e-15);
+        assert_eq!(new!(1.5, 2.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(0.5, 2.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn reliability() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 1.0];
+        for &x in &x {
+            let survival = 1.0 - d.distribution(x);
+            assert::close(d.survival(x), survival, 1e-15);
+            assert::close(d.hazard(x), d.density(x) / survival, 1e-12);
+            assert::close(d.cumulative_hazard(x), -survival.ln(), 1e-12);
+        }
+
+        assert_eq!(d.hazard(-1.0), 0.0);
+        assert_eq!(d.hazard(0.0), 1.0);
+        assert::close(d.hazard(20.0), 40f64.exp(), 1e-15 * 40f64.exp());
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(0.5, 2.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        let median = d.median();
+        let below = samples.iter().filter(|&&x| x <= median).count() as f64 / n as f64;
+        assert!((below - 0.5).abs() < 5.0 * (0.25 / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(0.5, 2.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Gompertz::try_new(0.5, 2.0).is_ok());
+        assert_eq!(Gompertz::try_new(0.0, 2.0).err(), Some("eta must be positive"));
+        assert_eq!(Gompertz::try_new(0.5, -1.0).err(), Some("b must be positive"));
+    }
+}
@@ -0,0 +1,267 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Gumbel distribution.
+#[derive(Clone, Copy)]
+pub struct Gumbel {
+    mu: f64,
+    beta: f64,
+}
+
+impl Gumbel {
+    /// Create a Gumbel distribution with location `mu` and scale `beta`.
+    ///
+    /// It should hold that `beta > 0`.
+    #[inline]
+    pub fn new(mu: f64, beta: f64) -> Self {
+        should!(beta > 0.0);
+        Gumbel { mu: mu, beta: beta }
+    }
+
+    /// Create a Gumbel distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(mu: f64, beta: f64) -> Result<Self, &'static str> {
+        must!(beta > 0.0, "beta must be positive");
+        Ok(Gumbel::new(mu, beta))
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 { self.mu }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 { self.beta }
+}
+
+impl Default for Gumbel {
+    #[inline]
+    fn default() -> Self {
+        Gumbel::new(0.0, 1.0)
+    }
+}
+
+impl fmt::Display for Gumbel {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Gumbel(μ={}, β={})", self.mu, self.beta)
+    }
+}
+
+impl distribution::Continuous for Gumbel {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        let z = (x - self.mu) / self.beta;
+        (-(z + (-z).exp())).exp() / self.beta
+    }
+}
+
+impl distribution::Distribution for Gumbel {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        (-(-(x - self.mu) / self.beta).exp()).exp()
+    }
+}
+
+impl distribution::Entropy for Gumbel {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        self.beta.ln() + EULER_MASCHERONI + 1.0
+    }
+}
+
+impl distribution::Inverse for Gumbel {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        self.mu - self.beta * (-p.ln()).ln()
+    }
+}
+
+impl distribution::Kurtosis for Gumbel {
+    #[inline]
+    fn kurtosis(&self) -> f64 { 2.4 }
+}
+
+impl distribution::Mean for Gumbel {
+    #[inline]
+    fn mean(&self) -> f64 {
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        self.mu + self.beta * EULER_MASCHERONI
+    }
+}
+
+impl distribution::Median for Gumbel {
+    #[inline]
+    fn median(&self) -> f64 {
+        use std::f64::consts::LN_2;
+        self.mu - self.beta * LN_2.ln()
+    }
+}
+
+impl distribution::Modes for Gumbel {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu]
+    }
+}
+
+impl distribution::Parametric for Gumbel {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for Gumbel {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Skewness for Gumbel {
+    /// Compute the skewness.
+    ///
+    /// The skewness is `12 √6 ζ(3) / π³`.
+    #[inline]
+    fn skewness(&self) -> f64 { 1.1395470994046487 }
+}
+
+impl distribution::Support for Gumbel {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        (NEG_INFINITY, INFINITY)
+    }
+}
+
+impl distribution::Variance for Gumbel {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use std::f64::consts::PI;
+        (PI * self.beta).powi(2) / 6.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $beta:expr) => (Gumbel::new($mu, $beta));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0, 8.0];
+        let p = vec![
+            2.5353556804990358e-02, 8.9687039367008591e-02, 1.5852096053897109e-01,
+            1.8393972058572116e-01, 1.6535214944520903e-01, 1.2732319002179125e-01,
+            5.9102475796571573e-02, 1.4649566066640760e-02,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 2.0).to_string(), "Gumbel(μ=1, β=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0, 8.0];
+        let p = vec![
+            1.1314286380459623e-02, 6.5988035845312537e-02, 1.9229564554796493e-01,
+            3.6787944117144232e-01, 5.4523921189260506e-01, 6.9220062755534635e-01,
+            8.7342301849311664e-01, 9.7025400259106244e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 2.0).entropy(), 2.2703628454614782, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+
+        let d = new!(1.0, 2.0);
+        let p = vec![0.0, 0.05, 0.1, 0.25, 0.5, 0.75, 0.9, 0.95, 1.0];
+        let x = vec![
+                      NEG_INFINITY, -1.1943774007298974e+00, -6.6806489049591155e-01,
+             3.4673148004343804e-01,  1.7330258411633287e+00,  3.4917986474144764e+00,
+             5.5007346546248910e+00,  6.9403904980843273e+00,  INFINITY,
+        ];
+
+        assert::close(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(1.0, 2.0).kurtosis(), 2.4);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(1.0, 2.0).mean(), 2.1544313298030657, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(1.0, 2.0).median(), 1.7330258411633287, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 2.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(1.0, 2.0);
+        let sum = Independent(&d, &mut source).take(n).fold(0.0, |a, b| a + b);
+        assert!((sum / n as f64 - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn skewness() {
+        use std::f64::consts::PI;
+        const ZETA_3: f64 = 1.2020569031595943;
+        assert::close(new!(1.0, 2.0).skewness(), 12.0 * 6f64.sqrt() * ZETA_3 / PI.powi(3), 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        assert_eq!(new!(1.0, 2.0).support(), (NEG_INFINITY, INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Gumbel::try_new(1.0, 2.0).is_ok());
+        assert_eq!(Gumbel::try_new(1.0, -2.0).err(), Some("beta must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(1.0, 2.0).variance(), 6.5797362673929057, 1e-15);
+    }
+}
@@ -0,0 +1,285 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A half-normal distribution.
+///
+/// The distribution is that of `|X|` where `X` follows the Gaussian
+/// distribution with mean zero and standard deviation `σ`.
+#[derive(Clone, Copy)]
+pub struct HalfNormal {
+    sigma: f64,
+}
+
+impl HalfNormal {
+    /// Create a half-normal distribution with scale parameter `sigma`.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(sigma: f64) -> Self {
+        should!(sigma > 0.0);
+        HalfNormal { sigma: sigma }
+    }
+
+    /// Create a half-normal distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(sigma: f64) -> Result<Self, &'static str> {
+        must!(sigma > 0.0, "sigma must be positive");
+        Ok(HalfNormal::new(sigma))
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 { self.sigma }
+}
+
+impl fmt::Display for HalfNormal {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "HalfNormal(σ={})", self.sigma)
+    }
+}
+
+impl distribution::Continuous for HalfNormal {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use std::f64::consts::PI;
+        if x < 0.0 {
+            0.0
+        } else {
+            let y = x / self.sigma;
+            (2.0 / PI).sqrt() * (-0.5 * y * y).exp() / self.sigma
+        }
+    }
+}
+
+impl distribution::Distribution for HalfNormal {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function, which is `erf(x / (σ
+    /// √2))`.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Error;
+        use std::f64::consts::SQRT_2;
+        if x <= 0.0 {
+            0.0
+        } else {
+            Error::erf(x / (self.sigma * SQRT_2))
+        }
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        use special::Error;
+        use std::f64::consts::SQRT_2;
+        if x <= 0.0 {
+            1.0
+        } else {
+            Error::erfc(x / (self.sigma * SQRT_2))
+        }
+    }
+}
+
+impl distribution::Entropy for HalfNormal {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use std::f64::consts::PI;
+        0.5 * (0.5 * PI * self.sigma * self.sigma).ln() + 0.5
+    }
+}
+
+impl distribution::Inverse for HalfNormal {
+    /// Compute the inverse of the cumulative distribution function, which is
+    /// `σ Φ⁻¹((1 + p) / 2)` where `Φ⁻¹` is the inverse of the cumulative
+    /// distribution function of the standard Gaussian distribution.
+    ///
+    /// For `p < 1/2`, the equivalent form `σ √2 erf⁻¹(p)` is used, since
+    /// `(1 + p) / 2` loses the accuracy of small `p`.
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gaussian;
+        use special::Error;
+        use std::f64::consts::SQRT_2;
+        should!(0.0 <= p && p <= 1.0);
+        if p < 0.5 {
+            self.sigma * SQRT_2 * Error::inv_erf(p)
+        } else {
+            -self.sigma * gaussian::inverse(0.5 * (1.0 - p))
+        }
+    }
+}
+
+impl distribution::Mean for HalfNormal {
+    #[inline]
+    fn mean(&self) -> f64 {
+        use std::f64::consts::PI;
+        self.sigma * (2.0 / PI).sqrt()
+    }
+}
+
+impl distribution::Median for HalfNormal {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for HalfNormal {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![0.0]
+    }
+}
+
+impl distribution::Parametric for HalfNormal {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for HalfNormal {
+    /// Draw a sample as the absolute value of a Gaussian sample.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::gaussian;
+        self.sigma * gaussian::sample(source).abs()
+    }
+}
+
+impl distribution::Support for HalfNormal {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for HalfNormal {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use std::f64::consts::PI;
+        self.sigma * self.sigma * (1.0 - 2.0 / PI)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($sigma:expr) => (HalfNormal::new($sigma));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(2.0);
+        for &x in &[-1.0, 0.0, 0.5, 2.0, 5.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+        assert::close(d.complementary(20.0), 1.5239706048321051e-23, 1e-35);
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 3.9894228040143270e-01, 3.8666811680284918e-01,
+            3.5206532676429947e-01, 2.4197072451914334e-01, 1.2951759566589172e-01,
+            1.7528300493568537e-02, 1.4867195147342977e-06,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let e = Gaussian::new(0.0, 2.0);
+        for &x in &x[1..] {
+            assert::close(d.density(x), 2.0 * e.density(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(2.0).to_string(), "HalfNormal(σ=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 1.9741265136584746e-01,
+            3.8292492254802618e-01, 6.8268949213708585e-01, 8.6638559746228383e-01,
+            9.8758066934844768e-01, 9.9999942669685626e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0).entropy(), 1.4189385332046727, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::INFINITY;
+
+        let d = new!(2.0);
+        for &x in &[0.5, 1.0, 2.0, 3.0, 5.0] {
+            assert::close(d.inverse(d.distribution(x)), x, 1e-12);
+        }
+        assert_eq!(d.inverse(0.0), 0.0);
+        assert_eq!(d.inverse(1.0), INFINITY);
+
+        assert::close(d.inverse(1e-12), 2.5066282746310005e-12, 1e-15 * 2.5e-12);
+        assert::close(d.inverse(1e-6), 2.5066282746316566e-6, 1e-15 * 2.5e-6);
+        assert::close(d.inverse(0.25), 0.63727872792875033, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0).mean(), 1.5957691216057308, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0).median(), 1.3489795003921634, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.0).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.0).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(2.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(HalfNormal::try_new(2.0).is_ok());
+        assert_eq!(HalfNormal::try_new(0.0).err(), Some("sigma must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0).variance(), 1.4535209105296747, 1e-15);
+    }
+}
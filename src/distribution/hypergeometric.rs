@@ -0,0 +1,355 @@
+use distribution;
+use source::Source;
+use std::fmt;
+use std::ops::Range;
+
+/// A hypergeometric distribution.
+#[derive(Clone, Copy)]
+pub struct Hypergeometric {
+    population: u64,
+    successes: u64,
+    draws: u64,
+    ln_norm: f64,
+}
+
+impl Hypergeometric {
+    /// Create a hypergeometric distribution for `draws` draws without
+    /// replacement from a population of size `population` containing
+    /// `successes` successes.
+    ///
+    /// It should hold that `successes <= population` and `draws <=
+    /// population`.
+    #[inline]
+    pub fn new(population: u64, successes: u64, draws: u64) -> Self {
+        should!(successes <= population && draws <= population);
+        Hypergeometric {
+            population: population,
+            successes: successes,
+            draws: draws,
+            ln_norm: ln_choose(population, draws),
+        }
+    }
+
+    /// Create a hypergeometric distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(population: u64, successes: u64, draws: u64) -> Result<Self, &'static str> {
+        must!(successes <= population, "successes must not exceed population");
+        must!(draws <= population, "draws must not exceed population");
+        Ok(Hypergeometric::new(population, successes, draws))
+    }
+
+    /// Return the size of the population.
+    #[inline(always)]
+    pub fn population(&self) -> u64 { self.population }
+
+    /// Return the number of successes in the population.
+    #[inline(always)]
+    pub fn successes(&self) -> u64 { self.successes }
+
+    /// Return the number of draws.
+    #[inline(always)]
+    pub fn draws(&self) -> u64 { self.draws }
+
+    /// Return the smallest value of the support.
+    #[inline]
+    fn lower(&self) -> u64 {
+        (self.draws + self.successes).saturating_sub(self.population)
+    }
+
+    /// Return the largest value of the support.
+    #[inline]
+    fn upper(&self) -> u64 {
+        if self.draws < self.successes { self.draws } else { self.successes }
+    }
+
+    /// Return the probability of the largest value of the support when the
+    /// support has at most two values.
+    #[inline]
+    fn bernoulli(&self) -> f64 {
+        use distribution::Mean;
+        self.mean() - self.lower() as f64
+    }
+}
+
+impl fmt::Display for Hypergeometric {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Hypergeometric(N={}, K={}, n={})",
+               self.population, self.successes, self.draws)
+    }
+}
+
+impl distribution::Discrete for Hypergeometric {
+    fn mass(&self, x: usize) -> f64 {
+        let x = x as u64;
+        if x < self.lower() || x > self.upper() {
+            return 0.0;
+        }
+        let &Hypergeometric { population, successes, draws, ln_norm } = self;
+        (ln_choose(successes, x) + ln_choose(population - successes, draws - x) - ln_norm).exp()
+    }
+}
+
+impl distribution::DiscreteSupport for Hypergeometric {
+    type Iter = Range<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (self.lower() as i64, self.upper() as i64)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        (self.lower() as usize)..(self.upper() as usize + 1)
+    }
+}
+
+impl distribution::Distribution for Hypergeometric {
+    type Value = usize;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use distribution::Discrete;
+        let (lower, upper) = (self.lower(), self.upper());
+        if x < lower as f64 {
+            return 0.0;
+        }
+        if x >= upper as f64 {
+            return 1.0;
+        }
+        let sum = (lower..(x as u64 + 1)).fold(0.0, |sum, x| sum + self.mass(x as usize));
+        if sum > 1.0 { 1.0 } else { sum }
+    }
+}
+
+impl distribution::Kurtosis for Hypergeometric {
+    /// Compute the excess kurtosis.
+    ///
+    /// A population of at most three leaves at most two values in the
+    /// support, and the kurtosis is then computed as for a Bernoulli
+    /// distribution, since the general formula divides by zero.
+    fn kurtosis(&self) -> f64 {
+        if self.population <= 3 {
+            let p = self.bernoulli();
+            return 1.0 / (p * (1.0 - p)) - 6.0;
+        }
+        let (big_n, big_k, n) = (self.population as f64, self.successes as f64,
+                                 self.draws as f64);
+        let product = n * big_k * (big_n - big_k) * (big_n - n);
+        ((big_n - 1.0) * big_n * big_n *
+            (big_n * (big_n + 1.0) - 6.0 * big_k * (big_n - big_k) - 6.0 * n * (big_n - n)) +
+            6.0 * product * (5.0 * big_n - 6.0)) /
+            (product * (big_n - 2.0) * (big_n - 3.0))
+    }
+}
+
+impl distribution::Mean for Hypergeometric {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.draws as f64 * self.successes as f64 / self.population as f64
+    }
+}
+
+impl distribution::Modes for Hypergeometric {
+    fn modes(&self) -> Vec<usize> {
+        let mode = (self.draws + 1) as f64 * (self.successes + 1) as f64 /
+            (self.population + 2) as f64;
+        let floor = mode.floor();
+        if mode == floor && floor >= self.lower() as f64 + 1.0 {
+            vec![floor as usize - 1, floor as usize]
+        } else {
+            vec![floor as usize]
+        }
+    }
+}
+
+impl distribution::Parametric for Hypergeometric {
+    /// Return the number of free parameters.
+    ///
+    /// Unlike the number of trials of a binomial distribution, the population
+    /// and the number of successes are what is usually estimated, as in
+    /// capture-recapture studies, so all three parameters are counted.
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 3 }
+}
+
+impl distribution::Sample for Hypergeometric {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by drawing the items one by one without
+    /// replacement and counting the successes.
+    fn sample<S>(&self, source: &mut S) -> usize where S: Source {
+        let mut population = self.population;
+        let mut successes = self.successes;
+        let mut count = 0;
+        for _ in 0..self.draws {
+            if successes == 0 {
+                break;
+            }
+            if source.read::<f64>() * (population as f64) < successes as f64 {
+                successes -= 1;
+                count += 1;
+            }
+            population -= 1;
+        }
+        count
+    }
+}
+
+impl distribution::Skewness for Hypergeometric {
+    /// Compute the skewness.
+    ///
+    /// A population of at most three is handled as in `kurtosis`.
+    fn skewness(&self) -> f64 {
+        if self.population <= 3 {
+            let p = self.bernoulli();
+            return (1.0 - 2.0 * p) / (p * (1.0 - p)).sqrt();
+        }
+        let (big_n, big_k, n) = (self.population as f64, self.successes as f64,
+                                 self.draws as f64);
+        (big_n - 2.0 * big_k) * (big_n - 1.0).sqrt() * (big_n - 2.0 * n) /
+            ((n * big_k * (big_n - big_k) * (big_n - n)).sqrt() * (big_n - 2.0))
+    }
+}
+
+impl distribution::Variance for Hypergeometric {
+    fn variance(&self) -> f64 {
+        let (big_n, big_k, n) = (self.population as f64, self.successes as f64,
+                                 self.draws as f64);
+        if big_n <= 1.0 {
+            return 0.0;
+        }
+        n * big_k / big_n * (big_n - big_k) / big_n * (big_n - n) / (big_n - 1.0)
+    }
+}
+
+fn ln_choose(n: u64, k: u64) -> f64 {
+    use special::Gamma;
+    let (n, k) = (n as f64, k as f64);
+    Gamma::ln_gamma(n + 1.0).0 - Gamma::ln_gamma(k + 1.0).0 - Gamma::ln_gamma(n - k + 1.0).0
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($population:expr, $successes:expr, $draws:expr) => (
+            Hypergeometric::new($population, $successes, $draws)
+        );
+    );
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(50, 10, 5).to_string(), "Hypergeometric(N=50, K=10, n=5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(50, 10, 12);
+        let p = vec![
+            4.6020342145777389e-02, 2.3644934412830451e-01, 5.5065719739947427e-01,
+            8.2094352279402882e-01, 9.5397507357416123e-01, 9.9267516107383602e-01,
+            9.9931488196838814e-01, 9.9996530360703817e-01, 9.9999917973405117e-01,
+            9.9999999357494029e-01, 1.0000000000000000e+00,
+        ];
+
+        assert::close(&(0..11).map(|x| d.distribution(x as f64)).collect::<Vec<_>>(), &p, 1e-13);
+        assert::close(&(0..11).map(|x| d.distribution(x as f64 + 0.5)).collect::<Vec<_>>(),
+                      &p, 1e-13);
+        assert_eq!(d.distribution(-1.0), 0.0);
+        assert_eq!(d.distribution(12.0), 1.0);
+
+        let d = new!(10, 7, 6);
+        assert_eq!(d.distribution(2.0), 0.0);
+        assert::close(d.distribution(3.0), 1.0 / 6.0, 1e-14);
+    }
+
+    #[test]
+    fn iter_support() {
+        let d = new!(20, 15, 10);
+        assert_eq!(d.iter_support().collect::<Vec<_>>(), vec![5, 6, 7, 8, 9, 10]);
+        assert::close(d.iter_support().fold(0.0, |sum, x| sum + d.mass(x)), 1.0, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(50, 10, 12).kurtosis(), -0.12090419699514744, 1e-14);
+        assert::close(new!(3, 1, 1).kurtosis(), -1.5, 1e-14);
+        assert::close(new!(3, 2, 2).kurtosis(), -1.5, 1e-14);
+        assert_eq!(new!(2, 1, 1).kurtosis(), -2.0);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(50, 10, 12);
+        let p = vec![
+            4.6020342145777389e-02, 1.9042900198252710e-01, 3.1420785327116973e-01,
+            2.7028632539455461e-01, 1.3303155078013235e-01, 3.8700087499674865e-02,
+            6.6397208945520600e-03, 6.5042163864999778e-04, 3.3876127013020712e-05,
+            8.1384088920169891e-07, 6.4250596515923595e-09, 0.0000000000000000e+00,
+        ];
+
+        assert::close(&(0..12).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-14);
+
+        let d = new!(10, 7, 6);
+        assert_eq!(d.mass(2), 0.0);
+        assert::close(d.mass(3), 1.0 / 6.0, 1e-14);
+        assert_eq!(d.mass(7), 0.0);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(50, 10, 12).mean(), 2.4, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(50, 10, 12).modes(), vec![2]);
+        assert_eq!(new!(8, 3, 4).modes(), vec![1, 2]);
+        assert_eq!(new!(10, 10, 4).modes(), vec![4]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(20, 15, 10).num_parameters(), 3);
+        assert_eq!(new!(50, 10, 12).num_parameters(), 3);
+        assert_eq!(new!(10, 0, 10).num_parameters(), 3);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(10, 7, 6);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| 3 <= x && x <= 6));
+        let mean = samples.iter().fold(0.0, |sum, &x| sum + x as f64) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(50, 10, 12).skewness(), 0.2663415901685442, 1e-14);
+        assert::close(new!(3, 1, 1).skewness(), 0.7071067811865476, 1e-14);
+        assert::close(new!(3, 2, 2).skewness(), 0.7071067811865476, 1e-14);
+        assert_eq!(new!(2, 1, 1).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(20, 15, 10).support(), (5, 10));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Hypergeometric::try_new(10, 3, 5).is_ok());
+        assert_eq!(Hypergeometric::try_new(10, 11, 5).err(),
+                   Some("successes must not exceed population"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(50, 10, 12).variance(), 1.4889795918367348, 1e-14);
+    }
+}
@@ -0,0 +1,281 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// An inverse-gamma distribution.
+#[derive(Clone, Copy)]
+pub struct InverseGamma {
+    alpha: f64,
+    beta: f64,
+    ln_norm: f64,
+}
+
+impl InverseGamma {
+    /// Create an inverse-gamma distribution with shape parameter `alpha` and
+    /// scale parameter `beta`.
+    ///
+    /// It should hold that `alpha > 0` and `beta > 0`.
+    #[inline]
+    pub fn new(alpha: f64, beta: f64) -> Self {
+        use special::Gamma;
+        should!(alpha > 0.0 && beta > 0.0);
+        InverseGamma {
+            alpha: alpha,
+            beta: beta,
+            ln_norm: alpha * beta.ln() - Gamma::ln_gamma(alpha).0,
+        }
+    }
+
+    /// Create an inverse-gamma distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(alpha: f64, beta: f64) -> Result<Self, &'static str> {
+        must!(alpha > 0.0, "alpha must be positive");
+        must!(beta > 0.0, "beta must be positive");
+        Ok(InverseGamma::new(alpha, beta))
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn alpha(&self) -> f64 { self.alpha }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn beta(&self) -> f64 { self.beta }
+}
+
+impl fmt::Display for InverseGamma {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "InverseGamma(α={}, β={})", self.alpha, self.beta)
+    }
+}
+
+impl distribution::Continuous for InverseGamma {
+    fn density(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            (self.ln_norm - (self.alpha + 1.0) * x.ln() - self.beta / x).exp()
+        }
+    }
+}
+
+impl distribution::Distribution for InverseGamma {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The implementation is based on the upper regularized incomplete gamma
+    /// function.
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x <= 0.0 {
+            0.0
+        } else {
+            1.0 - (self.beta / x).inc_gamma(self.alpha)
+        }
+    }
+}
+
+impl distribution::Entropy for InverseGamma {
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        let alpha = self.alpha;
+        alpha + self.beta.ln() + Gamma::ln_gamma(alpha).0 - (1.0 + alpha) * alpha.digamma()
+    }
+}
+
+impl distribution::Kurtosis for InverseGamma {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is undefined (NaN) for `alpha <= 4`.
+    fn kurtosis(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha <= 4.0 {
+            return ::std::f64::NAN;
+        }
+        (30.0 * alpha - 66.0) / ((alpha - 3.0) * (alpha - 4.0))
+    }
+}
+
+impl distribution::Mean for InverseGamma {
+    /// Compute the expected value.
+    ///
+    /// The expected value is infinite for `alpha <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.alpha <= 1.0 {
+            return ::std::f64::INFINITY;
+        }
+        self.beta / (self.alpha - 1.0)
+    }
+}
+
+impl distribution::Modes for InverseGamma {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.beta / (self.alpha + 1.0)]
+    }
+}
+
+impl distribution::Parametric for InverseGamma {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for InverseGamma {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as the reciprocal of a gamma variate.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::gamma;
+        self.beta / gamma::sample(self.alpha, source)
+    }
+}
+
+impl distribution::Skewness for InverseGamma {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined (NaN) for `alpha <= 3`.
+    fn skewness(&self) -> f64 {
+        let alpha = self.alpha;
+        if alpha <= 3.0 {
+            return ::std::f64::NAN;
+        }
+        4.0 * (alpha - 2.0).sqrt() / (alpha - 3.0)
+    }
+}
+
+impl distribution::Support for InverseGamma {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for InverseGamma {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite for `1 < alpha <= 2` and undefined (NaN) for
+    /// `alpha <= 1`.
+    fn variance(&self) -> f64 {
+        use std::f64::{INFINITY, NAN};
+        let alpha = self.alpha;
+        if alpha <= 1.0 {
+            return NAN;
+        }
+        if alpha <= 2.0 {
+            return INFINITY;
+        }
+        self.beta * self.beta / ((alpha - 1.0) * (alpha - 1.0) * (alpha - 2.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+    use std::f64::INFINITY;
+
+    macro_rules! new(
+        ($alpha:expr, $beta:expr) => (InverseGamma::new($alpha, $beta));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(3.0, 2.0);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 8.2446144897542386e-05,
+            3.4351373097217214e-01, 1.1722008888789874e+00, 8.7840807471788607e-01,
+            5.4134113294645081e-01, 2.0827428196798164e-01, 9.1969860292860584e-02,
+            2.5353931804078619e-02, 4.2900482946280913e-03, 3.2749230123119274e-04,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(3.0, 2.0).to_string(), "InverseGamma(α=3, β=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.0, 2.0);
+        let x = vec![-1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 5.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 4.5551495055892172e-07,
+            1.3753967744002985e-02, 2.3810330555354434e-01, 5.0182492549801105e-01,
+            6.7667641618306351e-01, 8.4936855615067519e-01, 9.1969860292860584e-01,
+            9.6978789150600719e-01, 9.9207366813274611e-01, 9.9885151875513789e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(3.0, 2.0).entropy(), 0.69515702072602203, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(5.5, 2.0).kurtosis(), 26.4, 1e-14);
+        assert!(new!(4.0, 2.0).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(3.0, 2.0).mean(), 1.0);
+        assert_eq!(new!(1.0, 2.0).mean(), INFINITY);
+        assert_eq!(new!(0.5, 2.0).mean(), INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(3.0, 2.0).modes(), vec![0.5]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.0, 3.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(3.0, 2.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x > 0.0));
+        let below = samples.iter().filter(|&&x| x <= 1.0).count() as f64 / n as f64;
+        assert!((below - d.distribution(1.0)).abs() < 0.02);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(5.5, 2.0).skewness(), 2.9933259094191533, 1e-15);
+        assert!(new!(3.0, 2.0).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.0, 3.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(InverseGamma::try_new(2.0, 3.0).is_ok());
+        assert_eq!(InverseGamma::try_new(-2.0, 3.0).err(), Some("alpha must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(3.0, 2.0).variance(), 1.0);
+        assert_eq!(new!(2.0, 2.0).variance(), INFINITY);
+        assert!(new!(1.0, 2.0).variance().is_nan());
+    }
+}
@@ -0,0 +1,378 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// An Irwin–Hall distribution.
+///
+/// The distribution is that of the sum of `n` independent random variables
+/// uniformly distributed on `[0, 1]`. The density is a piecewise polynomial
+/// of degree `n - 1` with knots at the integers, and the support is `[0, n]`.
+///
+/// The density and the cumulative distribution function are evaluated via
+/// alternating sums, which lose precision as `n` grows beyond a few dozen.
+#[derive(Clone, Copy)]
+pub struct IrwinHall {
+    n: u64,
+}
+
+/// A Bates distribution.
+///
+/// The distribution is that of the average of `n` independent random
+/// variables uniformly distributed on `[0, 1]`, which is an Irwin–Hall
+/// distribution scaled by `1 / n`. The support is `[0, 1]`.
+#[derive(Clone, Copy)]
+pub struct Bates {
+    inner: IrwinHall,
+}
+
+impl IrwinHall {
+    /// Create an Irwin–Hall distribution with `n` summands.
+    ///
+    /// It should hold that `n > 0`.
+    #[inline]
+    pub fn new(n: u64) -> Self {
+        should!(n > 0);
+        IrwinHall { n: n }
+    }
+
+    /// Create an Irwin–Hall distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(n: u64) -> Result<Self, &'static str> {
+        must!(n > 0, "n must be positive");
+        Ok(IrwinHall::new(n))
+    }
+
+    /// Return the number of summands.
+    #[inline(always)]
+    pub fn n(&self) -> u64 { self.n }
+}
+
+impl Bates {
+    /// Create a Bates distribution with `n` summands.
+    ///
+    /// It should hold that `n > 0`.
+    #[inline]
+    pub fn new(n: u64) -> Self {
+        Bates { inner: IrwinHall::new(n) }
+    }
+
+    /// Create a Bates distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(n: u64) -> Result<Self, &'static str> {
+        must!(n > 0, "n must be positive");
+        Ok(Bates::new(n))
+    }
+
+    /// Return the number of summands.
+    #[inline(always)]
+    pub fn n(&self) -> u64 { self.inner.n }
+}
+
+impl fmt::Display for IrwinHall {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "IrwinHall(n={})", self.n)
+    }
+}
+
+impl fmt::Display for Bates {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Bates(n={})", self.inner.n)
+    }
+}
+
+impl distribution::Continuous for IrwinHall {
+    /// Compute the probability density function, which is
+    /// `Σ_k (-1)^k C(n, k) (x - k)^(n - 1) / (n - 1)!` over `0 ≤ k ≤ ⌊x⌋`.
+    ///
+    /// The density is symmetric about `n / 2`, and the sum is evaluated on the
+    /// lower half, where it has fewer terms.
+    fn density(&self, x: f64) -> f64 {
+        let n = self.n as f64;
+        if x < 0.0 || x > n {
+            return 0.0;
+        }
+        let x = if x > 0.5 * n { n - x } else { x };
+        alternating(self.n, x, self.n - 1)
+    }
+}
+
+impl distribution::Continuous for Bates {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        let n = self.inner.n as f64;
+        n * self.inner.density(n * x)
+    }
+}
+
+impl distribution::Distribution for IrwinHall {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function, which is
+    /// `Σ_k (-1)^k C(n, k) (x - k)^n / n!` over `0 ≤ k ≤ ⌊x⌋`.
+    fn distribution(&self, x: f64) -> f64 {
+        let n = self.n as f64;
+        if x <= 0.0 {
+            0.0
+        } else if x >= n {
+            1.0
+        } else if x > 0.5 * n {
+            1.0 - alternating(self.n, n - x, self.n)
+        } else {
+            alternating(self.n, x, self.n)
+        }
+    }
+
+    fn complementary(&self, x: f64) -> f64 {
+        self.distribution(self.n as f64 - x)
+    }
+}
+
+impl distribution::Distribution for Bates {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        self.inner.distribution(self.inner.n as f64 * x)
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        self.inner.complementary(self.inner.n as f64 * x)
+    }
+}
+
+impl distribution::Mean for IrwinHall {
+    #[inline]
+    fn mean(&self) -> f64 {
+        0.5 * self.n as f64
+    }
+}
+
+impl distribution::Mean for Bates {
+    #[inline]
+    fn mean(&self) -> f64 {
+        0.5
+    }
+}
+
+impl distribution::Modes for IrwinHall {
+    /// Compute the modes, which consist of `n / 2`.
+    ///
+    /// For `n = 1`, the density is constant on the support, and its midpoint
+    /// is returned.
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![0.5 * self.n as f64]
+    }
+}
+
+impl distribution::Modes for Bates {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![0.5]
+    }
+}
+
+impl distribution::Parametric for IrwinHall {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Parametric for Bates {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for IrwinHall {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        (0..self.n).fold(0.0, |sum, _| sum + source.read::<f64>())
+    }
+}
+
+impl distribution::Sample for Bates {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        self.inner.sample(source) / self.inner.n as f64
+    }
+}
+
+impl distribution::Support for IrwinHall {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        (0.0, self.n as f64)
+    }
+}
+
+impl distribution::Support for Bates {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        (0.0, 1.0)
+    }
+}
+
+impl distribution::Variance for IrwinHall {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.n as f64 / 12.0
+    }
+}
+
+impl distribution::Variance for Bates {
+    #[inline]
+    fn variance(&self) -> f64 {
+        (12.0 * self.inner.n as f64).recip()
+    }
+}
+
+// Compute `Σ_k (-1)^k C(n, k) (x - k)^power / power!` over `0 ≤ k ≤ ⌊x⌋`.
+fn alternating(n: u64, x: f64, power: u64) -> f64 {
+    let factorial = (1..(power + 1)).fold(1.0, |product, i| product * i as f64);
+    let (mut sum, mut choose, mut k) = (0.0, 1.0, 0);
+    while k <= n && k as f64 <= x {
+        let term = choose * (x - k as f64).powi(power as i32);
+        if k % 2 == 0 { sum += term } else { sum -= term }
+        choose *= (n - k) as f64 / (k + 1) as f64;
+        k += 1;
+    }
+    sum / factorial
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        (IrwinHall, $n:expr) => (IrwinHall::new($n));
+        (Bates, $n:expr) => (Bates::new($n));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(IrwinHall, 5);
+        for &x in &[-1.0, 0.0, 0.5, 1.5, 2.5, 3.0, 4.75, 5.0, 6.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn density() {
+        let x = vec![-0.5, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.5];
+        let d = new!(IrwinHall, 1);
+        let p = vec![0.0, 1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(IrwinHall, 2);
+        let p = vec![0.0, 0.0, 0.25, 0.5, 1.0, 0.5, 0.0, 0.0, 0.0, 0.0];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(IrwinHall, 3);
+        let p = vec![0.0, 0.0, 0.03125, 0.125, 0.5, 0.75, 0.5, 0.125, 0.0, 0.0];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(IrwinHall, 5);
+        let p = vec![
+            0.0, 0.0, 1.6276041666666666e-04, 2.6041666666666665e-03,
+            4.1666666666666664e-02, 1.9791666666666666e-01, 4.5833333333333331e-01,
+            5.9895833333333337e-01, 4.5833333333333331e-01, 2.6041666666666665e-03,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(Bates, 3);
+        let e = new!(IrwinHall, 3);
+        for &x in &[0.1, 0.25, 0.5, 0.8] {
+            assert::close(d.density(x), 3.0 * e.density(3.0 * x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(IrwinHall, 3).to_string(), "IrwinHall(n=3)");
+        assert_eq!(new!(Bates, 3).to_string(), "Bates(n=3)");
+    }
+
+    #[test]
+    fn distribution() {
+        let x = vec![-0.5, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.5];
+        let d = new!(IrwinHall, 3);
+        let p = vec![
+            0.0, 0.0, 2.6041666666666665e-03, 2.0833333333333332e-02,
+            1.6666666666666666e-01, 0.5, 8.3333333333333337e-01, 9.7916666666666663e-01,
+            1.0, 1.0,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(IrwinHall, 5);
+        let p = vec![
+            0.0, 0.0, 8.1380208333333332e-06, 2.6041666666666666e-04,
+            8.3333333333333332e-03, 6.1979166666666669e-02, 0.225, 0.5, 0.775,
+            9.9973958333333335e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(Bates, 5);
+        let e = new!(IrwinHall, 5);
+        for &x in &[0.1, 0.25, 0.5, 0.8] {
+            assert::close(d.distribution(x), e.distribution(5.0 * x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(IrwinHall, 5).mean(), 2.5);
+        assert_eq!(new!(Bates, 5).mean(), 0.5);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(IrwinHall, 5).modes(), vec![2.5]);
+        assert_eq!(new!(Bates, 5).modes(), vec![0.5]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(IrwinHall, 5).num_parameters(), 1);
+        assert_eq!(new!(Bates, 5).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+
+        let d = new!(IrwinHall, 5);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 0.0 && x <= 5.0));
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+
+        let d = new!(Bates, 5);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 0.0 && x <= 1.0));
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(IrwinHall, 5).support(), (0.0, 5.0));
+        assert_eq!(new!(Bates, 5).support(), (0.0, 1.0));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(IrwinHall::try_new(5).is_ok());
+        assert_eq!(IrwinHall::try_new(0).err(), Some("n must be positive"));
+        assert!(Bates::try_new(5).is_ok());
+        assert_eq!(Bates::try_new(0).err(), Some("n must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(IrwinHall, 6).variance(), 0.5, 1e-15);
+        assert::close(new!(Bates, 6).variance(), 1.0 / 72.0, 1e-15);
+    }
+}
@@ -0,0 +1,185 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A kernel density estimate.
+///
+/// The distribution is an equally weighted mixture of Gaussian kernels, one
+/// centered at each of a set of observed samples, with a common standard
+/// deviation referred to as the bandwidth.
+#[derive(Clone)]
+pub struct Kde {
+    data: Vec<f64>,
+    bandwidth: f64,
+}
+
+impl Kde {
+    /// Create a kernel density estimate from `data` with `bandwidth`.
+    ///
+    /// It should hold that `data` is nonempty and `bandwidth > 0`.
+    #[inline]
+    pub fn new(data: Vec<f64>, bandwidth: f64) -> Self {
+        should!(!data.is_empty() && bandwidth > 0.0);
+        Kde { data: data, bandwidth: bandwidth }
+    }
+
+    /// Create a kernel density estimate or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(data: Vec<f64>, bandwidth: f64) -> Result<Self, &'static str> {
+        must!(!data.is_empty(), "data must be nonempty");
+        must!(bandwidth > 0.0, "bandwidth must be positive");
+        Ok(Kde::new(data, bandwidth))
+    }
+
+    /// Create a kernel density estimate from `data` with the bandwidth given
+    /// by Silverman’s rule of thumb, `0.9 min(σ, IQR / 1.34) n^(-1/5)`, where
+    /// `σ` is the sample standard deviation, and `IQR` is the interquartile
+    /// range.
+    ///
+    /// It should hold that there are at least two distinct samples.
+    pub fn with_silverman(data: Vec<f64>) -> Self {
+        use distribution::{Empirical, Inverse};
+        should!(data.len() > 1);
+        let n = data.len() as f64;
+        let mean = data.iter().fold(0.0, |sum, &x| sum + x) / n;
+        let deviation = (data.iter().fold(0.0, |sum, &x| sum + (x - mean) * (x - mean)) /
+                         (n - 1.0)).sqrt();
+        let empirical = Empirical::from_samples(data.clone());
+        let spread = empirical.iqr() / 1.34;
+        let scale = if spread > 0.0 && spread < deviation { spread } else { deviation };
+        Kde::new(data, 0.9 * scale * n.powf(-0.2))
+    }
+
+    /// Return the samples.
+    #[inline(always)]
+    pub fn data(&self) -> &[f64] { &self.data }
+
+    /// Return the bandwidth.
+    #[inline(always)]
+    pub fn bandwidth(&self) -> f64 { self.bandwidth }
+}
+
+impl fmt::Display for Kde {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Kde(n={}, h={})", self.data.len(), self.bandwidth)
+    }
+}
+
+impl distribution::Continuous for Kde {
+    fn density(&self, x: f64) -> f64 {
+        use std::f64::consts::PI;
+        let h = self.bandwidth;
+        let sum = self.data.iter().fold(0.0, |sum, &y| {
+            let z = (x - y) / h;
+            sum + (-0.5 * z * z).exp()
+        });
+        sum / (self.data.len() as f64 * h * (2.0 * PI).sqrt())
+    }
+}
+
+impl distribution::Distribution for Kde {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Error;
+        use std::f64::consts::SQRT_2;
+        let h = self.bandwidth;
+        let sum = self.data.iter().fold(0.0, |sum, &y| {
+            sum + (1.0 + Error::erf((x - y) / (h * SQRT_2))) / 2.0
+        });
+        sum / self.data.len() as f64
+    }
+}
+
+impl distribution::Sample for Kde {
+    /// Draw a sample.
+    ///
+    /// The sample is one of the observed samples chosen uniformly at random
+    /// with a Gaussian perturbation whose standard deviation is the bandwidth.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::gaussian;
+        let n = self.data.len();
+        let i = (source.read::<f64>() * n as f64) as usize;
+        self.data[if i < n { i } else { n - 1 }] + self.bandwidth * gaussian::sample(source)
+    }
+}
+
+impl distribution::Support for Kde {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        (NEG_INFINITY, INFINITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($data:expr, $bandwidth:expr) => (Kde::new($data.to_vec(), $bandwidth));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!([-1.0, 0.0, 2.5], 0.5);
+        let x = vec![-2.0, -1.0, 0.0, 1.0, 2.5, 4.0];
+        let p = vec![
+            3.6083197825968624e-02, 3.0195549794917032e-01, 3.0195648908942363e-01,
+            3.9037763433927293e-02, 2.6596251142005478e-01, 2.9545656079620398e-03,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!([-1.0, 0.0, 2.5], 0.5).to_string(), "Kde(n=3, h=0.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!([-1.0, 0.0, 2.5], 0.5);
+        let x = vec![-2.0, -1.0, 0.0, 1.0, 2.5, 4.0];
+        let p = vec![
+            7.5939343966707753e-03, 1.7425004398315300e-01, 4.9241671823446420e-01,
+            6.5952269828053922e-01, 8.3333323778238277e-01, 9.9955003398945641e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!([-1.0, 0.0, 2.5], 0.5);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        let below = samples.iter().filter(|&&x| x <= 1.0).count() as f64 / n as f64;
+        assert!((below - d.distribution(1.0)).abs() < 0.02);
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        assert_eq!(new!([1.0, 2.0], 0.5).support(), (NEG_INFINITY, INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Kde::try_new(vec![1.0, 2.0], 0.5).is_ok());
+        assert_eq!(Kde::try_new(vec![], 0.5).err(), Some("data must be nonempty"));
+    }
+
+    #[test]
+    fn with_silverman() {
+        let d = Kde::with_silverman(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert::close(d.bandwidth(), 0.9 * (2.0 / 1.34) * 5f64.powf(-0.2), 1e-15);
+
+        let d = Kde::with_silverman(vec![0.0, 0.0, 0.0, 0.0, 10.0]);
+        assert::close(d.bandwidth(), 0.9 * 20f64.sqrt() * 5f64.powf(-0.2), 1e-15);
+    }
+}
@@ -0,0 +1,278 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Kumaraswamy distribution.
+///
+/// The distribution is defined on the unit interval and resembles the beta
+/// distribution, but its cumulative distribution function and its quantile
+/// function have closed forms, which makes sampling by inversion cheap.
+#[derive(Clone, Copy)]
+pub struct Kumaraswamy {
+    a: f64,
+    b: f64,
+}
+
+impl Kumaraswamy {
+    /// Create a Kumaraswamy distribution with shape parameters `a` and `b`.
+    ///
+    /// It should hold that `a > 0` and `b > 0`.
+    #[inline]
+    pub fn new(a: f64, b: f64) -> Self {
+        should!(a > 0.0 && b > 0.0);
+        Kumaraswamy { a: a, b: b }
+    }
+
+    /// Create a Kumaraswamy distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(a: f64, b: f64) -> Result<Self, &'static str> {
+        must!(a > 0.0, "a must be positive");
+        must!(b > 0.0, "b must be positive");
+        Ok(Kumaraswamy::new(a, b))
+    }
+
+    /// Return the first shape parameter.
+    #[inline(always)]
+    pub fn a(&self) -> f64 { self.a }
+
+    /// Return the second shape parameter.
+    #[inline(always)]
+    pub fn b(&self) -> f64 { self.b }
+
+    // E[X^n] = b B(1 + n / a, b)
+    #[inline(always)]
+    fn moment(&self, n: f64) -> f64 {
+        use special::Beta;
+        (self.b.ln() + (1.0 + n / self.a).ln_beta(self.b)).exp()
+    }
+}
+
+impl fmt::Display for Kumaraswamy {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Kumaraswamy(a={}, b={})", self.a, self.b)
+    }
+}
+
+impl distribution::Continuous for Kumaraswamy {
+    fn density(&self, x: f64) -> f64 {
+        if x <= 0.0 || x >= 1.0 {
+            0.0
+        } else {
+            let y = x.powf(self.a);
+            self.a * self.b * y / x * ((self.b - 1.0) * (-y).ln_1p()).exp()
+        }
+    }
+}
+
+impl distribution::Distribution for Kumaraswamy {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x >= 1.0 {
+            1.0
+        } else {
+            -(self.b * (-x.powf(self.a)).ln_1p()).exp_m1()
+        }
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x >= 1.0 {
+            0.0
+        } else {
+            (self.b * (-x.powf(self.a)).ln_1p()).exp()
+        }
+    }
+}
+
+impl distribution::Inverse for Kumaraswamy {
+    /// Compute the inverse of the cumulative distribution function, which is
+    /// `(1 - (1 - p)^(1 / b))^(1 / a)`.
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        (-((-p).ln_1p() / self.b).exp_m1()).powf(1.0 / self.a)
+    }
+}
+
+impl distribution::Mean for Kumaraswamy {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.moment(1.0)
+    }
+}
+
+impl distribution::Median for Kumaraswamy {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for Kumaraswamy {
+    fn modes(&self) -> Vec<f64> {
+        let (a, b) = (self.a, self.b);
+        if a == 1.0 && b == 1.0 {
+            vec![]
+        } else if a == 1.0 && b > 1.0 {
+            vec![0.0]
+        } else if a > 1.0 && b == 1.0 {
+            vec![1.0]
+        } else if a < 1.0 && b < 1.0 {
+            vec![0.0, 1.0]
+        } else if a < 1.0 && b >= 1.0 {
+            vec![0.0]
+        } else if a >= 1.0 && b < 1.0 {
+            vec![1.0]
+        } else {
+            vec![((a - 1.0) / (a * b - 1.0)).powf(1.0 / a)]
+        }
+    }
+}
+
+impl distribution::Parametric for Kumaraswamy {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for Kumaraswamy {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Support for Kumaraswamy {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        (0.0, 1.0)
+    }
+}
+
+impl distribution::Variance for Kumaraswamy {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let mean = self.moment(1.0);
+        self.moment(2.0) - mean * mean
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($a:expr, $b:expr) => (Kumaraswamy::new($a, $b));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(2.0, 5.0);
+        for &x in &[-1.0, 0.0, 0.1, 0.5, 0.9, 1.0, 2.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-0.5, 0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0, 1.5];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 9.6059601000000006e-01,
+            1.9311904907226562e+00, 1.5820312500000000e+00, 2.7477264404296875e-01,
+            1.1728889999999990e-02, 0.0000000000000000e+00, 0.0000000000000000e+00,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(2.0, 5.5).to_string(), "Kumaraswamy(a=2, b=5.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 5.0);
+        let x = vec![-0.5, 0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0, 1.5];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 4.9009950100000005e-02,
+            2.7580356597900391e-01, 7.6269531250000000e-01, 9.8397159576416016e-01,
+            9.9975239010000005e-01, 1.0000000000000000e+00, 1.0000000000000000e+00,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(2.0, 5.0);
+        for &x in &[0.1, 0.25, 0.5, 0.75, 0.9] {
+            assert::close(d.inverse(d.distribution(x)), x, 1e-14);
+        }
+        assert_eq!(d.inverse(0.0), 0.0);
+        assert_eq!(d.inverse(1.0), 1.0);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0, 5.0).mean(), 0.36940836940836941, 1e-15);
+        assert::close(new!(1.0, 1.0).mean(), 0.5, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0, 5.0).median(), 0.35979082354039527, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(2.0, 5.0).modes()[0], 1.0 / 3.0, 1e-15);
+        assert!(new!(1.0, 1.0).modes().is_empty());
+        assert_eq!(new!(1.0, 2.0).modes(), vec![0.0]);
+        assert_eq!(new!(2.0, 1.0).modes(), vec![1.0]);
+        assert_eq!(new!(0.5, 0.5).modes(), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.0, 5.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(2.0, 5.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| 0.0 <= x && x <= 1.0));
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.0, 5.0).support(), (0.0, 1.0));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Kumaraswamy::try_new(2.0, 5.0).is_ok());
+        assert_eq!(Kumaraswamy::try_new(0.0, 5.0).err(), Some("a must be positive"));
+        assert_eq!(Kumaraswamy::try_new(2.0, -1.0).err(), Some("b must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0, 5.0).variance(), 0.030204123277716353, 1e-15);
+    }
+}
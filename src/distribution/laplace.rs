@@ -0,0 +1,276 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Laplace distribution.
+#[derive(Clone, Copy)]
+pub struct Laplace {
+    mu: f64,
+    b: f64,
+}
+
+impl Laplace {
+    /// Create a Laplace distribution with location `mu` and scale `b`.
+    ///
+    /// It should hold that `b > 0`.
+    #[inline]
+    pub fn new(mu: f64, b: f64) -> Self {
+        should!(b > 0.0);
+        Laplace { mu: mu, b: b }
+    }
+
+    /// Create a Laplace distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(mu: f64, b: f64) -> Result<Self, &'static str> {
+        must!(b > 0.0, "b must be positive");
+        Ok(Laplace::new(mu, b))
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 { self.mu }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn b(&self) -> f64 { self.b }
+}
+
+impl Default for Laplace {
+    #[inline]
+    fn default() -> Self {
+        Laplace::new(0.0, 1.0)
+    }
+}
+
+impl fmt::Display for Laplace {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Laplace(μ={}, b={})", self.mu, self.b)
+    }
+}
+
+impl distribution::Continuous for Laplace {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        (-(x - self.mu).abs() / self.b).exp() / (2.0 * self.b)
+    }
+}
+
+impl distribution::Distribution for Laplace {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        let z = (x - self.mu) / self.b;
+        if z < 0.0 {
+            0.5 * z.exp()
+        } else {
+            1.0 - 0.5 * (-z).exp()
+        }
+    }
+}
+
+impl distribution::Entropy for Laplace {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        1.0 + (2.0 * self.b).ln()
+    }
+}
+
+impl distribution::Inverse for Laplace {
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        if p < 0.5 {
+            self.mu + self.b * (2.0 * p).ln()
+        } else {
+            self.mu - self.b * (2.0 * (1.0 - p)).ln()
+        }
+    }
+}
+
+impl distribution::Kurtosis for Laplace {
+    #[inline]
+    fn kurtosis(&self) -> f64 { 3.0 }
+}
+
+impl distribution::Mean for Laplace {
+    #[inline]
+    fn mean(&self) -> f64 { self.mu }
+}
+
+impl distribution::Median for Laplace {
+    #[inline]
+    fn median(&self) -> f64 { self.mu }
+}
+
+impl distribution::Modes for Laplace {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu]
+    }
+}
+
+impl distribution::Parametric for Laplace {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for Laplace {
+    /// Draw a sample.
+    ///
+    /// A single uniform variate is consumed, and the sample is exactly `mu`
+    /// when the variate is one half.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        let u = source.read::<f64>() - 0.5;
+        self.mu - self.b * u.signum() * (-2.0 * u.abs()).ln_1p()
+    }
+}
+
+impl distribution::Skewness for Laplace {
+    #[inline]
+    fn skewness(&self) -> f64 { 0.0 }
+}
+
+impl distribution::Support for Laplace {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        (NEG_INFINITY, INFINITY)
+    }
+}
+
+impl distribution::Symmetric for Laplace {}
+
+impl distribution::Variance for Laplace {
+    #[inline]
+    fn variance(&self) -> f64 {
+        2.0 * self.b * self.b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $b:expr) => (Laplace::new($mu, $b));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-3.0, -2.0, -1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0];
+        let p = vec![
+            3.3833820809153173e-02, 5.5782540037107457e-02, 9.1969860292860580e-02,
+            1.5163266492815836e-01, 1.9470019576785122e-01, 2.5000000000000000e-01,
+            1.9470019576785122e-01, 1.5163266492815836e-01, 9.1969860292860580e-02,
+            5.5782540037107457e-02, 3.3833820809153173e-02,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 2.0).to_string(), "Laplace(μ=1, b=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0);
+        let x = vec![-3.0, -2.0, -1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0];
+        let p = vec![
+            6.7667641618306346e-02, 1.1156508007421491e-01, 1.8393972058572116e-01,
+            3.0326532985631671e-01, 3.8940039153570243e-01, 5.0000000000000000e-01,
+            6.1059960846429757e-01, 6.9673467014368329e-01, 8.1606027941427884e-01,
+            8.8843491992578509e-01, 9.3233235838169365e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn entropy() {
+        assert_eq!(new!(0.0, 0.5).entropy(), 1.0);
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+
+        let d = new!(1.0, 2.0);
+        let p = vec![0.0, 0.05, 0.1, 0.25, 0.5, 0.75, 0.9, 0.95, 1.0];
+        let x = vec![
+                      NEG_INFINITY, -3.6051701859880913e+00, -2.2188758248682006e+00,
+            -3.8629436111989062e-01,  1.0000000000000000e+00,  2.3862943611198906e+00,
+             4.2188758248682012e+00,  5.6051701859880896e+00,  INFINITY,
+        ];
+
+        assert::close(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(1.0, 2.0).kurtosis(), 3.0);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 2.0).mean(), 1.0);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(1.0, 2.0).median(), 1.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 2.0).modes(), vec![1.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(1.0, 2.0);
+        let sum = Independent(&d, &mut source).take(n).fold(0.0, |a, b| a + b);
+        assert!((sum / n as f64 - 1.0).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(1.0, 2.0).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        assert_eq!(new!(1.0, 2.0).support(), (NEG_INFINITY, INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Laplace::try_new(1.0, 2.0).is_ok());
+        assert_eq!(Laplace::try_new(1.0, 0.0).err(), Some("b must be positive"));
+    }
+
+    #[test]
+    fn two_sided_p() {
+        let d = new!(1.0, 2.0);
+        assert::close(d.two_sided_p(4.0), 0.22313016014842983, 1e-15);
+        assert::close(d.two_sided_p(-2.0), 0.22313016014842983, 1e-15);
+        assert_eq!(d.two_sided_p(1.0), 1.0);
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(1.0, 2.0).variance(), 8.0);
+    }
+}
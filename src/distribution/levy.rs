@@ -0,0 +1,281 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Lévy distribution.
+///
+/// The distribution is a stable distribution with stability parameter `1 /
+/// 2` and skewness `1`. It is the distribution of the first passage time of
+/// a standard Brownian motion to the level `√c`, shifted by `μ`. Neither the
+/// mean nor the variance is finite.
+#[derive(Clone, Copy)]
+pub struct Levy {
+    mu: f64,
+    c: f64,
+}
+
+impl Levy {
+    /// Create a Lévy distribution with location parameter `mu` and scale
+    /// parameter `c`.
+    ///
+    /// It should hold that `c > 0`.
+    #[inline]
+    pub fn new(mu: f64, c: f64) -> Self {
+        should!(c > 0.0);
+        Levy { mu: mu, c: c }
+    }
+
+    /// Create a Lévy distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(mu: f64, c: f64) -> Result<Self, &'static str> {
+        must!(mu.is_finite(), "mu must be finite");
+        must!(c > 0.0, "c must be positive");
+        Ok(Levy::new(mu, c))
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 { self.mu }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn c(&self) -> f64 { self.c }
+}
+
+impl fmt::Display for Levy {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Levy(μ={}, c={})", self.mu, self.c)
+    }
+}
+
+impl distribution::Continuous for Levy {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use std::f64::consts::PI;
+        if x <= self.mu {
+            0.0
+        } else {
+            let y = x - self.mu;
+            (self.c / (2.0 * PI)).sqrt() * (-0.5 * self.c / y).exp() / (y * y.sqrt())
+        }
+    }
+}
+
+impl distribution::Distribution for Levy {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function, which is `erfc(√(c / (2
+    /// (x - μ))))`.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Error;
+        if x <= self.mu {
+            0.0
+        } else {
+            Error::erfc((0.5 * self.c / (x - self.mu)).sqrt())
+        }
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        use special::Error;
+        if x <= self.mu {
+            1.0
+        } else {
+            Error::erf((0.5 * self.c / (x - self.mu)).sqrt())
+        }
+    }
+}
+
+impl distribution::Entropy for Levy {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use std::f64::consts::PI;
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        0.5 * (1.0 + 3.0 * EULER_MASCHERONI + (16.0 * PI * self.c * self.c).ln())
+    }
+}
+
+impl distribution::Inverse for Levy {
+    /// Compute the inverse of the cumulative distribution function, which is
+    /// `μ + c / Φ⁻¹(1 - p / 2)²` where `Φ⁻¹` is the inverse of the cumulative
+    /// distribution function of the standard Gaussian distribution.
+    ///
+    /// By symmetry, `Φ⁻¹(p / 2)` is used instead to retain the accuracy for
+    /// small `p`.
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        use distribution::gaussian;
+        should!(0.0 <= p && p <= 1.0);
+        let z = gaussian::inverse(0.5 * p);
+        self.mu + self.c / (z * z)
+    }
+}
+
+impl distribution::Mean for Levy {
+    /// Return infinity, as the mean is not finite.
+    #[inline]
+    fn mean(&self) -> f64 {
+        ::std::f64::INFINITY
+    }
+}
+
+impl distribution::Median for Levy {
+    #[inline]
+    fn median(&self) -> f64 {
+        use distribution::Inverse;
+        self.inverse(0.5)
+    }
+}
+
+impl distribution::Modes for Levy {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu + self.c / 3.0]
+    }
+}
+
+impl distribution::Parametric for Levy {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for Levy {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::Inverse;
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl distribution::Support for Levy {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (self.mu, INFINITY)
+    }
+}
+
+impl distribution::Variance for Levy {
+    /// Return infinity, as the variance is not finite.
+    #[inline]
+    fn variance(&self) -> f64 {
+        ::std::f64::INFINITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $c:expr) => (Levy::new($mu, $c));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(1.0, 2.0);
+        for &x in &[0.0, 1.0, 1.5, 3.0, 10.0, 100.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(1.0, 2.0);
+        let x = vec![0.0, 1.0, 1.1, 1.5, 2.0, 3.0, 5.0, 100.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 8.0999109560891784e-04,
+            2.1596386605275220e-01, 2.0755374871029736e-01, 1.2098536225957167e-01,
+            5.4923911183465297e-02, 5.6700313325571880e-04,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 2.0).to_string(), "Levy(μ=1, c=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(1.0, 2.0);
+        let x = vec![0.0, 1.0, 1.1, 1.5, 2.0, 3.0, 5.0, 100.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 7.7442164310441553e-06,
+            4.5500263896358417e-02, 1.5729920705028513e-01, 3.1731050786291409e-01,
+            4.7950012218695348e-01, 8.8697431204005939e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(1.0, 2.0).entropy(), 4.0176299819568353, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::INFINITY;
+
+        let d = new!(1.0, 2.0);
+        for &x in &[1.1, 1.5, 2.0, 3.0, 5.0, 100.0] {
+            assert::close(d.inverse(d.distribution(x)), x, 1e-12 * x);
+        }
+        assert_eq!(d.inverse(0.0), 1.0);
+        assert_eq!(d.inverse(1.0), INFINITY);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.0, 2.0).mean(), ::std::f64::INFINITY);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(1.0, 2.0).median(), 5.3962186766354652, 1e-14);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(1.0, 3.0).modes(), vec![2.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(1.0, 2.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 1.0));
+        let median = d.median();
+        let below = samples.iter().filter(|&&x| x <= median).count() as f64 / n as f64;
+        assert!((below - 0.5).abs() < 5.0 * (0.25 / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(1.0, 2.0).support(), (1.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Levy::try_new(1.0, 2.0).is_ok());
+        assert_eq!(Levy::try_new(::std::f64::NAN, 2.0).err(), Some("mu must be finite"));
+        assert_eq!(Levy::try_new(1.0, 0.0).err(), Some("c must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(1.0, 2.0).variance(), ::std::f64::INFINITY);
+    }
+}
@@ -1,5 +1,6 @@
 use distribution;
 use source::Source;
+use std::fmt;
 
 /// A logistic distribution.
 #[derive(Clone, Copy)]
@@ -18,6 +19,14 @@ impl Logistic {
         Logistic { mu: mu, s: s }
     }
 
+    /// Create a logistic distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(mu: f64, s: f64) -> Result<Self, &'static str> {
+        must!(s > 0.0, "s must be positive");
+        Ok(Logistic::new(mu, s))
+    }
+
     /// Return the location parameter.
     #[inline(always)]
     pub fn mu(&self) -> f64 { self.mu }
@@ -34,10 +43,16 @@ impl Default for Logistic {
     }
 }
 
+impl fmt::Display for Logistic {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Logistic(μ={}, s={})", self.mu, self.s)
+    }
+}
+
 impl distribution::Continuous for Logistic {
     #[inline]
     fn density(&self, x: f64) -> f64 {
-        let exp = (-(x - self.mu) / self.s).exp();
+        let exp = (-((x - self.mu) / self.s).abs()).exp();
         exp / (self.s * (1.0 + exp).powi(2))
     }
 }
@@ -47,7 +62,13 @@ impl distribution::Distribution for Logistic {
 
     #[inline]
     fn distribution(&self, x: f64) -> f64 {
-        1.0 / (1.0 + (-(x - self.mu) / self.s).exp())
+        let z = (x - self.mu) / self.s;
+        if z < 0.0 {
+            let exp = z.exp();
+            exp / (1.0 + exp)
+        } else {
+            1.0 / (1.0 + (-z).exp())
+        }
     }
 }
 
@@ -62,7 +83,7 @@ impl distribution::Inverse for Logistic {
     #[inline]
     fn inverse(&self, p: f64) -> f64 {
         should!(0.0 <= p && p <= 1.0);
-        self.mu - self.s * (1.0 / p - 1.0).ln()
+        self.mu + self.s * (p.ln() - (-p).ln_1p())
     }
 }
 
@@ -88,6 +109,11 @@ impl distribution::Modes for Logistic {
     }
 }
 
+impl distribution::Parametric for Logistic {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
 impl distribution::Sample for Logistic {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
@@ -101,6 +127,16 @@ impl distribution::Skewness for Logistic {
     fn skewness(&self) -> f64 { 0.0 }
 }
 
+impl distribution::Support for Logistic {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        (NEG_INFINITY, INFINITY)
+    }
+}
+
+impl distribution::Symmetric for Logistic {}
+
 impl distribution::Variance for Logistic {
     #[inline]
     fn variance(&self) -> f64 {
@@ -131,6 +167,16 @@ mod tests {
         ];
 
         assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(0.0, 1.0);
+        assert_eq!(d.density(-1000.0), 0.0);
+        assert_eq!(d.density(1000.0), 0.0);
+        assert::close(d.density(-40.0), 4.2483542552915889e-18, 1e-32);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 2.0).to_string(), "Logistic(μ=1, s=2)");
     }
 
     #[test]
@@ -145,6 +191,11 @@ mod tests {
         ];
 
         assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-7);
+
+        let d = new!(0.0, 1.0);
+        assert_eq!(d.distribution(-1000.0), 0.0);
+        assert_eq!(d.distribution(1000.0), 1.0);
+        assert::close(d.distribution(-40.0), 4.2483542552915889e-18, 1e-32);
     }
 
     #[test]
@@ -186,11 +237,36 @@ mod tests {
         assert_eq!(new!(2.0, 1.0).modes(), vec![2.0]);
     }
 
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0).num_parameters(), 2);
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(2.0, 1.0).skewness(), 0.0);
     }
 
+    #[test]
+    fn support() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        assert_eq!(new!(1.0, 2.0).support(), (NEG_INFINITY, INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Logistic::try_new(1.0, 2.0).is_ok());
+        assert_eq!(Logistic::try_new(1.0, 0.0).err(), Some("s must be positive"));
+    }
+
+    #[test]
+    fn two_sided_p() {
+        let d = new!(1.0, 2.0);
+        assert::close(d.two_sided_p(4.0), 0.36485104761271268, 1e-15);
+        assert::close(d.two_sided_p(-2.0), 0.36485104761271268, 1e-15);
+        assert_eq!(d.two_sided_p(1.0), 1.0);
+    }
+
     #[test]
     fn variance() {
         use std::f64::consts::PI;
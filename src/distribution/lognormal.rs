@@ -1,5 +1,6 @@
 use distribution::{self, Gaussian};
 use source::Source;
+use std::fmt;
 
 /// A lognormal distribution.
 #[derive(Clone, Copy)]
@@ -19,6 +20,14 @@ impl Lognormal {
         Lognormal { mu: mu, sigma: sigma, gaussian: Gaussian::new(mu, sigma) }
     }
 
+    /// Create a lognormal distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(mu: f64, sigma: f64) -> Result<Self, &'static str> {
+        must!(sigma > 0.0, "sigma must be positive");
+        Ok(Lognormal::new(mu, sigma))
+    }
+
     /// Return the location parameter.
     #[inline(always)]
     pub fn mu(&self) -> f64 { self.mu }
@@ -35,6 +44,12 @@ impl Default for Lognormal {
     }
 }
 
+impl fmt::Display for Lognormal {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Lognormal(μ={}, σ={})", self.mu, self.sigma)
+    }
+}
+
 impl distribution::Continuous for Lognormal {
     fn density(&self, x: f64) -> f64 {
         use std::f64::consts::PI;
@@ -103,6 +118,11 @@ impl distribution::Modes for Lognormal {
     }
 }
 
+impl distribution::Parametric for Lognormal {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
 impl distribution::Sample for Lognormal {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
@@ -118,6 +138,14 @@ impl distribution::Skewness for Lognormal {
     }
 }
 
+impl distribution::Support for Lognormal {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
 impl distribution::Variance for Lognormal {
     #[inline]
     fn variance(&self) -> f64 {
@@ -147,6 +175,13 @@ mod tests {
         ];
 
         assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        assert_eq!(d.density(-1.0), 0.0);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 2.0).to_string(), "Lognormal(μ=1, σ=2)");
     }
 
     #[test]
@@ -161,6 +196,8 @@ mod tests {
         ];
 
         assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        assert_eq!(d.distribution(-1.0), 0.0);
     }
 
     #[test]
@@ -210,18 +247,34 @@ mod tests {
         assert_eq!(new!(1.0, 1.0).modes(), vec![1.0]);
     }
 
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0).num_parameters(), 2);
+    }
+
     #[test]
     fn skewness() {
-        assert!(4.0 - new!(0.0, 2f64.ln().sqrt()).skewness() < 1e-10);
+        assert::close(new!(0.0, 2f64.ln().sqrt()).skewness(), 4.0, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(1.0, 2.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Lognormal::try_new(1.0, 2.0).is_ok());
+        assert_eq!(Lognormal::try_new(1.0, 0.0).err(), Some("sigma must be positive"));
     }
 
     #[test]
     fn variance() {
-        assert!(2.0 - new!(0.0, 2f64.ln().sqrt()).variance() < 1e-10);
+        assert::close(new!(0.0, 2f64.ln().sqrt()).variance(), 2.0, 1e-14);
     }
 
     #[test]
     fn deviation() {
-        assert!(2f64.sqrt() - new!(0.0, 2f64.ln().sqrt()).variance() < 1e-10);
+        assert::close(new!(0.0, 2f64.ln().sqrt()).deviation(), 2f64.sqrt(), 1e-14);
     }
 }
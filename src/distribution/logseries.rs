@@ -0,0 +1,263 @@
+use distribution;
+use source::Source;
+use std::fmt;
+use std::ops::RangeFrom;
+
+/// A logarithmic distribution.
+///
+/// The distribution is also known as the log-series distribution, and its
+/// mass at `k` is proportional to `p^k / k`. The support is `{1, 2, 3, …}`.
+#[derive(Clone, Copy)]
+pub struct LogSeries {
+    p: f64,
+    ln_q: f64,
+}
+
+impl LogSeries {
+    /// Create a logarithmic distribution with parameter `p`.
+    ///
+    /// It should hold that `p > 0` and `p < 1`.
+    #[inline]
+    pub fn new(p: f64) -> Self {
+        should!(p > 0.0 && p < 1.0);
+        LogSeries { p: p, ln_q: (-p).ln_1p() }
+    }
+
+    /// Create a logarithmic distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(p: f64) -> Result<Self, &'static str> {
+        must!(p > 0.0 && p < 1.0, "p must be in (0, 1)");
+        Ok(LogSeries::new(p))
+    }
+
+    /// Return the parameter.
+    #[inline(always)]
+    pub fn p(&self) -> f64 { self.p }
+}
+
+impl fmt::Display for LogSeries {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "LogSeries(p={})", self.p)
+    }
+}
+
+impl distribution::Discrete for LogSeries {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x == 0 {
+            0.0
+        } else {
+            self.ln_mass(x).exp()
+        }
+    }
+
+    #[inline]
+    fn ln_mass(&self, x: usize) -> f64 {
+        if x == 0 {
+            return ::std::f64::NEG_INFINITY;
+        }
+        let x = x as f64;
+        x * self.p.ln() - x.ln() - (-self.ln_q).ln()
+    }
+}
+
+impl distribution::DiscreteSupport for LogSeries {
+    type Iter = RangeFrom<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (1, ::std::i64::MAX)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        1..
+    }
+}
+
+impl distribution::Distribution for LogSeries {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The masses are summed via the recurrence `p(k + 1) = p(k) p k / (k + 1)`
+    /// until `x` is reached or the remaining terms become negligible.
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            return 0.0;
+        }
+        let x = x.floor();
+        let (mut sum, mut term, mut k) = (0.0, -self.p / self.ln_q, 1.0);
+        while k <= x {
+            sum += term;
+            if term < ::std::f64::EPSILON * sum * (1.0 - self.p) {
+                break;
+            }
+            term *= self.p * k / (k + 1.0);
+            k += 1.0;
+        }
+        sum.min(1.0)
+    }
+}
+
+impl distribution::Mean for LogSeries {
+    #[inline]
+    fn mean(&self) -> f64 {
+        -self.p / ((1.0 - self.p) * self.ln_q)
+    }
+}
+
+impl distribution::Modes for LogSeries {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Parametric for LogSeries {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for LogSeries {
+    /// Draw a sample.
+    ///
+    /// ## References
+    ///
+    /// 1. A. W. Kemp, “Efficient generation of logarithmically distributed
+    ///    pseudo-random variables,” Journal of the Royal Statistical Society.
+    ///    Series C (Applied Statistics), vol. 30, no. 3, pp. 249–253, 1981.
+    fn sample<S>(&self, source: &mut S) -> usize where S: Source {
+        let v = source.read::<f64>();
+        if v >= self.p {
+            return 1;
+        }
+        let q = -(self.ln_q * source.read::<f64>()).exp_m1();
+        if v <= q * q {
+            let x = 1.0 + v.ln() / q.ln();
+            if x.is_finite() { x as usize } else { 1 }
+        } else if v <= q {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+impl distribution::Variance for LogSeries {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let (p, ln_q) = (self.p, self.ln_q);
+        -p * (p + ln_q) / ((1.0 - p) * (1.0 - p) * ln_q * ln_q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($p:expr) => (LogSeries::new($p));
+    );
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(0.5).to_string(), "LogSeries(p=0.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(0.5);
+        let p = vec![
+            0.0, 0.0, 7.2134752044448169e-01, 9.0168440055560217e-01,
+            9.6179669392597555e-01, 9.8433880393986561e-01, 9.9335564794542164e-01,
+            9.9711266628106998e-01, 9.9872281699634791e-01, 9.9942725793428200e-01,
+        ];
+
+        let x = (-1..9).map(|x| d.distribution(x as f64)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+
+        let x = (-1..9).map(|x| d.distribution(x as f64 + 0.5)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+
+        assert::close(new!(0.99).distribution(1e6), 1.0, 1e-14);
+    }
+
+    #[test]
+    fn iter_support() {
+        let d = new!(0.5);
+        assert_eq!(d.iter_support().take(3).collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn ln_mass() {
+        let d = new!(0.5);
+        for x in 1..12 {
+            assert::close(d.ln_mass(x), d.mass(x).ln(), 1e-14);
+        }
+        assert_eq!(d.ln_mass(0), ::std::f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(0.5);
+        let p = vec![
+            0.0, 7.2134752044448169e-01, 1.8033688011112042e-01,
+            6.0112293370373472e-02, 2.2542110013890053e-02, 9.0168440055560205e-03,
+            3.7570183356483420e-03, 1.6101507152778609e-03, 7.0444093793406415e-04,
+        ];
+
+        assert::close(&(0..9).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        for &p in &[0.1, 0.5, 0.9] {
+            let d = new!(p);
+            assert::close(d.masses(0..1000).iter().fold(0.0, |a, b| a + b), 1.0, 1e-14);
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(0.5).mean(), 1.4426950408889634, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(0.5).modes(), vec![1]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(0.5).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+
+        let d = new!(0.9);
+        assert!(Independent(&d, &mut source).take(1000).all(|x| x >= 1));
+
+        let n = 10000;
+        let sum = Independent(&d, &mut source).take(n).fold(0, |a, b| a + b);
+        assert!((sum as f64 / n as f64 - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(0.5).support(), (1, ::std::i64::MAX));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(LogSeries::try_new(0.5).is_ok());
+        assert_eq!(LogSeries::try_new(0.0).err(), Some("p must be in (0, 1)"));
+        assert_eq!(LogSeries::try_new(1.0).err(), Some("p must be in (0, 1)"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(0.5).variance(), 0.80402110077231902, 1e-15);
+    }
+}
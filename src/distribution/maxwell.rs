@@ -0,0 +1,244 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Maxwell–Boltzmann distribution.
+///
+/// The distribution is that of the Euclidean norm of a three-dimensional
+/// vector whose components are independent Gaussian random variables with
+/// zero mean and standard deviation `a`. In physics, it describes the speeds
+/// of particles in an ideal gas, in which case `a = √(kT / m)` and the mode
+/// is the most probable speed.
+#[derive(Clone, Copy)]
+pub struct Maxwell {
+    a: f64,
+}
+
+impl Maxwell {
+    /// Create a Maxwell–Boltzmann distribution with scale parameter `a`.
+    ///
+    /// It should hold that `a > 0`.
+    #[inline]
+    pub fn new(a: f64) -> Self {
+        should!(a > 0.0);
+        Maxwell { a: a }
+    }
+
+    /// Create a Maxwell–Boltzmann distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(a: f64) -> Result<Self, &'static str> {
+        must!(a > 0.0, "a must be positive");
+        Ok(Maxwell::new(a))
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn a(&self) -> f64 { self.a }
+}
+
+impl fmt::Display for Maxwell {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Maxwell(a={})", self.a)
+    }
+}
+
+impl distribution::Continuous for Maxwell {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        use std::f64::consts::PI;
+        if x < 0.0 {
+            0.0
+        } else {
+            let y = x / self.a;
+            (2.0 / PI).sqrt() * y * y * (-0.5 * y * y).exp() / self.a
+        }
+    }
+}
+
+impl distribution::Distribution for Maxwell {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function, which is the regularized
+    /// lower incomplete gamma function `P(3 / 2, x² / (2a²))`.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let y = x / self.a;
+            (0.5 * y * y).inc_gamma(1.5)
+        }
+    }
+}
+
+impl distribution::Entropy for Maxwell {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use std::f64::consts::PI;
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        (self.a * (2.0 * PI).sqrt()).ln() + EULER_MASCHERONI - 0.5
+    }
+}
+
+impl distribution::Kurtosis for Maxwell {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        use std::f64::consts::PI;
+        4.0 * (-96.0 + 40.0 * PI - 3.0 * PI * PI) / (3.0 * PI - 8.0).powi(2)
+    }
+}
+
+impl distribution::Mean for Maxwell {
+    #[inline]
+    fn mean(&self) -> f64 {
+        use std::f64::consts::PI;
+        2.0 * self.a * (2.0 / PI).sqrt()
+    }
+}
+
+impl distribution::Modes for Maxwell {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        use std::f64::consts::SQRT_2;
+        vec![self.a * SQRT_2]
+    }
+}
+
+impl distribution::Parametric for Maxwell {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for Maxwell {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::Gaussian;
+        let gaussian = Gaussian::standard();
+        let (x, y, z) = (gaussian.sample(source), gaussian.sample(source), gaussian.sample(source));
+        self.a * (x * x + y * y + z * z).sqrt()
+    }
+}
+
+impl distribution::Skewness for Maxwell {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        use std::f64::consts::PI;
+        2.0 * 2f64.sqrt() * (16.0 - 5.0 * PI) / (3.0 * PI - 8.0).powf(1.5)
+    }
+}
+
+impl distribution::Support for Maxwell {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for Maxwell {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use std::f64::consts::PI;
+        self.a * self.a * (3.0 * PI - 8.0) / PI
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($a:expr) => (Maxwell::new($a));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0, 8.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 2.4166757300178074e-02,
+            8.8016331691074867e-02, 2.4197072451914334e-01, 2.9141459024825639e-01,
+            2.1596386605275220e-01, 2.1412836122381654e-03,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(2.0).to_string(), "Maxwell(a=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0, 8.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 4.0785929644228452e-03,
+            3.0859595783726729e-02, 1.9874804309879920e-01, 4.7783281046460868e-01,
+            7.3853587005088939e-01, 9.9886601571021472e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0).entropy(), 1.6893013786661509, 1e-15);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0).kurtosis(), 0.10816384281629415, 1e-14);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0).mean(), 3.1915382432114614, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.0).modes(), vec![2.0 * ::std::f64::consts::SQRT_2]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.0).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(2.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0).skewness(), 0.48569282804959081, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Maxwell::try_new(2.0).is_ok());
+        assert_eq!(Maxwell::try_new(0.0).err(), Some("a must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0).variance(), 1.8140836421186985, 1e-15);
+    }
+}
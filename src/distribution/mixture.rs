@@ -0,0 +1,235 @@
+use distribution::{self, Categorical, Continuous, Distribution, Mean, Parametric, Sample, Support,
+                   Variance};
+use source::Source;
+use std::fmt;
+
+/// A finite mixture distribution.
+///
+/// The distribution is a weighted combination of continuous components. A
+/// sample is drawn by first choosing a component according to the weights and
+/// then sampling the chosen component.
+#[derive(Clone)]
+pub struct Mixture<D> {
+    weights: Vec<f64>,
+    components: Vec<D>,
+    selector: Categorical,
+}
+
+impl<D> Mixture<D> where D: Continuous + Distribution<Value=f64> + Sample {
+    /// Create a mixture of `components` with the corresponding `weights`.
+    ///
+    /// It should hold that the number of weights is equal to the number of
+    /// components, that the weights are nonnegative, and that they sum up to
+    /// one.
+    pub fn new(weights: Vec<f64>, components: Vec<D>) -> Self {
+        should!(!weights.is_empty() && weights.len() == components.len());
+        let selector = Categorical::new(&weights);
+        Mixture { weights: weights, components: components, selector: selector }
+    }
+
+    /// Create a mixture or return an error if the parameters are invalid.
+    #[inline]
+    pub fn try_new(weights: Vec<f64>, components: Vec<D>) -> Result<Self, &'static str> {
+        must!(weights.len() == components.len(), "weights and components must be of equal length");
+        must!(!weights.is_empty(), "weights must be nonempty");
+        must!(weights.iter().all(|&p| p >= 0.0 && p <= 1.0), "weights must contain probabilities");
+        must!((weights.iter().fold(0.0, |sum, &p| sum + p) - 1.0).abs() < 1e-12,
+              "weights must sum up to one");
+        Ok(Mixture::new(weights, components))
+    }
+
+    /// Return the weights.
+    #[inline(always)]
+    pub fn weights(&self) -> &[f64] { &self.weights }
+
+    /// Return the components.
+    #[inline(always)]
+    pub fn components(&self) -> &[D] { &self.components }
+}
+
+impl<D> fmt::Display for Mixture<D> where D: fmt::Display {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Mixture(")?;
+        for (i, (weight, component)) in self.weights.iter().zip(&self.components).enumerate() {
+            if i > 0 {
+                write!(formatter, ", ")?;
+            }
+            write!(formatter, "{} × {}", weight, component)?;
+        }
+        write!(formatter, ")")
+    }
+}
+
+impl<D> distribution::Continuous for Mixture<D>
+    where D: Continuous + Distribution<Value=f64> + Sample
+{
+    fn density(&self, x: f64) -> f64 {
+        self.weights.iter().zip(&self.components).fold(0.0, |sum, (&weight, component)| {
+            sum + weight * component.density(x)
+        })
+    }
+}
+
+impl<D> distribution::Distribution for Mixture<D>
+    where D: Continuous + Distribution<Value=f64> + Sample
+{
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        self.weights.iter().zip(&self.components).fold(0.0, |sum, (&weight, component)| {
+            sum + weight * component.distribution(x)
+        })
+    }
+}
+
+impl<D> distribution::Mean for Mixture<D>
+    where D: Continuous + Distribution<Value=f64> + Sample + Mean
+{
+    fn mean(&self) -> f64 {
+        self.weights.iter().zip(&self.components).fold(0.0, |sum, (&weight, component)| {
+            sum + weight * component.mean()
+        })
+    }
+}
+
+impl<D> distribution::Parametric for Mixture<D>
+    where D: Continuous + Distribution<Value=f64> + Parametric + Sample
+{
+    /// Return the number of free parameters.
+    ///
+    /// The parameters are the weights, one of which is determined by the
+    /// others, and the parameters of the components.
+    fn num_parameters(&self) -> usize {
+        self.components.iter().fold(self.weights.len() - 1, |sum, component| {
+            sum + component.num_parameters()
+        })
+    }
+}
+
+impl<D> distribution::Sample for Mixture<D>
+    where D: Continuous + Distribution<Value=f64> + Sample
+{
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        self.components[self.selector.sample(source)].sample(source)
+    }
+}
+
+impl<D> distribution::Support for Mixture<D>
+    where D: Continuous + Distribution<Value=f64> + Sample + Support
+{
+    /// Compute the support.
+    ///
+    /// The support is the smallest interval containing the supports of the
+    /// components with positive weights.
+    fn support(&self) -> (f64, f64) {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        self.weights.iter().zip(&self.components).filter(|&(&weight, _)| weight > 0.0)
+            .fold((INFINITY, NEG_INFINITY), |(lower, upper), (_, component)| {
+                let (a, b) = component.support();
+                (lower.min(a), upper.max(b))
+            })
+    }
+}
+
+impl<D> distribution::Variance for Mixture<D>
+    where D: Continuous + Distribution<Value=f64> + Sample + Variance
+{
+    /// Compute the variance.
+    ///
+    /// The variance is computed via the law of total variance, that is, as the
+    /// sum of the mean of the component variances and the variance of the
+    /// component means.
+    fn variance(&self) -> f64 {
+        let mean = self.mean();
+        self.weights.iter().zip(&self.components).fold(0.0, |sum, (&weight, component)| {
+            let deviation = component.mean() - mean;
+            sum + weight * (component.variance() + deviation * deviation)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($weights:expr, $components:expr) => (Mixture::new($weights, $components));
+    );
+
+    fn gaussians() -> Mixture<Gaussian> {
+        new!(vec![0.3, 0.7], vec![Gaussian::new(-1.0, 0.5), Gaussian::new(2.0, 1.5)])
+    }
+
+    #[test]
+    fn density() {
+        let d = gaussians();
+        let x = vec![-2.0, -1.0, 0.0, 1.0, 2.0, 4.0];
+        let p = vec![
+            3.7712706719018302e-02, 2.6456115261368068e-01, 1.0893261475670984e-01,
+            1.4915603404588995e-01, 1.8617306783286494e-01, 7.6538034848797024e-02,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(gaussians().to_string(),
+                   "Mixture(0.3 × Gaussian(μ=-1, σ=0.5), 0.7 × Gaussian(μ=2, σ=1.5))");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = gaussians();
+        let x = vec![-2.0, -1.0, 0.0, 1.0, 2.0, 4.0];
+        let p = vec![
+            9.5063059817665774e-03, 1.6592509236372543e-01, 3.5702281422365373e-01,
+            4.7673527491029610e-01, 6.4999999970402367e-01, 9.3615214619189246e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(gaussians().mean(), 1.1, 1e-15);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(gaussians().num_parameters(), 2 * 2 + 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = gaussians();
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        let below = samples.iter().filter(|&&x| x <= 0.0).count() as f64 / n as f64;
+        assert!((below - d.distribution(0.0)).abs() < 0.02);
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+
+    #[test]
+    fn support() {
+        let d = new!(vec![0.3, 0.7, 0.0], vec![Uniform::new(-1.0, 1.0), Uniform::new(0.5, 2.0),
+                                              Uniform::new(-5.0, 5.0)]);
+        assert_eq!(d.support(), (-1.0, 2.0));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Mixture::try_new(vec![0.5, 0.5], vec![Gaussian::new(-1.0, 1.0), Gaussian::new(1.0, 1.0)]).is_ok());
+        assert_eq!(Mixture::try_new(vec![0.5, 0.5], vec![Gaussian::new(0.0, 1.0)]).err(),
+                   Some("weights and components must be of equal length"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(gaussians().variance(), 3.54, 1e-14);
+    }
+}
@@ -1,17 +1,96 @@
 //! Probability distributions.
 
+use sampler::Samples;
 use source::Source;
+use std::ops::Range;
+
+/// A distribution capable of computing the characteristic function.
+pub trait CharacteristicFunction: Distribution {
+    /// Compute the characteristic function, which is `φ(t) = E[exp(itX)]`.
+    ///
+    /// The real and imaginary parts are returned, respectively.
+    fn characteristic(&self, t: f64) -> (f64, f64);
+}
 
 /// A continuous distribution.
 pub trait Continuous: Distribution {
     /// Compute the probability density function.
     fn density(&self, f64) -> f64;
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The default implementation takes the logarithm of `density`, which
+    /// underflows in the tails; distributions are encouraged to provide a
+    /// direct computation.
+    #[inline]
+    fn ln_density(&self, x: f64) -> f64 {
+        self.density(x).ln()
+    }
+
+    /// Compute the probability density function at each point of `xs` and
+    /// write the results to `out`.
+    ///
+    /// It should hold that `xs` and `out` have the same length. The default
+    /// implementation calls `density` for each point; distributions are
+    /// encouraged to hoist the computations shared by all points.
+    fn density_into(&self, xs: &[f64], out: &mut [f64]) {
+        should!(xs.len() == out.len());
+        for (&x, y) in xs.iter().zip(out.iter_mut()) {
+            *y = self.density(x);
+        }
+    }
+
+    /// Compute the expectation of `f(X)`.
+    ///
+    /// The default implementation integrates `f(x)` weighted by the density
+    /// numerically using adaptive Gauss–Kronrod quadrature. The real line is
+    /// partitioned at quantiles located by bisection of `distribution`, which
+    /// places the nodes where the probability mass is irrespective of the
+    /// location and scale of the distribution.
+    fn expectation<F>(&self, f: F) -> f64 where F: Fn(f64) -> f64, Self: Sized {
+        quadrature::expectation(self, f)
+    }
 }
 
 /// A discrete distribution.
 pub trait Discrete: Distribution {
     /// Compute the probability mass function.
     fn mass(&self, Self::Value) -> f64;
+
+    /// Compute the natural logarithm of the probability mass function.
+    ///
+    /// The default implementation takes the logarithm of `mass`, which
+    /// underflows in the tails; distributions are encouraged to provide a
+    /// direct computation.
+    #[inline]
+    fn ln_mass(&self, x: Self::Value) -> f64 {
+        self.mass(x).ln()
+    }
+
+    /// Compute the probability mass function at each point of `range`.
+    ///
+    /// The default implementation calls `mass` for each point; distributions
+    /// are encouraged to provide a faster computation.
+    fn masses(&self, range: Range<usize>) -> Vec<f64> where Self: Distribution<Value=usize> {
+        range.map(|x| self.mass(x)).collect()
+    }
+}
+
+/// A discrete distribution capable of computing the support.
+pub trait DiscreteSupport: Discrete {
+    /// The type of the iterator over the support.
+    type Iter: Iterator<Item=Self::Value>;
+
+    /// Compute the support, which is the smallest interval `[lower, upper]`
+    /// outside of which the mass is zero.
+    ///
+    /// The upper bound is `i64::MAX` when the support is unbounded.
+    fn support(&self) -> (i64, i64);
+
+    /// Return an iterator over the values of the support in increasing order.
+    ///
+    /// The iterator is infinite when the support is unbounded.
+    fn iter_support(&self) -> Self::Iter;
 }
 
 /// A distribution.
@@ -21,6 +100,17 @@ pub trait Distribution {
 
     /// Compute the cumulative distribution function.
     fn distribution(&self, f64) -> f64;
+
+    /// Compute the complementary cumulative distribution function, which is
+    /// `P(X > x) = 1 - F(x)`.
+    ///
+    /// The default implementation subtracts `distribution` from one, which
+    /// loses precision in the right tail; distributions are encouraged to
+    /// provide a direct computation.
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        1.0 - self.distribution(x)
+    }
 }
 
 /// A distribution capable of computing the differential entropy.
@@ -35,6 +125,19 @@ pub trait Entropy: Distribution {
 pub trait Inverse: Distribution {
     /// Compute the inverse of the cumulative distribution function.
     fn inverse(&self, f64) -> Self::Value;
+
+    /// Compute the first quartile, the median, and the third quartile.
+    #[inline]
+    fn quartiles(&self) -> (f64, f64, f64) where Self: Distribution<Value=f64> {
+        (self.inverse(0.25), self.inverse(0.5), self.inverse(0.75))
+    }
+
+    /// Compute the interquartile range, which is the difference between the
+    /// third and first quartiles.
+    #[inline]
+    fn iqr(&self) -> f64 where Self: Distribution<Value=f64> {
+        self.inverse(0.75) - self.inverse(0.25)
+    }
 }
 
 /// A distribution capable of computing the excess kurtosis.
@@ -67,10 +170,90 @@ pub trait Modes: Distribution {
     fn modes(&self) -> Vec<Self::Value>;
 }
 
+/// A distribution capable of computing moments.
+pub trait Moment: Kurtosis {
+    /// Compute the raw moment of order `n`, which is `E[X^n]`.
+    ///
+    /// The default implementation expands the raw moment in terms of the
+    /// central moments.
+    fn raw_moment(&self, n: u32) -> f64 {
+        let mean = self.mean();
+        let mut sum = 0.0;
+        let mut coefficient = 1.0;
+        for i in 0..(n + 1) {
+            sum += coefficient * mean.powi((n - i) as i32) * self.central_moment(i);
+            coefficient *= (n - i) as f64 / (i + 1) as f64;
+        }
+        sum
+    }
+
+    /// Compute the central moment of order `n`, which is `E[(X - E[X])^n]`.
+    ///
+    /// The default implementation is based on the variance, skewness, and
+    /// kurtosis and is hence limited to `n <= 4`; NaN is returned otherwise.
+    fn central_moment(&self, n: u32) -> f64 {
+        match n {
+            0 => 1.0,
+            1 => 0.0,
+            2 => self.variance(),
+            3 => self.skewness() * self.variance().powf(1.5),
+            4 => (self.kurtosis() + 3.0) * self.variance().powi(2),
+            _ => ::std::f64::NAN,
+        }
+    }
+}
+
+/// A parametric distribution.
+pub trait Parametric: Distribution {
+    /// Return the number of free parameters.
+    ///
+    /// The parameters counted are those estimated when the distribution is
+    /// fitted to data. Parameters that fix the sample space, such as the
+    /// number of trials of a binomial distribution or the support of a beta
+    /// distribution, are not counted.
+    fn num_parameters(&self) -> usize;
+}
+
+/// A distribution capable of computing reliability functions.
+///
+/// The default implementations are based on the cumulative distribution
+/// function and the probability density function.
+pub trait Reliability: Continuous {
+    /// Compute the survival function, which is `S(x) = 1 - F(x)`.
+    ///
+    /// The default implementation calls `complementary`.
+    #[inline]
+    fn survival(&self, x: f64) -> f64 {
+        self.complementary(x)
+    }
+
+    /// Compute the hazard function, which is `h(x) = f(x) / S(x)`.
+    #[inline]
+    fn hazard(&self, x: f64) -> f64 {
+        self.density(x) / self.survival(x)
+    }
+
+    /// Compute the cumulative hazard function, which is `H(x) = -ln S(x)`.
+    #[inline]
+    fn cumulative_hazard(&self, x: f64) -> f64 {
+        -self.survival(x).ln()
+    }
+}
+
 /// A distribution capable of drawing samples.
 pub trait Sample: Distribution {
     /// Draw a sample.
     fn sample<S>(&self, &mut S) -> Self::Value where S: Source;
+
+    /// Create a lazy iterator over independent samples.
+    ///
+    /// The source is borrowed mutably for as long as the iterator lives.
+    #[inline]
+    fn sample_iter<'a, S>(&'a self, source: &'a mut S) -> Samples<'a, Self, S>
+        where Self: Sized, S: Source
+    {
+        Samples::new(self, source)
+    }
 }
 
 /// A distribution capable of computing the skewness.
@@ -79,6 +262,28 @@ pub trait Skewness: Variance {
     fn skewness(&self) -> f64;
 }
 
+/// A continuous distribution capable of computing the support.
+pub trait Support: Continuous {
+    /// Compute the support, which is the smallest interval `(lower, upper)`
+    /// outside of which the density is zero.
+    ///
+    /// Either bound can be infinite.
+    fn support(&self) -> (f64, f64);
+}
+
+/// A distribution symmetric about its center.
+///
+/// The trait marks the distributions for which two-sided tail probabilities
+/// are meaningful without further qualification.
+pub trait Symmetric: Distribution {
+    /// Compute the two-sided p-value of the test statistic `x`, which is
+    /// `2 min(F(x), 1 - F(x))`.
+    fn two_sided_p(&self, x: f64) -> f64 {
+        let p = 2.0 * self.distribution(x).min(self.complementary(x));
+        if p > 1.0 { 1.0 } else { p }
+    }
+}
+
 /// A distribution capable of computing the variance.
 ///
 /// The trait is applicable when the variance exists, that is, finite.
@@ -93,26 +298,122 @@ pub trait Variance: Mean {
     }
 }
 
+pub mod divergence;
+
+mod affine;
+mod benford;
 mod bernoulli;
 mod beta;
+mod betabinomial;
 mod binomial;
 mod categorical;
+mod cauchy;
+mod chi;
+mod chisquared;
+mod compoisson;
+mod dirichlet;
+mod discreteuniform;
+mod empirical;
+mod erlang;
 mod exponential;
+mod fisher;
+mod foldednormal;
+mod frechet;
 mod gamma;
 mod gaussian;
+mod genpareto;
+mod geometric;
+mod gev;
+mod gompertz;
+mod gumbel;
+mod halfnormal;
+mod hypergeometric;
+mod inversegamma;
+mod irwinhall;
+mod kde;
+mod kumaraswamy;
+mod laplace;
+mod levy;
 mod logistic;
 mod lognormal;
+mod logseries;
+mod maxwell;
+mod mixture;
+mod multinomial;
+mod mvgaussian;
+mod nakagami;
+mod noncentralchisquared;
+mod poisson;
+mod quadrature;
+mod rayleigh;
+mod skellam;
+mod skewnormal;
+mod students_t;
 mod triangular;
+mod truncated;
 mod uniform;
+mod vonmises;
+mod wald;
+mod weibull;
+mod yulesimon;
+mod zipf;
+
+#[cfg(feature = "serde")]
+mod serialization;
 
+pub use self::affine::Affine;
+pub use self::benford::Benford;
 pub use self::bernoulli::Bernoulli;
 pub use self::beta::Beta;
+pub use self::betabinomial::BetaBinomial;
 pub use self::binomial::Binomial;
 pub use self::categorical::Categorical;
+pub use self::cauchy::Cauchy;
+pub use self::chi::Chi;
+pub use self::chisquared::Chisquared;
+pub use self::compoisson::ComPoisson;
+pub use self::dirichlet::Dirichlet;
+pub use self::discreteuniform::DiscreteUniform;
+pub use self::empirical::Empirical;
+pub use self::erlang::Erlang;
 pub use self::exponential::Exponential;
+pub use self::fisher::Fisher;
+pub use self::foldednormal::FoldedNormal;
+pub use self::frechet::Frechet;
 pub use self::gamma::Gamma;
-pub use self::gaussian::Gaussian;
+pub use self::gaussian::{Gaussian, GaussianMethod};
+pub use self::genpareto::GeneralizedPareto;
+pub use self::geometric::Geometric;
+pub use self::gev::Gev;
+pub use self::gompertz::Gompertz;
+pub use self::gumbel::Gumbel;
+pub use self::halfnormal::HalfNormal;
+pub use self::hypergeometric::Hypergeometric;
+pub use self::inversegamma::InverseGamma;
+pub use self::irwinhall::{Bates, IrwinHall};
+pub use self::kde::Kde;
+pub use self::kumaraswamy::Kumaraswamy;
+pub use self::laplace::Laplace;
+pub use self::levy::Levy;
 pub use self::logistic::Logistic;
 pub use self::lognormal::Lognormal;
+pub use self::logseries::LogSeries;
+pub use self::maxwell::Maxwell;
+pub use self::mixture::Mixture;
+pub use self::multinomial::Multinomial;
+pub use self::mvgaussian::MvGaussian;
+pub use self::nakagami::Nakagami;
+pub use self::noncentralchisquared::NoncentralChisquared;
+pub use self::poisson::Poisson;
+pub use self::rayleigh::Rayleigh;
+pub use self::skellam::Skellam;
+pub use self::skewnormal::SkewNormal;
+pub use self::students_t::StudentsT;
 pub use self::triangular::Triangular;
+pub use self::truncated::Truncated;
 pub use self::uniform::Uniform;
+pub use self::vonmises::VonMises;
+pub use self::wald::Wald;
+pub use self::weibull::Weibull;
+pub use self::yulesimon::YuleSimon;
+pub use self::zipf::{Zeta, Zipf};
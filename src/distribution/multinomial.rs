@@ -0,0 +1,196 @@
+use source::Source;
+use std::fmt;
+
+/// A multinomial distribution.
+///
+/// The distribution is multivariate, and its outcomes are vectors of counts.
+/// Since the scalar traits of the module do not apply, the distribution is
+/// operated via the methods defined on the type itself. The single-trial case
+/// is covered by `Categorical`.
+#[derive(Clone)]
+pub struct Multinomial {
+    n: u64,
+    p: Vec<f64>,
+}
+
+impl Multinomial {
+    /// Create a multinomial distribution with `n` trials and event
+    /// probabilities `p`.
+    ///
+    /// It should hold that `p[i] >= 0`, `p[i] <= 1`, and `sum(p) == 1`.
+    pub fn new(n: u64, p: Vec<f64>) -> Self {
+        should!(is_probability_vector(&p), {
+            const EPSILON: f64 = 1e-12;
+            p.iter().all(|&p| p >= 0.0 && p <= 1.0) &&
+                (p.iter().fold(0.0, |sum, &p| sum + p) - 1.0).abs() < EPSILON
+        });
+        Multinomial { n: n, p: p }
+    }
+
+    /// Create a multinomial distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(n: u64, p: Vec<f64>) -> Result<Self, &'static str> {
+        must!(!p.is_empty(), "p must be nonempty");
+        must!(p.iter().all(|&p| p >= 0.0 && p <= 1.0), "p must contain probabilities");
+        must!((p.iter().fold(0.0, |sum, &p| sum + p) - 1.0).abs() < 1e-12, "p must sum up to one");
+        Ok(Multinomial::new(n, p))
+    }
+
+    /// Return the number of trials.
+    #[inline(always)]
+    pub fn n(&self) -> u64 { self.n }
+
+    /// Return the number of categories.
+    #[inline(always)]
+    pub fn k(&self) -> usize { self.p.len() }
+
+    /// Return the event probabilities.
+    #[inline(always)]
+    pub fn p(&self) -> &[f64] { &self.p }
+
+    /// Compute the probability mass function.
+    ///
+    /// The mass is zero if the dimension of `counts` is wrong or if the counts
+    /// do not sum up to the number of trials.
+    pub fn mass(&self, counts: &[u64]) -> f64 {
+        use special::Gamma;
+        if counts.len() != self.p.len() || counts.iter().sum::<u64>() != self.n {
+            return 0.0;
+        }
+        let mut ln_mass = Gamma::ln_gamma(self.n as f64 + 1.0).0;
+        for (&x, &p) in counts.iter().zip(&self.p) {
+            if x == 0 {
+                continue;
+            }
+            if p == 0.0 {
+                return 0.0;
+            }
+            let x = x as f64;
+            ln_mass += x * p.ln() - Gamma::ln_gamma(x + 1.0).0;
+        }
+        ln_mass.exp()
+    }
+
+    /// Compute the expected value.
+    pub fn mean(&self) -> Vec<f64> {
+        let n = self.n as f64;
+        self.p.iter().map(|&p| n * p).collect()
+    }
+
+    /// Compute the covariance matrix.
+    ///
+    /// The matrix is returned as a vector of rows.
+    pub fn covariance(&self) -> Vec<Vec<f64>> {
+        let n = self.n as f64;
+        self.p.iter().enumerate().map(|(i, &p_i)| {
+            self.p.iter().enumerate().map(|(j, &p_j)| {
+                if i == j { n * p_i * (1.0 - p_i) } else { -n * p_i * p_j }
+            }).collect()
+        }).collect()
+    }
+
+    /// Draw a sample.
+    ///
+    /// The counts are drawn one after another from binomial distributions
+    /// conditioned on the trials left over by the preceding categories.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<u64> where S: Source {
+        use distribution::{Binomial, Sample};
+        let k = self.p.len();
+        let mut counts = vec![0; k];
+        let mut n = self.n;
+        let mut rest = 1.0;
+        for i in 0..(k - 1) {
+            if n == 0 {
+                break;
+            }
+            let p = self.p[i] / rest;
+            counts[i] = if p >= 1.0 {
+                n
+            } else if p <= 0.0 {
+                0
+            } else {
+                Binomial::new(n as usize, p).sample(source) as u64
+            };
+            n -= counts[i];
+            rest -= self.p[i];
+        }
+        counts[k - 1] += n;
+        counts
+    }
+}
+
+impl fmt::Display for Multinomial {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Multinomial(n={}, p={:?})", self.n, self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($n:expr, $p:expr) => (Multinomial::new($n, $p.to_vec()));
+    );
+
+    #[test]
+    fn covariance() {
+        let d = new!(10, [0.2, 0.3, 0.5]);
+        let c = d.covariance();
+        assert::close(&c[0], &[1.6, -0.6, -1.0], 1e-15);
+        assert::close(&c[1], &[-0.6, 2.1, -1.5], 1e-15);
+        assert::close(&c[2], &[-1.0, -1.5, 2.5], 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(10, [0.25, 0.75]).to_string(), "Multinomial(n=10, p=[0.25, 0.75])");
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(10, [0.2, 0.3, 0.5]);
+        assert::close(d.mass(&[2, 3, 5]), 8.5050000000000000e-02, 1e-14);
+        assert::close(d.mass(&[0, 0, 10]), 9.7656250000000000e-04, 1e-15);
+        assert::close(d.mass(&[10, 0, 0]), 1.0240000000000000e-07, 1e-19);
+        assert_eq!(d.mass(&[2, 3, 4]), 0.0);
+        assert_eq!(d.mass(&[2, 3, 6]), 0.0);
+        assert_eq!(d.mass(&[5, 5]), 0.0);
+
+        let d = new!(4, [0.5, 0.0, 0.5]);
+        assert::close(d.mass(&[2, 0, 2]), 0.375, 1e-15);
+        assert_eq!(d.mass(&[2, 1, 1]), 0.0);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(10, [0.25, 0.75]).mean(), vec![2.5, 7.5]);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let d = new!(20, [0.2, 0.0, 0.3, 0.5]);
+        let n = 10000;
+        let mut mean = vec![0.0; 4];
+        for _ in 0..n {
+            let x = d.sample(&mut source);
+            assert_eq!(x.iter().sum::<u64>(), 20);
+            assert_eq!(x[1], 0);
+            for i in 0..4 {
+                mean[i] += x[i] as f64 / n as f64;
+            }
+        }
+        assert::close(&mean, &d.mean(), 0.1);
+
+        assert_eq!(new!(5, [0.0, 1.0]).sample(&mut source), vec![0, 5]);
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Multinomial::try_new(5, vec![0.25, 0.75]).is_ok());
+        assert_eq!(Multinomial::try_new(5, vec![0.5, 0.75]).err(), Some("p must sum up to one"));
+    }
+}
@@ -0,0 +1,223 @@
+use source::Source;
+use std::fmt;
+
+/// A multivariate Gaussian distribution.
+///
+/// The distribution is parameterized by a mean vector `μ` and a symmetric
+/// positive-definite covariance matrix `Σ`, which is factorized as `Σ = L Lᵀ`
+/// by the Cholesky decomposition once on creation. Since the scalar traits of
+/// the module do not apply, the distribution is operated via the methods
+/// defined on the type itself.
+#[derive(Clone)]
+pub struct MvGaussian {
+    mean: Vec<f64>,
+    covariance: Vec<Vec<f64>>,
+    factor: Vec<Vec<f64>>,
+    ln_norm: f64,
+}
+
+impl MvGaussian {
+    /// Create a multivariate Gaussian distribution with mean vector `mean` and
+    /// covariance matrix `covariance`, which is given as a vector of rows.
+    ///
+    /// It should hold that `mean` is nonempty and that `covariance` is a
+    /// symmetric positive-definite matrix of the same dimension.
+    pub fn new(mean: Vec<f64>, covariance: Vec<Vec<f64>>) -> Self {
+        should!(!mean.is_empty() && is_square(&covariance, mean.len()) &&
+                is_symmetric(&covariance));
+        let distribution = MvGaussian::factorize(mean, covariance);
+        should!(distribution.ln_norm.is_finite());
+        distribution
+    }
+
+    /// Create a multivariate Gaussian distribution or return an error if the
+    /// parameters are invalid.
+    pub fn try_new(mean: Vec<f64>, covariance: Vec<Vec<f64>>) -> Result<Self, &'static str> {
+        must!(!mean.is_empty(), "mean must be nonempty");
+        must!(is_square(&covariance, mean.len()),
+              "covariance must be a square matrix of the dimension of mean");
+        must!(is_symmetric(&covariance), "covariance must be symmetric");
+        let distribution = MvGaussian::factorize(mean, covariance);
+        must!(distribution.ln_norm.is_finite(), "covariance must be positive definite");
+        Ok(distribution)
+    }
+
+    /// Return the number of dimensions.
+    #[inline(always)]
+    pub fn dimension(&self) -> usize { self.mean.len() }
+
+    /// Return the mean vector.
+    #[inline(always)]
+    pub fn mean(&self) -> &[f64] { &self.mean }
+
+    /// Return the covariance matrix as a vector of rows.
+    #[inline(always)]
+    pub fn covariance(&self) -> &[Vec<f64>] { &self.covariance }
+
+    /// Compute the probability density function.
+    ///
+    /// The density is zero if the dimension of `x` is wrong.
+    pub fn density(&self, x: &[f64]) -> f64 {
+        if x.len() != self.mean.len() {
+            return 0.0;
+        }
+        let mut y = Vec::with_capacity(x.len());
+        for (i, row) in self.factor.iter().enumerate() {
+            let sum = (0..i).fold(x[i] - self.mean[i], |sum, j| sum - row[j] * y[j]);
+            y.push(sum / row[i]);
+        }
+        (self.ln_norm - 0.5 * y.iter().fold(0.0, |sum, &y| sum + y * y)).exp()
+    }
+
+    /// Draw a sample.
+    ///
+    /// The sample is `μ + L z` where `z` is a vector of independent standard
+    /// Gaussian variates.
+    pub fn sample<S>(&self, source: &mut S) -> Vec<f64> where S: Source {
+        use distribution::gaussian;
+        let z = (0..self.mean.len()).map(|_| gaussian::sample(source)).collect::<Vec<_>>();
+        self.factor.iter().zip(&self.mean).map(|(row, &mean)| {
+            row.iter().zip(&z).fold(mean, |sum, (&l, &z)| sum + l * z)
+        }).collect()
+    }
+
+    // Compute the Cholesky factor, whose rows are stored up to the diagonal,
+    // together with the logarithm of the normalizing constant. The constant
+    // is not finite if the covariance matrix is not positive definite.
+    fn factorize(mean: Vec<f64>, covariance: Vec<Vec<f64>>) -> Self {
+        use std::f64::consts::PI;
+        let d = mean.len();
+        let mut factor: Vec<Vec<f64>> = Vec::with_capacity(d);
+        let mut ln_norm = -0.5 * d as f64 * (2.0 * PI).ln();
+        for i in 0..d {
+            let mut row = Vec::with_capacity(i + 1);
+            for j in 0..(i + 1) {
+                let sum = (0..j).fold(covariance[i][j], |sum, k| {
+                    sum - row[k] * if j < i { factor[j][k] } else { row[k] }
+                });
+                if j < i {
+                    row.push(sum / factor[j][j]);
+                } else if sum > 0.0 {
+                    row.push(sum.sqrt());
+                    ln_norm -= 0.5 * sum.ln();
+                } else {
+                    row.push(::std::f64::NAN);
+                    ln_norm = ::std::f64::NAN;
+                }
+            }
+            factor.push(row);
+        }
+        MvGaussian { mean: mean, covariance: covariance, factor: factor, ln_norm: ln_norm }
+    }
+}
+
+impl fmt::Display for MvGaussian {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "MvGaussian(μ={:?}, Σ={:?})", self.mean, self.covariance)
+    }
+}
+
+fn is_square(matrix: &[Vec<f64>], d: usize) -> bool {
+    matrix.len() == d && matrix.iter().all(|row| row.len() == d)
+}
+
+fn is_symmetric(matrix: &[Vec<f64>]) -> bool {
+    (0..matrix.len()).all(|i| (0..i).all(|j| matrix[i][j] == matrix[j][i]))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mean:expr, $covariance:expr) => (MvGaussian::new($mean, $covariance));
+    );
+
+    #[test]
+    fn covariance() {
+        let d = new!(vec![1.0, -1.0], vec![vec![2.0, 0.6], vec![0.6, 1.0]]);
+        assert_eq!(d.covariance(), &[vec![2.0, 0.6], vec![0.6, 1.0]][..]);
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(vec![1.0, -1.0], vec![vec![2.0, 0.6], vec![0.6, 1.0]]);
+        let x = vec![vec![1.0, -1.0], vec![0.0, 0.0], vec![2.5, -0.5], vec![-1.0, 2.0]];
+        let p = vec![
+            1.2427913092914156e-01, 3.4537382452756629e-02, 7.0704251213345684e-02,
+            1.6909005439462822e-05,
+        ];
+        assert::close(&x.iter().map(|x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+        assert_eq!(d.density(&[1.0]), 0.0);
+
+        let (d, e) = (new!(vec![1.5], vec![vec![4.0]]), Gaussian::new(1.5, 2.0));
+        for &x in &[-2.0, 0.0, 1.5, 4.0] {
+            assert::close(d.density(&[x]), e.density(x), 1e-15);
+        }
+
+        let d = new!(vec![0.0, 1.0], vec![vec![1.0, 0.0], vec![0.0, 9.0]]);
+        let (e1, e2) = (Gaussian::new(0.0, 1.0), Gaussian::new(1.0, 3.0));
+        assert::close(d.density(&[0.5, -2.0]), e1.density(0.5) * e2.density(-2.0), 1e-15);
+    }
+
+    #[test]
+    fn dimension() {
+        assert_eq!(new!(vec![0.0, 0.0, 0.0], vec![vec![1.0, 0.0, 0.0],
+                                                   vec![0.0, 1.0, 0.0],
+                                                   vec![0.0, 0.0, 1.0]]).dimension(), 3);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(vec![1.0], vec![vec![2.5]]).to_string(), "MvGaussian(μ=[1.0], Σ=[[2.5]])");
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(vec![1.0, -1.0], vec![vec![2.0, 0.6], vec![0.6, 1.0]]).mean(),
+                   &[1.0, -1.0]);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let mean = vec![1.0, -2.0, 0.5];
+        let covariance = vec![vec![4.0, 1.2, -0.8], vec![1.2, 1.0, 0.3], vec![-0.8, 0.3, 2.25]];
+        let d = new!(mean.clone(), covariance.clone());
+        let n = 100000;
+        let samples = (0..n).map(|_| d.sample(&mut source)).collect::<Vec<_>>();
+        assert!(samples.iter().all(|x| x.len() == 3));
+
+        let mut estimate = vec![0.0; 3];
+        for x in &samples {
+            for i in 0..3 {
+                estimate[i] += x[i] / n as f64;
+            }
+        }
+        assert::close(&estimate, &mean, 5e-2);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let estimate = samples.iter().fold(0.0, |sum, x| {
+                    sum + (x[i] - estimate[i]) * (x[j] - estimate[j])
+                }) / (n - 1) as f64;
+                assert::close(estimate, covariance[i][j], 1e-1);
+            }
+        }
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(MvGaussian::try_new(vec![0.0, 0.0], vec![vec![2.0, 0.6], vec![0.6, 1.0]]).is_ok());
+        assert_eq!(MvGaussian::try_new(vec![], vec![]).err(), Some("mean must be nonempty"));
+        assert_eq!(MvGaussian::try_new(vec![0.0, 0.0], vec![vec![1.0, 0.0]]).err(),
+                   Some("covariance must be a square matrix of the dimension of mean"));
+        assert_eq!(MvGaussian::try_new(vec![0.0, 0.0], vec![vec![1.0, 0.5], vec![0.6, 1.0]]).err(),
+                   Some("covariance must be symmetric"));
+        assert_eq!(MvGaussian::try_new(vec![0.0, 0.0], vec![vec![1.0, 2.0], vec![2.0, 1.0]]).err(),
+                   Some("covariance must be positive definite"));
+        assert_eq!(MvGaussian::try_new(vec![0.0, 0.0], vec![vec![1.0, 1.0], vec![1.0, 1.0]]).err(),
+                   Some("covariance must be positive definite"));
+    }
+}
@@ -0,0 +1,278 @@
+use distribution::{self, Rayleigh};
+use source::Source;
+use std::fmt;
+
+/// A Nakagami distribution.
+///
+/// The distribution is that of the square root of a gamma variate with shape
+/// `m` and scale `Ω / m`, so that `Ω` is the second moment. It is a common
+/// model of the amplitude of a fading radio signal. The case `m = 1` is the
+/// Rayleigh distribution with `σ = √(Ω / 2)`, to which the computations are
+/// delegated.
+#[derive(Clone, Copy)]
+pub struct Nakagami {
+    m: f64,
+    omega: f64,
+    ln_norm: f64,
+}
+
+impl Nakagami {
+    /// Create a Nakagami distribution with shape parameter `m` and spread
+    /// parameter `omega`.
+    ///
+    /// It should hold that `m >= 0.5` and `omega > 0`.
+    #[inline]
+    pub fn new(m: f64, omega: f64) -> Self {
+        use special::Gamma;
+        use std::f64::consts::LN_2;
+        should!(m >= 0.5 && omega > 0.0);
+        Nakagami {
+            m: m,
+            omega: omega,
+            ln_norm: LN_2 + m * (m / omega).ln() - Gamma::ln_gamma(m).0,
+        }
+    }
+
+    /// Create a Nakagami distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(m: f64, omega: f64) -> Result<Self, &'static str> {
+        must!(m >= 0.5, "m must be at least 0.5");
+        must!(omega > 0.0, "omega must be positive");
+        Ok(Nakagami::new(m, omega))
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn m(&self) -> f64 { self.m }
+
+    /// Return the spread parameter.
+    #[inline(always)]
+    pub fn omega(&self) -> f64 { self.omega }
+
+    #[inline(always)]
+    fn rayleigh(&self) -> Option<Rayleigh> {
+        if self.m == 1.0 { Some(Rayleigh::new((0.5 * self.omega).sqrt())) } else { None }
+    }
+}
+
+impl fmt::Display for Nakagami {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Nakagami(m={}, Ω={})", self.m, self.omega)
+    }
+}
+
+impl distribution::Continuous for Nakagami {
+    fn density(&self, x: f64) -> f64 {
+        if let Some(rayleigh) = self.rayleigh() {
+            return rayleigh.density(x);
+        }
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if self.m == 0.5 { self.ln_norm.exp() } else { 0.0 }
+        } else {
+            ((2.0 * self.m - 1.0) * x.ln() - self.m * x * x / self.omega + self.ln_norm).exp()
+        }
+    }
+}
+
+impl distribution::Distribution for Nakagami {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function, which is the regularized
+    /// lower incomplete gamma function `P(m, m x² / Ω)`.
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if let Some(rayleigh) = self.rayleigh() {
+            return rayleigh.distribution(x);
+        }
+        if x <= 0.0 {
+            0.0
+        } else {
+            (self.m * x * x / self.omega).inc_gamma(self.m)
+        }
+    }
+}
+
+impl distribution::Mean for Nakagami {
+    fn mean(&self) -> f64 {
+        use special::Gamma;
+        if let Some(rayleigh) = self.rayleigh() {
+            return rayleigh.mean();
+        }
+        let m = self.m;
+        (Gamma::ln_gamma(m + 0.5).0 - Gamma::ln_gamma(m).0).exp() * (self.omega / m).sqrt()
+    }
+}
+
+impl distribution::Modes for Nakagami {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        if let Some(rayleigh) = self.rayleigh() {
+            return rayleigh.modes();
+        }
+        vec![((2.0 * self.m - 1.0) * self.omega / (2.0 * self.m)).sqrt()]
+    }
+}
+
+impl distribution::Parametric for Nakagami {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for Nakagami {
+    /// Draw a sample.
+    ///
+    /// The sample is obtained as the square root of a gamma variate with shape
+    /// `m` and scale `Ω / m`.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::gamma;
+        if let Some(rayleigh) = self.rayleigh() {
+            return rayleigh.sample(source);
+        }
+        (self.omega / self.m * gamma::sample(self.m, source)).sqrt()
+    }
+}
+
+impl distribution::Support for Nakagami {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for Nakagami {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use distribution::Mean;
+        if let Some(rayleigh) = self.rayleigh() {
+            return rayleigh.variance();
+        }
+        let mean = self.mean();
+        self.omega - mean * mean
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($m:expr, $omega:expr) => (Nakagami::new($m, $omega));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(2.5, 3.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 4.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 3.5365614711902718e-03,
+            4.8399701265889009e-02, 4.1450389183155406e-01, 7.4046334305396799e-01,
+            5.4439282224950103e-01, 3.9544633429325192e-04,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(0.5, 3.0);
+        let p = vec![
+            0.0000000000000000e+00, 4.6065886596178063e-01, 4.5588524189754864e-01,
+            4.4185912755439161e-01, 3.8993931144548227e-01, 3.1660589975553932e-01,
+            2.3651014781891838e-01, 3.2008167843406453e-02,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.0, 3.0);
+        let e = Rayleigh::new(1.5f64.sqrt());
+        for &x in &x {
+            assert_eq!(d.density(x), e.density(x));
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(2.5, 3.0).to_string(), "Nakagami(m=2.5, Ω=3)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.5, 3.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 4.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 1.7949019010779737e-04,
+            5.1419231149265514e-03, 1.0692785926404226e-01, 4.1405888158502946e-01,
+            7.5336584781394778e-01, 9.9993376813657675e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+
+        let d = new!(1.0, 3.0);
+        let e = Rayleigh::new(1.5f64.sqrt());
+        for &x in &x {
+            assert_eq!(d.distribution(x), e.distribution(x));
+        }
+        let d = new!(1.0 + 1e-12, 3.0);
+        for &x in &x {
+            assert::close(d.distribution(x), e.distribution(x), 1e-11);
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.5, 3.0).mean(), 1.6481032619656089, 1e-15);
+        assert::close(new!(0.5, 3.0).mean(), (6.0 / ::std::f64::consts::PI).sqrt(), 1e-15);
+        assert_eq!(new!(1.0, 3.0).mean(), Rayleigh::new(1.5f64.sqrt()).mean());
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(2.5, 3.0).modes()[0], 2.4f64.sqrt(), 1e-15);
+        assert_eq!(new!(0.5, 3.0).modes(), vec![0.0]);
+        assert_eq!(new!(1.0, 3.0).modes(), Rayleigh::new(1.5f64.sqrt()).modes());
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.5, 3.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        for &m in &[0.5, 1.0, 2.5] {
+            let d = new!(m, 3.0);
+            let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+            assert!(samples.iter().all(|&x| x >= 0.0));
+            let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+            assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+        }
+
+        let d = new!(1.0, 3.0);
+        let e = Rayleigh::new(1.5f64.sqrt());
+        let mut source1 = source::Xorshift128Plus::new([42, 69]);
+        let mut source2 = source::Xorshift128Plus::new([42, 69]);
+        for _ in 0..100 {
+            assert_eq!(d.sample(&mut source1), e.sample(&mut source2));
+        }
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.5, 3.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Nakagami::try_new(0.5, 3.0).is_ok());
+        assert_eq!(Nakagami::try_new(0.4, 3.0).err(), Some("m must be at least 0.5"));
+        assert_eq!(Nakagami::try_new(2.5, 0.0).err(), Some("omega must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.5, 3.0).variance(), 0.28375563789831959, 1e-15);
+        assert_eq!(new!(1.0, 3.0).variance(), Rayleigh::new(1.5f64.sqrt()).variance());
+    }
+}
@@ -0,0 +1,264 @@
+use distribution::{self, Chisquared, Discrete, Gaussian, Poisson};
+use source::Source;
+use std::fmt;
+
+/// A noncentral chi-squared distribution.
+///
+/// The distribution is that of `Σ_i (Z_i + μ_i)²` where `Z_1, …, Z_k` are
+/// independent standard Gaussian random variables and the noncentrality
+/// parameter is `λ = Σ_i μ_i²`. It is a Poisson mixture of central chi-squared
+/// distributions: conditional on `J`, which follows the Poisson distribution
+/// with mean `λ / 2`, the distribution is chi-squared with `k + 2J` degrees
+/// of freedom.
+#[derive(Clone, Copy)]
+pub struct NoncentralChisquared {
+    k: u64,
+    lambda: f64,
+}
+
+impl NoncentralChisquared {
+    /// Create a noncentral chi-squared distribution with `k` degrees of
+    /// freedom and noncentrality parameter `lambda`.
+    ///
+    /// It should hold that `k > 0` and `lambda >= 0`.
+    #[inline]
+    pub fn new(k: u64, lambda: f64) -> Self {
+        should!(k > 0 && lambda >= 0.0 && lambda.is_finite());
+        NoncentralChisquared { k: k, lambda: lambda }
+    }
+
+    /// Create a noncentral chi-squared distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(k: u64, lambda: f64) -> Result<Self, &'static str> {
+        must!(k > 0, "k must be positive");
+        must!(lambda >= 0.0 && lambda.is_finite(), "lambda must be nonnegative and finite");
+        Ok(NoncentralChisquared::new(k, lambda))
+    }
+
+    /// Return the degrees of freedom.
+    #[inline(always)]
+    pub fn k(&self) -> u64 { self.k }
+
+    /// Return the noncentrality parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 { self.lambda }
+
+    // Compute `Σ_j P(J = j) f(χ²(k + 2j))`. The summation starts at the mode
+    // of the Poisson weights and proceeds in both directions until the
+    // weights fall below a fixed tolerance.
+    fn series<F>(&self, f: F) -> f64 where F: Fn(&Chisquared) -> f64 {
+        const TOLERANCE: f64 = 1e-17;
+
+        if self.lambda == 0.0 {
+            return f(&Chisquared::new(self.k));
+        }
+        let poisson = Poisson::new(0.5 * self.lambda);
+        let peak = (0.5 * self.lambda).floor() as u64;
+        let mut sum = 0.0;
+        let mut j = peak;
+        loop {
+            let weight = poisson.mass(j as usize);
+            sum += weight * f(&Chisquared::new(self.k + 2 * j));
+            if weight < TOLERANCE {
+                break;
+            }
+            j += 1;
+        }
+        let mut j = peak;
+        while j > 0 {
+            j -= 1;
+            let weight = poisson.mass(j as usize);
+            sum += weight * f(&Chisquared::new(self.k + 2 * j));
+            if weight < TOLERANCE {
+                break;
+            }
+        }
+        sum
+    }
+}
+
+impl fmt::Display for NoncentralChisquared {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "NoncentralChisquared(k={}, λ={})", self.k, self.lambda)
+    }
+}
+
+impl distribution::Continuous for NoncentralChisquared {
+    /// Compute the probability density function as the Poisson-weighted sum
+    /// of central chi-squared densities.
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+        self.series(|d| d.density(x))
+    }
+}
+
+impl distribution::Distribution for NoncentralChisquared {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function as the Poisson-weighted
+    /// sum of central chi-squared cumulative distribution functions.
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        self.series(|d| d.distribution(x)).min(1.0)
+    }
+}
+
+impl distribution::Kurtosis for NoncentralChisquared {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let (k, lambda) = (self.k as f64, self.lambda);
+        12.0 * (k + 4.0 * lambda) / ((k + 2.0 * lambda) * (k + 2.0 * lambda))
+    }
+}
+
+impl distribution::Mean for NoncentralChisquared {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.k as f64 + self.lambda
+    }
+}
+
+impl distribution::Parametric for NoncentralChisquared {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for NoncentralChisquared {
+    /// Draw a sample as the sum of a central chi-squared sample with `k - 1`
+    /// degrees of freedom and the square of a Gaussian sample with mean `√λ`.
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        let z = Gaussian::new(self.lambda.sqrt(), 1.0).sample(source);
+        if self.k == 1 {
+            z * z
+        } else {
+            Chisquared::new(self.k - 1).sample(source) + z * z
+        }
+    }
+}
+
+impl distribution::Skewness for NoncentralChisquared {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        let (k, lambda) = (self.k as f64, self.lambda);
+        8f64.sqrt() * (k + 3.0 * lambda) / (k + 2.0 * lambda).powf(1.5)
+    }
+}
+
+impl distribution::Support for NoncentralChisquared {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        (0.0, ::std::f64::INFINITY)
+    }
+}
+
+impl distribution::Variance for NoncentralChisquared {
+    #[inline]
+    fn variance(&self) -> f64 {
+        2.0 * (self.k as f64 + 2.0 * self.lambda)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($k:expr, $lambda:expr) => (NoncentralChisquared::new($k, $lambda));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(3, 2.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0, 30.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 7.6901561505609256e-02,
+            1.0204478813180075e-01, 1.2299006053428375e-01, 1.0171842112185070e-01,
+            3.6142837576849524e-02, 6.3785984405957411e-05,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(4, 0.0);
+        let e = Chisquared::new(4);
+        for &x in &x {
+            assert_eq!(d.density(x), e.density(x));
+        }
+
+        assert::close(new!(2, 200.0).density(200.0), 1.4113579974555958e-02, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(3, 2.5).to_string(), "NoncentralChisquared(k=3, λ=2.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3, 2.5);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0, 30.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 2.6185666340326989e-02,
+            7.1560120260761001e-02, 1.8636366488062314e-01, 5.4023898579081497e-01,
+            8.7079012512499387e-01, 9.9982354833637743e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+
+        assert::close(new!(2, 200.0).distribution(200.0), 4.8588642002544402e-01, 1e-13);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(3, 2.5).kurtosis(), 12.0 * 13.0 / 64.0, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(3, 2.5).mean(), 5.5);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(3, 2.5).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        for &(k, lambda) in &[(1, 2.5), (3, 2.5), (3, 0.0)] {
+            let d = new!(k, lambda);
+            let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+            assert!(samples.iter().all(|&x| x >= 0.0));
+            let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+            assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(3, 2.5).skewness(), 8f64.sqrt() * 10.5 / 512f64.sqrt(), 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(3, 2.5).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(NoncentralChisquared::try_new(3, 0.0).is_ok());
+        assert_eq!(NoncentralChisquared::try_new(0, 1.0).err(), Some("k must be positive"));
+        assert_eq!(NoncentralChisquared::try_new(3, -1.0).err(),
+                   Some("lambda must be nonnegative and finite"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(3, 2.5).variance(), 16.0);
+    }
+}
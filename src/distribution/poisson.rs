@@ -0,0 +1,395 @@
+use distribution;
+use source::Source;
+use std::fmt;
+use std::ops::{Range, RangeFrom};
+
+/// A Poisson distribution.
+#[derive(Clone, Copy)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    /// Create a Poisson distribution with rate `lambda`.
+    ///
+    /// It should hold that `lambda > 0`.
+    #[inline]
+    pub fn new(lambda: f64) -> Self {
+        should!(lambda > 0.0);
+        Poisson { lambda: lambda }
+    }
+
+    /// Create a Poisson distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(lambda: f64) -> Result<Self, &'static str> {
+        must!(lambda > 0.0, "lambda must be positive");
+        Ok(Poisson::new(lambda))
+    }
+
+    /// Return the distribution of the sum of two independent variables
+    /// distributed according to `self` and `other`, respectively.
+    ///
+    /// The sum is Poisson with the rates added.
+    #[inline]
+    pub fn sum(&self, other: &Poisson) -> Poisson {
+        Poisson::new(self.lambda + other.lambda)
+    }
+
+    /// Return the rate parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 { self.lambda }
+}
+
+impl fmt::Display for Poisson {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Poisson(λ={})", self.lambda)
+    }
+}
+
+impl distribution::CharacteristicFunction for Poisson {
+    fn characteristic(&self, t: f64) -> (f64, f64) {
+        let lambda = self.lambda;
+        let modulus = (lambda * (t.cos() - 1.0)).exp();
+        let argument = lambda * t.sin();
+        (modulus * argument.cos(), modulus * argument.sin())
+    }
+}
+
+impl distribution::Discrete for Poisson {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        ln_mass(self.lambda, x as f64).exp()
+    }
+
+    #[inline]
+    fn ln_mass(&self, x: usize) -> f64 {
+        ln_mass(self.lambda, x as f64)
+    }
+
+    /// Compute the probability mass function at each point of `range`.
+    ///
+    /// The masses are computed by the recurrence `p(x) = p(x - 1) λ / x`,
+    /// which is restarted from `mass` whenever it underflows.
+    fn masses(&self, range: Range<usize>) -> Vec<f64> {
+        let mut masses = Vec::with_capacity(range.len());
+        let mut p = 0.0;
+        for x in range {
+            p = if p == 0.0 { self.mass(x) } else { p * self.lambda / x as f64 };
+            masses.push(p);
+        }
+        masses
+    }
+}
+
+impl distribution::DiscreteSupport for Poisson {
+    type Iter = RangeFrom<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (0, ::std::i64::MAX)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        0..
+    }
+}
+
+impl distribution::Distribution for Poisson {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The implementation is based on the incomplete gamma function.
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Gamma;
+        if x < 0.0 {
+            return 0.0;
+        }
+        1.0 - self.lambda.inc_gamma(x.floor() + 1.0)
+    }
+}
+
+impl distribution::Entropy for Poisson {
+    fn entropy(&self) -> f64 {
+        use std::f64::consts::{E, PI};
+
+        let lambda = self.lambda;
+        if lambda > 1000.0 {
+            // Use an asymptotic expansion.
+            0.5 * (2.0 * PI * E * lambda).ln() - 1.0 / (12.0 * lambda) -
+                1.0 / (24.0 * lambda * lambda) - 19.0 / (360.0 * lambda * lambda * lambda)
+        } else {
+            let n = (lambda + 20.0 * lambda.sqrt() + 20.0) as usize;
+            -(0..(n + 1)).fold(0.0, |sum, i| {
+                let ln_p = ln_mass(lambda, i as f64);
+                sum + ln_p.exp() * ln_p
+            })
+        }
+    }
+}
+
+impl distribution::Kurtosis for Poisson {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        self.lambda.recip()
+    }
+}
+
+impl distribution::Mean for Poisson {
+    #[inline]
+    fn mean(&self) -> f64 { self.lambda }
+}
+
+impl distribution::Modes for Poisson {
+    fn modes(&self) -> Vec<usize> {
+        let m = self.lambda.floor();
+        if m == self.lambda {
+            vec![m as usize - 1, m as usize]
+        } else {
+            vec![m as usize]
+        }
+    }
+}
+
+impl distribution::Parametric for Poisson {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for Poisson {
+    /// Draw a sample.
+    ///
+    /// For small `lambda`, the multiplication method is used. Otherwise, the
+    /// transformed rejection method with squeeze is employed.
+    ///
+    /// ## References
+    ///
+    /// 1. D. E. Knuth, The Art of Computer Programming, Volume 2:
+    ///    Seminumerical Algorithms, 3rd ed. Addison-Wesley, 1997.
+    ///
+    /// 2. W. Hörmann, “The transformed rejection method for generating Poisson
+    ///    random variables,” Insurance: Mathematics and Economics, vol. 12,
+    ///    no. 1, pp. 39–45, 1993.
+    fn sample<S>(&self, source: &mut S) -> usize where S: Source {
+        if self.lambda < 10.0 {
+            sample_multiplication(self.lambda, source)
+        } else {
+            sample_rejection(self.lambda, source)
+        }
+    }
+}
+
+impl distribution::Skewness for Poisson {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        self.lambda.sqrt().recip()
+    }
+}
+
+impl distribution::Variance for Poisson {
+    #[inline]
+    fn variance(&self) -> f64 { self.lambda }
+}
+
+// ln(p(x)) = x * ln(λ) - λ - ln(x!)
+#[inline]
+fn ln_mass(lambda: f64, x: f64) -> f64 {
+    use special::Gamma;
+    x * lambda.ln() - lambda - Gamma::ln_gamma(x + 1.0).0
+}
+
+// See [Knuth, 1997, pp. 137].
+fn sample_multiplication<S: Source>(lambda: f64, source: &mut S) -> usize {
+    let limit = (-lambda).exp();
+    let mut product = source.read::<f64>();
+    let mut k = 0;
+    while product > limit {
+        product *= source.read::<f64>();
+        k += 1;
+    }
+    k
+}
+
+// See [Hörmann, 1993, pp. 43].
+fn sample_rejection<S: Source>(lambda: f64, source: &mut S) -> usize {
+    let b = 0.931 + 2.53 * lambda.sqrt();
+    let a = -0.059 + 0.02483 * b;
+    let alpha = 1.1239 + 1.1328 / (b - 3.4);
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
+
+    loop {
+        let u = source.read::<f64>() - 0.5;
+        let v = source.read::<f64>();
+        let us = 0.5 - u.abs();
+        let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+        if us >= 0.07 && v <= v_r {
+            return k as usize;
+        }
+        if k < 0.0 || (us < 0.013 && v > us) {
+            continue;
+        }
+        if (v * alpha / (a / (us * us) + b)).ln() <= ln_mass(lambda, k) {
+            return k as usize;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($lambda:expr) => (Poisson::new($lambda));
+    );
+
+    #[test]
+    fn characteristic() {
+        let d = new!(4.0);
+        let t = vec![-1.5, 0.0, 0.5, 2.0];
+        let phi = vec![
+            (-1.6070670784956310e-02, 1.8234378317416276e-02), (1.0, 0.0),
+            (-2.0835539381158974e-01, 5.7632204691030530e-01),
+            (-3.0494964150723463e-03, -1.6485552662371427e-03),
+        ];
+        for (&t, &(re, im)) in t.iter().zip(&phi) {
+            let (x, y) = d.characteristic(t);
+            assert::close(x, re, 1e-15);
+            assert::close(y, im, 1e-15);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(4.5).to_string(), "Poisson(λ=4.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(4.5);
+        let p = vec![
+            0.0000000000000000e+00, 1.1108996538242306e-02, 6.1099480960332686e-02,
+            1.7357807091003604e-01, 3.4229595583459107e-01, 5.3210357637471548e-01,
+            7.0293043486082745e-01, 8.3105057872541142e-01, 9.1341352835264398e-01,
+            9.5974268751796229e-01, 9.8290726710062145e-01, 9.9333132791281807e-01,
+        ];
+
+        let x = (-1..11).map(|i| d.distribution(i as f64)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-14);
+
+        let x = (-1..11).map(|i| d.distribution(i as f64 + 0.5)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-14);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(4.5).entropy(), 2.1490576572911777, 1e-14);
+        assert::close(new!(100.0).entropy(), 3.7206860722602589, 1e-12);
+        assert::close(new!(2000.0).entropy(), 5.2193480858857763, 1e-14);
+    }
+
+    #[test]
+    fn iter_support() {
+        let d = new!(2.0);
+        assert_eq!(d.iter_support().take(3).collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(4.0).kurtosis(), 0.25);
+    }
+
+    #[test]
+    fn ln_mass() {
+        let d = new!(4.5);
+        for x in 0..12 {
+            assert::close(d.ln_mass(x), d.mass(x).ln(), 1e-14);
+        }
+        assert_eq!(new!(1.0).mass(1000), 0.0);
+        assert::close(new!(1.0).ln_mass(1000), -5913.128178488163, 1e-11);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(4.5);
+        let p = vec![
+            1.1108996538242306e-02, 4.9990484422090379e-02, 1.1247858994970335e-01,
+            1.6871788492455503e-01, 1.8980762054012441e-01, 1.7082685848611197e-01,
+            1.2812014386458398e-01, 8.2362949627232556e-02, 4.6329159165318313e-02,
+            2.3164579582659156e-02, 1.0424060812196620e-02, 4.2643885140804356e-03,
+        ];
+
+        assert::close(&(0..12).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn masses() {
+        let d = new!(4.5);
+        assert::close(&d.masses(3..12), &(3..12).map(|x| d.mass(x)).collect::<Vec<_>>(), 1e-15);
+
+        let d = new!(500.0);
+        let p = d.masses(0..2000);
+        let x = vec![0, 300, 500, 700, 1000, 1500, 1999];
+        let q = vec![
+            7.1245764067412855e-218, 1.1427659863030548e-22, 1.7838267869511779e-02,
+            5.5921749527902036e-18, 1.6524151277513418e-86, 4.2212408530771691e-284, 0.0,
+        ];
+        assert!(x.iter().zip(&q).all(|(&x, &q)| (p[x] - q).abs() <= 1e-14 * q));
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(4.5).mean(), 4.5);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(4.5).modes(), vec![4]);
+        assert_eq!(new!(4.0).modes(), vec![3, 4]);
+        assert_eq!(new!(0.5).modes(), vec![0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.0).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        for &lambda in &[0.5, 4.5, 42.0, 5000.0] {
+            let n = 10000;
+            let sum = Independent(&new!(lambda), &mut source).take(n).fold(0, |a, b| a + b);
+            let mean = sum as f64 / n as f64;
+            assert!((mean - lambda).abs() < 5.0 * (lambda / n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(4.0).skewness(), 0.5);
+    }
+
+    #[test]
+    fn sum() {
+        assert_eq!(new!(1.5).sum(&new!(2.25)).lambda(), 3.75);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.0).support(), (0, ::std::i64::MAX));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Poisson::try_new(4.5).is_ok());
+        assert_eq!(Poisson::try_new(0.0).err(), Some("lambda must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(4.5).variance(), 4.5);
+    }
+}
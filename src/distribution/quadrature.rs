@@ -0,0 +1,220 @@
+//! Numerical integration.
+
+use distribution::Continuous;
+
+/// The nonnegative nodes of the 15-point Kronrod rule in decreasing order.
+const NODES: [f64; 8] = [
+    0.991455371120812639206854697526329, 0.949107912342758524526189684047851,
+    0.864864423359769072789712788640926, 0.741531185599394439863864773280788,
+    0.586087235467691130294144845693013, 0.405845151377397166906606412076961,
+    0.207784955007898467600689403773245, 0.000000000000000000000000000000000,
+];
+
+/// The weights of the 15-point Kronrod rule corresponding to `NODES`.
+const KRONROD: [f64; 8] = [
+    0.022935322010529224963732008058970, 0.063092092629978553290700663189204,
+    0.104790010322250183839876322541518, 0.140653259715525918745189590510238,
+    0.169004726639267902826583426598550, 0.190350578064785409913256402421014,
+    0.204432940075298892414161999234649, 0.209482141084727828012999174891714,
+];
+
+/// The weights of the 7-point Gauss rule corresponding to every other element
+/// of `NODES` starting from the second one.
+const GAUSS: [f64; 4] = [
+    0.129484966168869693270611432679082, 0.279705391489276667901467771423780,
+    0.381830050505118944950369775488975, 0.417959183673469387755102040816327,
+];
+
+/// The maximal number of subintervals.
+const MAX_INTERVALS: usize = 500;
+
+/// The relative tolerance used for expectations.
+const TOLERANCE: f64 = 1e-13;
+
+/// Integrate `f` over `[a, b]` using adaptive Gauss–Kronrod quadrature.
+///
+/// The subinterval with the largest error estimate, which is the difference
+/// between the Kronrod and Gauss estimates, is bisected until the total error
+/// estimate is at most `tolerance` times the integral of `|f|` or until the
+/// number of subintervals reaches a fixed limit.
+pub fn integrate<F>(f: F, a: f64, b: f64, tolerance: f64) -> f64 where F: Fn(f64) -> f64 {
+    should!(a <= b);
+    if a == b {
+        return 0.0;
+    }
+    let (value, error, magnitude) = kronrod(&f, a, b);
+    let mut intervals = vec![(a, b, value, error, magnitude)];
+    let (mut error, mut magnitude) = (error, magnitude);
+    while error > tolerance * magnitude && intervals.len() < MAX_INTERVALS {
+        let mut k = 0;
+        for i in 1..intervals.len() {
+            if intervals[i].3 > intervals[k].3 {
+                k = i;
+            }
+        }
+        let (a, b, _, e, m) = intervals[k];
+        let c = 0.5 * (a + b);
+        if c <= a || c >= b {
+            break;
+        }
+        let (v1, e1, m1) = kronrod(&f, a, c);
+        let (v2, e2, m2) = kronrod(&f, c, b);
+        error += e1 + e2 - e;
+        magnitude += m1 + m2 - m;
+        intervals[k] = (a, c, v1, e1, m1);
+        intervals.push((c, b, v2, e2, m2));
+    }
+    intervals.iter().fold(0.0, |sum, interval| sum + interval.2)
+}
+
+/// Integrate `f` over `[a, ∞)`.
+///
+/// The interval is mapped onto `[0, 1)` via `x = a + scale t / (1 - t)`, where
+/// `scale` is the width of the region in which `f` is expected to vary.
+pub fn integrate_above<F>(f: F, a: f64, scale: f64, tolerance: f64) -> f64
+    where F: Fn(f64) -> f64
+{
+    integrate(|t| {
+        let s = 1.0 - t;
+        f(a + scale * t / s) * scale / (s * s)
+    }, 0.0, 1.0, tolerance)
+}
+
+/// Integrate `f` over `(-∞, b]`.
+///
+/// The interval is mapped onto `[0, 1)` via `x = b - scale t / (1 - t)`, where
+/// `scale` is the width of the region in which `f` is expected to vary.
+pub fn integrate_below<F>(f: F, b: f64, scale: f64, tolerance: f64) -> f64
+    where F: Fn(f64) -> f64
+{
+    integrate(|t| {
+        let s = 1.0 - t;
+        f(b - scale * t / s) * scale / (s * s)
+    }, 0.0, 1.0, tolerance)
+}
+
+/// Compute the expectation of `f(X)` with `X` distributed according to
+/// `distribution`.
+///
+/// The real line is partitioned at quantiles of the distribution, so that
+/// each piece carries a bounded share of the probability mass, and the
+/// product of `f` and the density is integrated over each piece separately.
+pub fn expectation<D, F>(distribution: &D, f: F) -> f64
+    where D: Continuous, F: Fn(f64) -> f64
+{
+    const LOWER: [f64; 5] = [1e-15, 1e-8, 1e-3, 0.1, 0.5];
+    const UPPER: [f64; 4] = [0.1, 1e-3, 1e-8, 1e-15];
+
+    let mut points = Vec::with_capacity(LOWER.len() + UPPER.len());
+    for &p in &LOWER {
+        points.push(solve(|x| distribution.distribution(x) >= p));
+    }
+    for &p in &UPPER {
+        points.push(solve(|x| distribution.complementary(x) <= p));
+    }
+    for i in 1..points.len() {
+        if points[i] < points[i - 1] {
+            points[i] = points[i - 1];
+        }
+    }
+
+    // A node can coincide with an integrable singularity of the density, such
+    // as the one at each end of the support of a beta distribution, in which
+    // case it is skipped.
+    let g = |x: f64| {
+        let density = distribution.density(x);
+        if density == 0.0 || density.is_infinite() { 0.0 } else { f(x) * density }
+    };
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let scale = if last > first { last - first } else { 1.0 };
+    let mut sum = integrate_below(&g, first, scale, TOLERANCE);
+    for i in 1..points.len() {
+        sum += integrate(&g, points[i - 1], points[i], TOLERANCE);
+    }
+    sum + integrate_above(&g, last, scale, TOLERANCE)
+}
+
+// Apply the 15-point Kronrod rule to `f` over `[a, b]` and return the
+// estimate, the difference from the embedded 7-point Gauss rule, and the
+// estimate of the integral of `|f|`.
+fn kronrod<F>(f: &F, a: f64, b: f64) -> (f64, f64, f64) where F: Fn(f64) -> f64 {
+    let center = 0.5 * (a + b);
+    let half = 0.5 * (b - a);
+    let y = f(center);
+    let (mut kronrod, mut gauss) = (KRONROD[7] * y, GAUSS[3] * y);
+    let mut magnitude = KRONROD[7] * y.abs();
+    for i in 0..7 {
+        let (y1, y2) = (f(center - half * NODES[i]), f(center + half * NODES[i]));
+        kronrod += KRONROD[i] * (y1 + y2);
+        magnitude += KRONROD[i] * (y1.abs() + y2.abs());
+        if i % 2 == 1 {
+            gauss += GAUSS[i / 2] * (y1 + y2);
+        }
+    }
+    (kronrod * half, ((kronrod - gauss) * half).abs(), magnitude * half)
+}
+
+// Find the point at which a monotone predicate switches from false to true.
+fn solve<F>(predicate: F) -> f64 where F: Fn(f64) -> bool {
+    const MAX_ITERATIONS: usize = 1023;
+
+    let (mut a, mut b) = (-1.0, 1.0);
+    for _ in 0..MAX_ITERATIONS {
+        if !predicate(a) {
+            break;
+        }
+        b = a;
+        a *= 2.0;
+    }
+    for _ in 0..MAX_ITERATIONS {
+        if predicate(b) {
+            break;
+        }
+        a = b;
+        b *= 2.0;
+    }
+    for _ in 0..MAX_ITERATIONS {
+        let c = a + 0.5 * (b - a);
+        if c <= a || c >= b {
+            break;
+        }
+        if predicate(c) { b = c } else { a = c }
+    }
+    b
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn integrate() {
+        assert::close(super::integrate(|x| x.powi(20), -1.0, 1.0, 1e-14), 2.0 / 21.0, 1e-15);
+        assert::close(super::integrate(|x| x.sin(), 0.0, PI, 1e-14), 2.0, 1e-14);
+        assert::close(super::integrate(|x| x.sqrt(), 0.0, 1.0, 1e-14), 2.0 / 3.0, 1e-13);
+        assert::close(super::integrate(|x| if x < 0.3 { 1.0 } else { 0.0 }, 0.0, 1.0, 1e-14), 0.3,
+                      1e-13);
+        assert_eq!(super::integrate(|x| x, 1.0, 1.0, 1e-14), 0.0);
+    }
+
+    #[test]
+    fn integrate_above() {
+        assert::close(super::integrate_above(|x| (-x).exp(), 0.0, 1.0, 1e-14), 1.0, 1e-14);
+        assert::close(super::integrate_above(|x| 1.0 / (x * x), 1.0, 1.0, 1e-14), 1.0, 1e-14);
+    }
+
+    #[test]
+    fn integrate_below() {
+        assert::close(super::integrate_below(|x| x.exp(), 1.0, 1.0, 1e-14), 1f64.exp(), 1e-14);
+        assert::close(super::integrate_below(|x| 1.0 / (1.0 + x * x), 0.0, 1.0, 1e-14), PI / 2.0,
+                      1e-13);
+    }
+
+    #[test]
+    fn solve() {
+        assert::close(super::solve(|x| x >= 0.3), 0.3, 1e-15);
+        assert::close(super::solve(|x| x >= -1e6), -1e6, 1e-9);
+        assert::close(super::solve(|x| x * x * x >= 1e9), 1e3, 1e-12);
+    }
+}
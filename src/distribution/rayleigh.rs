@@ -0,0 +1,261 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Rayleigh distribution.
+#[derive(Clone, Copy)]
+pub struct Rayleigh {
+    sigma: f64,
+}
+
+impl Rayleigh {
+    /// Create a Rayleigh distribution with scale parameter `sigma`.
+    ///
+    /// It should hold that `sigma > 0`.
+    #[inline]
+    pub fn new(sigma: f64) -> Self {
+        should!(sigma > 0.0);
+        Rayleigh { sigma: sigma }
+    }
+
+    /// Create a Rayleigh distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(sigma: f64) -> Result<Self, &'static str> {
+        must!(sigma > 0.0, "sigma must be positive");
+        Ok(Rayleigh::new(sigma))
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn sigma(&self) -> f64 { self.sigma }
+}
+
+impl fmt::Display for Rayleigh {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Rayleigh(σ={})", self.sigma)
+    }
+}
+
+impl distribution::Continuous for Rayleigh {
+    #[inline]
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            let s2 = self.sigma * self.sigma;
+            x / s2 * (-x * x / (2.0 * s2)).exp()
+        }
+    }
+}
+
+impl distribution::Distribution for Rayleigh {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            -(-x * x / (2.0 * self.sigma * self.sigma)).exp_m1()
+        }
+    }
+}
+
+impl distribution::Entropy for Rayleigh {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use std::f64::consts::SQRT_2;
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        1.0 + (self.sigma / SQRT_2).ln() + 0.5 * EULER_MASCHERONI
+    }
+}
+
+impl distribution::Inverse for Rayleigh {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        self.sigma * (-2.0 * (-p).ln_1p()).sqrt()
+    }
+}
+
+impl distribution::Kurtosis for Rayleigh {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        use std::f64::consts::PI;
+        -(6.0 * PI * PI - 24.0 * PI + 16.0) / (4.0 - PI).powi(2)
+    }
+}
+
+impl distribution::Mean for Rayleigh {
+    #[inline]
+    fn mean(&self) -> f64 {
+        use std::f64::consts::PI;
+        self.sigma * (PI / 2.0).sqrt()
+    }
+}
+
+impl distribution::Median for Rayleigh {
+    #[inline]
+    fn median(&self) -> f64 {
+        use std::f64::consts::LN_2;
+        self.sigma * (2.0 * LN_2).sqrt()
+    }
+}
+
+impl distribution::Modes for Rayleigh {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.sigma]
+    }
+}
+
+impl distribution::Parametric for Rayleigh {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for Rayleigh {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        self.sigma * (-2.0 * source.read::<f64>().ln()).sqrt()
+    }
+}
+
+impl distribution::Skewness for Rayleigh {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        use std::f64::consts::PI;
+        2.0 * PI.sqrt() * (PI - 3.0) / (4.0 - PI).powf(1.5)
+    }
+}
+
+impl distribution::Support for Rayleigh {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for Rayleigh {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use std::f64::consts::PI;
+        (4.0 - PI) / 2.0 * self.sigma * self.sigma
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($sigma:expr) => (Rayleigh::new($sigma));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 1.2115415430954301e-01,
+            2.2062422564614885e-01, 3.0326532985631671e-01, 2.4348935051876230e-01,
+            1.3533528323661269e-01, 1.6663494807363460e-02,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(2.0).to_string(), "Rayleigh(σ=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 3.0766765523655918e-02,
+            1.1750309741540460e-01, 3.9346934028736658e-01, 6.7534753264165027e-01,
+            8.6466471676338731e-01, 9.8889100346175769e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0).entropy(), 1.6351814227307391, 1e-15);
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::INFINITY;
+
+        let d = new!(2.0);
+        let p = vec![0.0, 0.05, 0.1, 0.25, 0.5, 0.75, 0.9, 0.95, 1.0];
+        let x = vec![
+            0.0000000000000000e+00, 6.4058282454371526e-01, 9.1808721005284158e-01,
+            1.5170552328818643e+00, 2.3548200450309494e+00, 3.3302184446307910e+00,
+            4.2919320525786947e+00, 4.8954936613616324e+00, INFINITY,
+        ];
+
+        assert::close(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0).kurtosis(), 0.24508930068763806, 1e-14);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0).mean(), 2.5066282746310005, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0).median(), 2.3548200450309494, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.0).modes(), vec![2.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.0).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(2.0), &mut source::default()).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0).skewness(), 0.63111065781893714, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Rayleigh::try_new(2.0).is_ok());
+        assert_eq!(Rayleigh::try_new(-2.0).err(), Some("sigma must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0).variance(), 1.7168146928204135, 1e-15);
+    }
+}
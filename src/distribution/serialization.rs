@@ -0,0 +1,234 @@
+//! Serialization of distributions.
+//!
+//! A distribution is serialized as the parameters of its constructor, and it
+//! is deserialized by calling the fallible constructor, so that invalid
+//! parameters result in an error instead of a panic.
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use distribution::*;
+
+macro_rules! implement(
+    ($($kind:ident { $($field:ident: $type:ty),* } => $constructor:expr;)*) => ($(
+        impl Serialize for $kind {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+                #[derive(Serialize)]
+                struct Parameters { $($field: $type),* }
+                Parameters { $($field: self.$field().into()),* }.serialize(serializer)
+            }
+        }
+
+        impl<'l> Deserialize<'l> for $kind {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'l> {
+                #[derive(Deserialize)]
+                struct Parameters { $($field: $type),* }
+                let Parameters { $($field),* } = Parameters::deserialize(deserializer)?;
+                $constructor.map_err(D::Error::custom)
+            }
+        }
+    )*);
+);
+
+implement! {
+    Bates { n: u64 } => Bates::try_new(n);
+    Benford { base: u64 } => Benford::try_new(base);
+    Bernoulli { p: f64 } => Bernoulli::try_new(p);
+    Beta { alpha: f64, beta: f64, a: f64, b: f64 } => Beta::try_new(alpha, beta, a, b);
+    BetaBinomial { n: u64, alpha: f64, beta: f64 } => BetaBinomial::try_new(n, alpha, beta);
+    Binomial { n: usize, p: f64 } => Binomial::try_new(n, p);
+    Categorical { p: Vec<f64> } => Categorical::try_new(&p);
+    Cauchy { x0: f64, gamma: f64 } => Cauchy::try_new(x0, gamma);
+    Chi { k: u64 } => Chi::try_new(k);
+    Chisquared { k: u64 } => Chisquared::try_new(k);
+    ComPoisson { lambda: f64, nu: f64 } => ComPoisson::try_new(lambda, nu);
+    Dirichlet { alpha: Vec<f64> } => Dirichlet::try_new(&alpha);
+    DiscreteUniform { a: i64, b: i64 } => DiscreteUniform::try_new(a, b);
+    Empirical { data: Vec<f64> } => Empirical::try_from_samples(data);
+    Erlang { k: u64, lambda: f64 } => Erlang::try_new(k, lambda);
+    Exponential { lambda: f64 } => Exponential::try_new(lambda);
+    Fisher { d1: u64, d2: u64 } => Fisher::try_new(d1, d2);
+    FoldedNormal { mu: f64, sigma: f64 } => FoldedNormal::try_new(mu, sigma);
+    Frechet { alpha: f64, s: f64, m: f64 } => Frechet::try_new(alpha, s, m);
+    Gamma { k: f64, theta: f64 } => Gamma::try_new(k, theta);
+    Gaussian { mu: f64, sigma: f64 } => Gaussian::try_new(mu, sigma);
+    GeneralizedPareto { mu: f64, sigma: f64, xi: f64 } =>
+        GeneralizedPareto::try_new(mu, sigma, xi);
+    Geometric { p: f64 } => Geometric::try_new(p);
+    Gev { mu: f64, sigma: f64, xi: f64 } => Gev::try_new(mu, sigma, xi);
+    Gompertz { eta: f64, b: f64 } => Gompertz::try_new(eta, b);
+    Gumbel { mu: f64, beta: f64 } => Gumbel::try_new(mu, beta);
+    HalfNormal { sigma: f64 } => HalfNormal::try_new(sigma);
+    Hypergeometric { population: u64, successes: u64, draws: u64 } =>
+        Hypergeometric::try_new(population, successes, draws);
+    InverseGamma { alpha: f64, beta: f64 } => InverseGamma::try_new(alpha, beta);
+    IrwinHall { n: u64 } => IrwinHall::try_new(n);
+    Kde { data: Vec<f64>, bandwidth: f64 } => Kde::try_new(data, bandwidth);
+    Kumaraswamy { a: f64, b: f64 } => Kumaraswamy::try_new(a, b);
+    Laplace { mu: f64, b: f64 } => Laplace::try_new(mu, b);
+    Levy { mu: f64, c: f64 } => Levy::try_new(mu, c);
+    Logistic { mu: f64, s: f64 } => Logistic::try_new(mu, s);
+    Lognormal { mu: f64, sigma: f64 } => Lognormal::try_new(mu, sigma);
+    LogSeries { p: f64 } => LogSeries::try_new(p);
+    Maxwell { a: f64 } => Maxwell::try_new(a);
+    Multinomial { n: u64, p: Vec<f64> } => Multinomial::try_new(n, p);
+    MvGaussian { mean: Vec<f64>, covariance: Vec<Vec<f64>> } =>
+        MvGaussian::try_new(mean, covariance);
+    Nakagami { m: f64, omega: f64 } => Nakagami::try_new(m, omega);
+    NoncentralChisquared { k: u64, lambda: f64 } => NoncentralChisquared::try_new(k, lambda);
+    Poisson { lambda: f64 } => Poisson::try_new(lambda);
+    Rayleigh { sigma: f64 } => Rayleigh::try_new(sigma);
+    Skellam { mu1: f64, mu2: f64 } => Skellam::try_new(mu1, mu2);
+    SkewNormal { location: f64, scale: f64, shape: f64 } =>
+        SkewNormal::try_new(location, scale, shape);
+    StudentsT { nu: f64 } => StudentsT::try_new(nu);
+    Triangular { a: f64, b: f64, c: f64 } => Triangular::try_new(a, b, c);
+    Uniform { a: f64, b: f64 } => Uniform::try_new(a, b);
+    VonMises { mu: f64, kappa: f64 } => VonMises::try_new(mu, kappa);
+    Wald { mu: f64, lambda: f64 } => Wald::try_new(mu, lambda);
+    Weibull { lambda: f64, k: f64 } => Weibull::try_new(lambda, k);
+    YuleSimon { rho: f64 } => YuleSimon::try_new(rho);
+    Zeta { s: f64 } => Zeta::try_new(s);
+    Zipf { n: u64, s: f64 } => Zipf::try_new(n, s);
+}
+
+impl<D> Serialize for Affine<D>
+    where D: Continuous + Distribution<Value=f64> + Sample + Serialize
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Serialize)]
+        struct Parameters<'a, D: 'a> { inner: &'a D, a: f64, b: f64 }
+        Parameters { inner: self.inner(), a: self.a(), b: self.b() }.serialize(serializer)
+    }
+}
+
+impl<'l, D> Deserialize<'l> for Affine<D>
+    where D: Continuous + Distribution<Value=f64> + Sample + Deserialize<'l>
+{
+    fn deserialize<T>(deserializer: T) -> Result<Self, T::Error> where T: Deserializer<'l> {
+        #[derive(Deserialize)]
+        struct Parameters<D> { inner: D, a: f64, b: f64 }
+        let Parameters { inner, a, b } = Parameters::deserialize(deserializer)?;
+        Affine::try_new(inner, a, b).map_err(T::Error::custom)
+    }
+}
+
+impl<D> Serialize for Mixture<D>
+    where D: Continuous + Distribution<Value=f64> + Sample + Serialize
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Serialize)]
+        struct Parameters<'a, D: 'a> { weights: &'a [f64], components: &'a [D] }
+        Parameters { weights: self.weights(), components: self.components() }
+            .serialize(serializer)
+    }
+}
+
+impl<'l, D> Deserialize<'l> for Mixture<D>
+    where D: Continuous + Distribution<Value=f64> + Sample + Deserialize<'l>
+{
+    fn deserialize<T>(deserializer: T) -> Result<Self, T::Error> where T: Deserializer<'l> {
+        #[derive(Deserialize)]
+        struct Parameters<D> { weights: Vec<f64>, components: Vec<D> }
+        let Parameters { weights, components } = Parameters::deserialize(deserializer)?;
+        Mixture::try_new(weights, components).map_err(T::Error::custom)
+    }
+}
+
+impl<D> Serialize for Truncated<D>
+    where D: Continuous + Distribution<Value=f64> + Inverse + Serialize
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        #[derive(Serialize)]
+        struct Parameters<'a, D: 'a> { inner: &'a D, lower: f64, upper: f64 }
+        Parameters { inner: self.inner(), lower: self.lower(), upper: self.upper() }
+            .serialize(serializer)
+    }
+}
+
+impl<'l, D> Deserialize<'l> for Truncated<D>
+    where D: Continuous + Distribution<Value=f64> + Inverse + Deserialize<'l>
+{
+    fn deserialize<T>(deserializer: T) -> Result<Self, T::Error> where T: Deserializer<'l> {
+        #[derive(Deserialize)]
+        struct Parameters<D> { inner: D, lower: f64, upper: f64 }
+        let Parameters { inner, lower, upper } = Parameters::deserialize(deserializer)?;
+        Truncated::try_new(inner, lower, upper).map_err(T::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+    use serde_json;
+
+    macro_rules! test(
+        ($value:expr, $json:expr) => ({
+            let value = $value;
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, $json);
+            serde_json::from_str(&json).unwrap()
+        });
+    );
+
+    #[test]
+    fn continuous() {
+        let d: Gaussian = test!(Gaussian::new(0.0, 1.0), r#"{"mu":0.0,"sigma":1.0}"#);
+        assert_eq!((d.mu(), d.sigma()), (0.0, 1.0));
+        assert_eq!(d.density(0.0), Gaussian::new(0.0, 1.0).density(0.0));
+
+        let d: Uniform = test!(Uniform::new(-1.0, 2.0), r#"{"a":-1.0,"b":2.0}"#);
+        assert_eq!((d.a(), d.b()), (-1.0, 2.0));
+
+        let d: Beta = test!(Beta::new(2.0, 3.0, 0.0, 1.0),
+                            r#"{"alpha":2.0,"beta":3.0,"a":0.0,"b":1.0}"#);
+        assert_eq!(d.mean(), 0.4);
+
+        let d: Erlang = test!(Erlang::new(3, 0.3), r#"{"k":3,"lambda":0.3}"#);
+        assert_eq!((d.k(), d.lambda()), (3, 0.3));
+    }
+
+    #[test]
+    fn discrete() {
+        let d: Binomial = test!(Binomial::new(16, 0.25), r#"{"n":16,"p":0.25}"#);
+        assert_eq!(d.mean(), 4.0);
+
+        let d: Categorical = test!(Categorical::new(&[0.25, 0.75]), r#"{"p":[0.25,0.75]}"#);
+        assert_eq!(d.mass(1), 0.75);
+    }
+
+    #[test]
+    fn generic() {
+        let d: Affine<Gaussian> = test!(Affine::new(Gaussian::new(0.0, 1.0), -2.0, 1.0),
+                                        r#"{"inner":{"mu":0.0,"sigma":1.0},"a":-2.0,"b":1.0}"#);
+        assert_eq!(d.mean(), 1.0);
+
+        let d: Mixture<Gaussian> = test!(
+            Mixture::new(vec![0.5, 0.5], vec![Gaussian::new(-1.0, 1.0), Gaussian::new(1.0, 1.0)]),
+            r#"{"weights":[0.5,0.5],"components":[{"mu":-1.0,"sigma":1.0},{"mu":1.0,"sigma":1.0}]}"#
+        );
+        assert_eq!(d.mean(), 0.0);
+
+        let d: Truncated<Exponential> = test!(Truncated::new(Exponential::new(2.0), 1.0, 2.0),
+                                              r#"{"inner":{"lambda":2.0},"lower":1.0,"upper":2.0}"#);
+        assert_eq!(d.distribution(2.0), 1.0);
+    }
+
+    #[test]
+    fn validation() {
+        macro_rules! error(
+            ($kind:ty, $json:expr) => ({
+                let result: Result<$kind, _> = serde_json::from_str($json);
+                result.err().unwrap().to_string()
+            });
+        );
+
+        assert!(error!(Gaussian, r#"{"mu":0.0,"sigma":-1.0}"#)
+                    .starts_with("sigma must be positive"));
+        assert!(error!(Binomial, r#"{"n":10,"p":1.5}"#).starts_with("p must be in (0, 1)"));
+        assert!(error!(Affine<Gaussian>, r#"{"inner":{"mu":0.0,"sigma":1.0},"a":0.0,"b":1.0}"#)
+                    .starts_with("a must be finite and nonzero"));
+        assert!(error!(Affine<Gaussian>, r#"{"inner":{"mu":0.0,"sigma":0.0},"a":1.0,"b":1.0}"#)
+                    .starts_with("sigma must be positive"));
+    }
+}
@@ -0,0 +1,275 @@
+use distribution::{self, Discrete, Poisson};
+use source::Source;
+use std::fmt;
+
+/// A Skellam distribution.
+///
+/// The distribution models the difference `N1 - N2` of two independent
+/// Poisson random variables with means `μ1` and `μ2`. Therefore, the support
+/// is the set of all integers.
+#[derive(Clone, Copy)]
+pub struct Skellam {
+    mu1: f64,
+    mu2: f64,
+    first: Poisson,
+    second: Poisson,
+}
+
+impl Skellam {
+    /// Create a Skellam distribution with means `mu1` and `mu2` of the two
+    /// Poisson distributions.
+    ///
+    /// It should hold that `mu1 > 0` and `mu2 > 0`.
+    #[inline]
+    pub fn new(mu1: f64, mu2: f64) -> Self {
+        should!(mu1 > 0.0 && mu2 > 0.0);
+        Skellam { mu1: mu1, mu2: mu2, first: Poisson::new(mu1), second: Poisson::new(mu2) }
+    }
+
+    /// Create a Skellam distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(mu1: f64, mu2: f64) -> Result<Self, &'static str> {
+        must!(mu1 > 0.0, "mu1 must be positive");
+        must!(mu2 > 0.0, "mu2 must be positive");
+        Ok(Skellam::new(mu1, mu2))
+    }
+
+    /// Return the mean of the first Poisson distribution.
+    #[inline(always)]
+    pub fn mu1(&self) -> f64 { self.mu1 }
+
+    /// Return the mean of the second Poisson distribution.
+    #[inline(always)]
+    pub fn mu2(&self) -> f64 { self.mu2 }
+}
+
+impl fmt::Display for Skellam {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Skellam(μ1={}, μ2={})", self.mu1, self.mu2)
+    }
+}
+
+impl distribution::Discrete for Skellam {
+    #[inline]
+    fn mass(&self, x: i64) -> f64 {
+        self.ln_mass(x).exp()
+    }
+
+    /// Compute the natural logarithm of the probability mass function.
+    ///
+    /// The mass is `exp(-(μ1 + μ2)) (μ1 / μ2)^(k / 2) I_|k|(2 √(μ1 μ2))` where
+    /// `I_k` is the modified Bessel function of the first kind. The Bessel
+    /// function is evaluated via its power series, which is a sum of products
+    /// of Poisson masses; the sum is started at its largest term and extended
+    /// in both directions until the terms become negligible, so that it
+    /// neither overflows nor underflows.
+    fn ln_mass(&self, x: i64) -> f64 {
+        let (first, second) = if x >= 0 {
+            (&self.first, &self.second)
+        } else {
+            (&self.second, &self.first)
+        };
+        let n = x.abs() as f64;
+        let ab = first.lambda() * second.lambda();
+        let peak = (0.5 * ((n * n + 4.0 * ab).sqrt() - n - 2.0)).max(0.0).round();
+        let mut sum = 1.0;
+        let (mut term, mut j) = (1.0, peak);
+        loop {
+            term *= ab / ((j + 1.0) * (j + n + 1.0));
+            sum += term;
+            j += 1.0;
+            if term < ::std::f64::EPSILON * sum {
+                break;
+            }
+        }
+        let (mut term, mut j) = (1.0, peak);
+        while j > 0.0 {
+            term *= j * (j + n) / ab;
+            sum += term;
+            j -= 1.0;
+            if term < ::std::f64::EPSILON * sum {
+                break;
+            }
+        }
+        let j = peak as usize;
+        first.ln_mass(j + x.abs() as usize) + second.ln_mass(j) + sum.ln()
+    }
+}
+
+impl distribution::Distribution for Skellam {
+    type Value = i64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The function is computed as `Σ_j P(N2 = j) P(N1 ≤ x + j)` over the
+    /// values of `N2` that carry a nonnegligible mass.
+    fn distribution(&self, x: f64) -> f64 {
+        let x = x.floor();
+        let start = if x < 0.0 { -x as usize } else { 0 };
+        let end = (self.mu2 + 40.0 * self.mu2.sqrt() + 40.0) as usize;
+        if start > end {
+            return 0.0;
+        }
+        let masses = self.second.masses(start..(end + 1));
+        masses.iter().enumerate().fold(0.0, |sum, (i, &p)| {
+            sum + p * self.first.distribution(x + (start + i) as f64)
+        })
+    }
+
+    /// Compute the complementary cumulative distribution function.
+    ///
+    /// The function is computed as the cumulative distribution function of the
+    /// reflected distribution, which is `P(N2 - N1 ≤ -⌊x⌋ - 1)`.
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        Skellam::new(self.mu2, self.mu1).distribution(-x.floor() - 1.0)
+    }
+}
+
+impl distribution::Kurtosis for Skellam {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        (self.mu1 + self.mu2).recip()
+    }
+}
+
+impl distribution::Mean for Skellam {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.mu1 - self.mu2
+    }
+}
+
+impl distribution::Parametric for Skellam {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for Skellam {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> i64 where S: Source {
+        self.first.sample(source) as i64 - self.second.sample(source) as i64
+    }
+}
+
+impl distribution::Skewness for Skellam {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        (self.mu1 - self.mu2) / (self.mu1 + self.mu2).powf(1.5)
+    }
+}
+
+impl distribution::Variance for Skellam {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.mu1 + self.mu2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu1:expr, $mu2:expr) => (Skellam::new($mu1, $mu2));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(3.0, 1.5);
+        for &x in &[-6.0, -2.5, 0.0, 1.0, 3.5, 8.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-14);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(3.0, 1.5).to_string(), "Skellam(μ1=3, μ2=1.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.0, 1.5);
+        let p = vec![
+            1.8409457256749074e-03, 7.2799641729967765e-03, 2.4655686748309612e-02,
+            7.0285168793579031e-02, 1.6587592333789725e-01, 3.2086205712464821e-01,
+            5.1204356621328462e-01, 6.9456149439436232e-01, 8.3356727499686500e-01,
+            9.2059157015401494e-01, 9.6653834427328722e-01,
+        ];
+
+        let x = (-5..6).map(|i| d.distribution(i as f64)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-14);
+
+        let x = (-5..6).map(|i| d.distribution(i as f64 + 0.5)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(3.0, 1.0).kurtosis(), 0.25);
+    }
+
+    #[test]
+    fn ln_mass() {
+        let d = new!(3.0, 1.5);
+        for x in -10..11 {
+            assert::close(d.ln_mass(x), d.mass(x).ln(), 1e-13);
+        }
+        assert::close(new!(1.0, 2.0).ln_mass(1000), -5915.1261804881574, 1e-10);
+        assert::close(new!(1e4, 1e4).ln_mass(0), -5.8706760593164786, 1e-12);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(3.0, 1.5);
+        let p = vec![
+            1.4358366912272590e-03, 5.4390184473218691e-03, 1.7375722575312835e-02,
+            4.5629482045269412e-02, 9.5590754544318216e-02, 1.5498613378675097e-01,
+            1.9118150908863643e-01, 1.8251792818107765e-01, 1.3900578060250268e-01,
+            8.7024295157149906e-02, 4.5946774119272289e-02,
+        ];
+
+        assert::close(&(-5..6).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+        assert::close((-100..101).fold(0.0, |sum, x| sum + d.mass(x)), 1.0, 1e-14);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(3.0, 1.5).mean(), 1.5);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(3.0, 1.5).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(3.0, 1.5);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        let mean = samples.iter().fold(0.0, |a, &b| a + b as f64) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+        assert!(samples.iter().any(|&x| x < 0));
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(3.0, 1.0).skewness(), 0.25);
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Skellam::try_new(3.0, 1.5).is_ok());
+        assert_eq!(Skellam::try_new(0.0, 1.5).err(), Some("mu1 must be positive"));
+        assert_eq!(Skellam::try_new(3.0, -1.0).err(), Some("mu2 must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(3.0, 1.5).variance(), 4.5);
+    }
+}
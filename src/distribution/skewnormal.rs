@@ -0,0 +1,320 @@
+use distribution::{self, Gaussian};
+use source::Source;
+use std::fmt;
+
+/// A skew-normal distribution.
+///
+/// The density is `2 / ω φ((x - ξ) / ω) Φ(α (x - ξ) / ω)` where `φ` and `Φ`
+/// are the density and the cumulative distribution function of the standard
+/// Gaussian distribution, `ξ` is the location, `ω` is the scale, and `α` is
+/// the shape. The sign of the shape determines the direction of the skew, and
+/// a zero shape yields the Gaussian distribution with mean `ξ` and standard
+/// deviation `ω`.
+#[derive(Clone, Copy)]
+pub struct SkewNormal {
+    location: f64,
+    scale: f64,
+    shape: f64,
+    delta: f64,
+    gaussian: Gaussian,
+}
+
+impl SkewNormal {
+    /// Create a skew-normal distribution with location `location`, scale
+    /// `scale`, and shape `shape`.
+    ///
+    /// It should hold that `scale > 0` and that `shape` is finite.
+    #[inline]
+    pub fn new(location: f64, scale: f64, shape: f64) -> Self {
+        should!(scale > 0.0 && shape.is_finite());
+        SkewNormal {
+            location: location,
+            scale: scale,
+            shape: shape,
+            delta: shape / (1.0 + shape * shape).sqrt(),
+            gaussian: Gaussian::new(location, scale),
+        }
+    }
+
+    /// Create a skew-normal distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(location: f64, scale: f64, shape: f64) -> Result<Self, &'static str> {
+        must!(scale > 0.0, "scale must be positive");
+        must!(shape.is_finite(), "shape must be finite");
+        Ok(SkewNormal::new(location, scale, shape))
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn location(&self) -> f64 { self.location }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn scale(&self) -> f64 { self.scale }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn shape(&self) -> f64 { self.shape }
+}
+
+impl fmt::Display for SkewNormal {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "SkewNormal(ξ={}, ω={}, α={})", self.location, self.scale, self.shape)
+    }
+}
+
+impl distribution::Continuous for SkewNormal {
+    fn density(&self, x: f64) -> f64 {
+        use distribution::Distribution;
+        if self.shape == 0.0 {
+            return self.gaussian.density(x);
+        }
+        let z = (x - self.location) / self.scale;
+        2.0 * self.gaussian.density(x) * Gaussian::standard().distribution(self.shape * z)
+    }
+}
+
+impl distribution::Distribution for SkewNormal {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function, which is `Φ(z) - 2 T(z,
+    /// α)` where `z = (x - ξ) / ω` and `T` is Owen's T function.
+    fn distribution(&self, x: f64) -> f64 {
+        if self.shape == 0.0 {
+            return self.gaussian.distribution(x);
+        }
+        let z = (x - self.location) / self.scale;
+        let p = self.gaussian.distribution(x) - 2.0 * owen(z, self.shape);
+        if p < 0.0 { 0.0 } else if p > 1.0 { 1.0 } else { p }
+    }
+
+    /// Compute the complementary cumulative distribution function, which is
+    /// `1 - Φ(z) + 2 T(z, α)` where `z = (x - ξ) / ω`.
+    fn complementary(&self, x: f64) -> f64 {
+        if self.shape == 0.0 {
+            return self.gaussian.complementary(x);
+        }
+        let z = (x - self.location) / self.scale;
+        let p = self.gaussian.complementary(x) + 2.0 * owen(z, self.shape);
+        if p < 0.0 { 0.0 } else if p > 1.0 { 1.0 } else { p }
+    }
+}
+
+impl distribution::Mean for SkewNormal {
+    #[inline]
+    fn mean(&self) -> f64 {
+        use std::f64::consts::PI;
+        self.location + self.scale * self.delta * (2.0 / PI).sqrt()
+    }
+}
+
+impl distribution::Parametric for SkewNormal {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 3 }
+}
+
+impl distribution::Sample for SkewNormal {
+    /// Draw a sample.
+    ///
+    /// Given two independent standard Gaussian samples `u` and `v`, the sample
+    /// is `ξ + ω z` where `z = δ |u| + √(1 - δ²) v` and `δ = α / √(1 + α²)`.
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::gaussian;
+        if self.shape == 0.0 {
+            return self.gaussian.sample(source);
+        }
+        let (u, v) = (gaussian::sample(source), gaussian::sample(source));
+        let z = self.delta * u.abs() + (1.0 - self.delta * self.delta).sqrt() * v;
+        self.location + self.scale * z
+    }
+}
+
+impl distribution::Skewness for SkewNormal {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        use std::f64::consts::PI;
+        let mu = self.delta * (2.0 / PI).sqrt();
+        0.5 * (4.0 - PI) * mu.powi(3) / (1.0 - mu * mu).powf(1.5)
+    }
+}
+
+impl distribution::Support for SkewNormal {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (-INFINITY, INFINITY)
+    }
+}
+
+impl distribution::Variance for SkewNormal {
+    #[inline]
+    fn variance(&self) -> f64 {
+        use std::f64::consts::PI;
+        self.scale * self.scale * (1.0 - 2.0 * self.delta * self.delta / PI)
+    }
+}
+
+// Compute Owen's T function, which is
+//
+//     T(h, a) = 1 / (2π) ∫_0^a exp(-h² (1 + x²) / 2) / (1 + x²) dx.
+//
+// The function is even in `h` and odd in `a`. For `|a| ≤ 1`, the integral is
+// evaluated by quadrature; otherwise, the identity
+//
+//     T(h, a) + T(a h, 1 / a) = (Q(h) + Q(a h)) / 2 - Q(h) Q(a h),
+//
+// where `Q` is the complementary cumulative distribution function of the
+// standard Gaussian distribution and `h, a ≥ 0`, reduces the shape to the
+// unit interval.
+fn owen(h: f64, a: f64) -> f64 {
+    use distribution::quadrature::integrate;
+    use distribution::Distribution;
+    use std::f64::consts::PI;
+
+    if a < 0.0 {
+        return -owen(h, -a);
+    }
+    let h = h.abs();
+    if a == 0.0 {
+        return 0.0;
+    }
+    if a <= 1.0 {
+        let value = integrate(|x| {
+            let y = 1.0 + x * x;
+            (-0.5 * h * h * y).exp() / y
+        }, 0.0, a, 1e-15);
+        return value / (2.0 * PI);
+    }
+    let gaussian = Gaussian::standard();
+    let (p, q) = (gaussian.complementary(h), gaussian.complementary(a * h));
+    0.5 * (p + q) - p * q - owen(a * h, a.recip())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($location:expr, $scale:expr, $shape:expr) => (SkewNormal::new($location, $scale, $shape));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(1.0, 2.0, -3.0);
+        for &x in &[-10.0, -3.0, 0.0, 1.0, 2.5, 6.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+    }
+
+    #[test]
+    fn density() {
+        let x = vec![-3.0, -1.0, 0.0, 0.5, 1.0, 2.0, 4.0, 8.0];
+        let d = new!(1.0, 2.0, 3.0);
+        let p = vec![
+            5.3266820505555420e-11, 3.2663580474049948e-04, 2.3520499144928837e-02,
+            8.7629571559579345e-02, 1.9947114020071635e-01, 3.2854482761937065e-01,
+            1.2951715560743776e-01, 8.7268269504576005e-04,
+        ];
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.0, 2.0, 0.0);
+        let e = Gaussian::new(1.0, 2.0);
+        for &x in &x {
+            assert_eq!(d.density(x), e.density(x));
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 2.0, -3.0).to_string(), "SkewNormal(ξ=1, ω=2, α=-3)");
+    }
+
+    #[test]
+    fn distribution() {
+        let x = vec![-3.0, -1.0, 0.0, 0.5, 1.0, 2.0, 4.0, 8.0];
+        let d = new!(1.0, 2.0, 3.0);
+        let p = vec![
+            5.0891259751793009e-12, 5.6244433711877094e-05, 6.3694525739500744e-03,
+            3.1793304731104415e-02, 1.0241638234956672e-01, 3.8929437512197629e-01,
+            8.6638565182128213e-01, 9.9953474184192892e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.0, 2.0, -0.5);
+        let p = vec![
+            4.0000287919222222e-02, 2.4478463617302779e-01, 4.3751474442099442e-01,
+            5.4398694196251185e-01, 6.4758361765043326e-01, 8.2043966696902060e-01,
+            9.7720571139312729e-01, 9.9998549017709626e-01,
+        ];
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.0, 2.0, 0.0);
+        let e = Gaussian::new(1.0, 2.0);
+        for &x in &x {
+            assert_eq!(d.distribution(x), e.distribution(x));
+        }
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(1.0, 2.0, 3.0).mean(), 2.513879513212096, 1e-15);
+        assert_eq!(new!(1.0, 2.0, 0.0).mean(), 1.0);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0, 3.0).num_parameters(), 3);
+    }
+
+    #[test]
+    fn owen() {
+        use super::owen;
+        assert::close(owen(0.5, 0.25), 0.03432021712709421, 1e-16);
+        assert::close(owen(-0.5, 0.25), 0.03432021712709421, 1e-16);
+        assert::close(owen(0.5, -0.25), -0.03432021712709421, 1e-16);
+        assert::close(owen(1.5, 4.0), 0.033403600629650859, 1e-16);
+        assert::close(owen(0.0, 1.0), 0.125, 1e-16);
+        assert::close(owen(6.0, 10.0), 4.9329382251884905e-10, 1e-22);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        for &shape in &[-3.0, 0.0, 3.0] {
+            let d = new!(1.0, 2.0, shape);
+            let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+            let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+            assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(1.0, 2.0, 3.0).skewness(), 0.66702357015240795, 1e-15);
+        assert::close(new!(1.0, 2.0, -3.0).skewness(), -0.66702357015240795, 1e-15);
+        assert_eq!(new!(1.0, 2.0, 0.0).skewness(), 0.0);
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::INFINITY;
+        assert_eq!(new!(1.0, 2.0, 3.0).support(), (-INFINITY, INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(SkewNormal::try_new(1.0, 2.0, 3.0).is_ok());
+        assert_eq!(SkewNormal::try_new(1.0, 0.0, 3.0).err(), Some("scale must be positive"));
+        assert_eq!(SkewNormal::try_new(1.0, 2.0, ::std::f64::NAN).err(),
+                   Some("shape must be finite"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(1.0, 2.0, 3.0).variance(), 1.7081688194767072, 1e-15);
+        assert_eq!(new!(1.0, 2.0, 0.0).variance(), 4.0);
+    }
+}
@@ -0,0 +1,289 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Student’s t distribution.
+#[derive(Clone, Copy)]
+pub struct StudentsT {
+    nu: f64,
+    ln_beta: f64,
+    ln_norm: f64,
+}
+
+impl StudentsT {
+    /// Create a Student’s t distribution with `nu` degrees of freedom.
+    ///
+    /// It should hold that `nu > 0`.
+    #[inline]
+    pub fn new(nu: f64) -> Self {
+        use special::Beta;
+        should!(nu > 0.0);
+        let ln_beta = (0.5 * nu).ln_beta(0.5);
+        StudentsT { nu: nu, ln_beta: ln_beta, ln_norm: -0.5 * nu.ln() - ln_beta }
+    }
+
+    /// Create a Student’s t distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(nu: f64) -> Result<Self, &'static str> {
+        must!(nu > 0.0, "nu must be positive");
+        Ok(StudentsT::new(nu))
+    }
+
+    /// Return the degrees of freedom.
+    #[inline(always)]
+    pub fn nu(&self) -> f64 { self.nu }
+}
+
+impl fmt::Display for StudentsT {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "StudentsT(ν={})", self.nu)
+    }
+}
+
+impl distribution::Continuous for StudentsT {
+    fn density(&self, x: f64) -> f64 {
+        (self.ln_norm - 0.5 * (self.nu + 1.0) * (x * x / self.nu).ln_1p()).exp()
+    }
+}
+
+impl distribution::Distribution for StudentsT {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The implementation is based on the incomplete beta function.
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Beta;
+        nonnan!(x);
+        let p = 0.5 * (self.nu / (self.nu + x * x)).inc_beta(0.5 * self.nu, 0.5, self.ln_beta);
+        if x < 0.0 { p } else { 1.0 - p }
+    }
+}
+
+impl distribution::Entropy for StudentsT {
+    fn entropy(&self) -> f64 {
+        use special::Gamma;
+        let nu = self.nu;
+        0.5 * (nu + 1.0) * ((0.5 * (nu + 1.0)).digamma() - (0.5 * nu).digamma()) +
+            0.5 * nu.ln() + self.ln_beta
+    }
+}
+
+impl distribution::Kurtosis for StudentsT {
+    /// Compute the excess kurtosis.
+    ///
+    /// The excess kurtosis is infinite for `2 < nu <= 4` and undefined (NaN)
+    /// for `nu <= 2`.
+    fn kurtosis(&self) -> f64 {
+        use std::f64::{INFINITY, NAN};
+        if self.nu > 4.0 {
+            6.0 / (self.nu - 4.0)
+        } else if self.nu > 2.0 {
+            INFINITY
+        } else {
+            NAN
+        }
+    }
+}
+
+impl distribution::Mean for StudentsT {
+    /// Compute the expected value.
+    ///
+    /// The expected value is undefined (NaN) for `nu <= 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.nu > 1.0 { 0.0 } else { ::std::f64::NAN }
+    }
+}
+
+impl distribution::Median for StudentsT {
+    #[inline]
+    fn median(&self) -> f64 { 0.0 }
+}
+
+impl distribution::Modes for StudentsT {
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![0.0]
+    }
+}
+
+impl distribution::Parametric for StudentsT {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for StudentsT {
+    /// Draw a sample.
+    ///
+    /// The sample is the ratio of a standard Gaussian variate and the square
+    /// root of a chi-squared variate with `nu` degrees of freedom divided by
+    /// `nu`.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::{chisquared, gaussian};
+        let z = gaussian::sample(source);
+        let v = chisquared::sample(self.nu, source);
+        z / (v / self.nu).sqrt()
+    }
+}
+
+impl distribution::Skewness for StudentsT {
+    /// Compute the skewness.
+    ///
+    /// The skewness is undefined (NaN) for `nu <= 3`.
+    #[inline]
+    fn skewness(&self) -> f64 {
+        if self.nu > 3.0 { 0.0 } else { ::std::f64::NAN }
+    }
+}
+
+impl distribution::Support for StudentsT {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        (NEG_INFINITY, INFINITY)
+    }
+}
+
+impl distribution::Symmetric for StudentsT {}
+
+impl distribution::Variance for StudentsT {
+    /// Compute the variance.
+    ///
+    /// The variance is infinite for `1 < nu <= 2` and undefined (NaN) for
+    /// `nu <= 1`.
+    fn variance(&self) -> f64 {
+        use std::f64::{INFINITY, NAN};
+        if self.nu > 2.0 {
+            self.nu / (self.nu - 2.0)
+        } else if self.nu > 1.0 {
+            INFINITY
+        } else {
+            NAN
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+    use std::f64::INFINITY;
+
+    macro_rules! new(
+        ($nu:expr) => (StudentsT::new($nu));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(3.5);
+        let x = vec![-4.0, -3.0, -2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0];
+        let p = vec![
+            7.7956494814780407e-03, 2.1202284114910120e-02, 6.6917905068630840e-02,
+            2.1120394361781111e-01, 3.1831699531476979e-01, 3.7177234373209533e-01,
+            3.1831699531476979e-01, 2.1120394361781111e-01, 6.6917905068630840e-02,
+            2.1202284114910120e-02, 7.7956494814780407e-03,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(2.5).to_string(), "StudentsT(ν=2.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(3.5);
+        let x = vec![-4.0, -3.0, -2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0];
+        let p = vec![
+            1.0460308182701862e-02, 2.3687619742373218e-02, 6.3069261287956767e-02,
+            1.9066862678172277e-01, 3.2342521966127551e-01, 5.0000000000000000e-01,
+            6.7657478033872449e-01, 8.0933137321827723e-01, 9.3693073871204323e-01,
+            9.7631238025762678e-01, 9.8953969181729814e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn entropy() {
+        use std::f64::consts::PI;
+        assert::close(new!(3.5).entropy(), 1.7208901206824671, 1e-14);
+        assert::close(new!(1.0).entropy(), (4.0 * PI).ln(), 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(6.0).kurtosis(), 3.0);
+        assert_eq!(new!(3.0).kurtosis(), INFINITY);
+        assert!(new!(2.0).kurtosis().is_nan());
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(1.5).mean(), 0.0);
+        assert!(new!(1.0).mean().is_nan());
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(0.5).median(), 0.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(3.5).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(3.0).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(5.0);
+        let sum = Independent(&d, &mut source).take(n).fold(0.0, |a, b| a + b);
+        assert!((sum / n as f64).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+        assert!(Independent(&new!(0.5), &mut source).take(100).all(|x| !x.is_nan()));
+    }
+
+    #[test]
+    fn skewness() {
+        assert_eq!(new!(3.5).skewness(), 0.0);
+        assert!(new!(3.0).skewness().is_nan());
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::{INFINITY, NEG_INFINITY};
+        assert_eq!(new!(3.0).support(), (NEG_INFINITY, INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(StudentsT::try_new(2.0).is_ok());
+        assert_eq!(StudentsT::try_new(0.0).err(), Some("nu must be positive"));
+    }
+
+    #[test]
+    fn two_sided_p() {
+        let d = new!(3.0);
+        assert::close(d.two_sided_p(2.0), 0.13932596855884318, 1e-14);
+        assert::close(d.two_sided_p(-2.0), 0.13932596855884318, 1e-14);
+        assert_eq!(d.two_sided_p(0.0), 1.0);
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(4.0).variance(), 2.0);
+        assert_eq!(new!(2.0).variance(), INFINITY);
+        assert!(new!(1.0).variance().is_nan());
+    }
+}
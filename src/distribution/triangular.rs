@@ -1,5 +1,6 @@
 use distribution;
 use source::Source;
+use std::fmt;
 
 /// A triangular distribution.
 #[derive(Clone, Copy)]
@@ -19,6 +20,15 @@ impl Triangular {
         Triangular { a: a, b: b, c: c }
     }
 
+    /// Create a triangular distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(a: f64, b: f64, c: f64) -> Result<Self, &'static str> {
+        must!(a < b, "a must be less than b");
+        must!(a <= c && c <= b, "c must be in [a, b]");
+        Ok(Triangular::new(a, b, c))
+    }
+
     /// Return the left endpoint of the support.
     #[inline(always)]
     pub fn a(&self) -> f64 { self.a }
@@ -32,6 +42,12 @@ impl Triangular {
     pub fn c(&self) -> f64 { self.c }
 }
 
+impl fmt::Display for Triangular {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Triangular(a={}, b={}, c={})", self.a, self.b, self.c)
+    }
+}
+
 impl distribution::Continuous for Triangular {
     fn density(&self, x: f64) -> f64 {
         nonnan!(x);
@@ -130,6 +146,11 @@ impl distribution::Modes for Triangular {
     }
 }
 
+impl distribution::Parametric for Triangular {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 3 }
+}
+
 impl distribution::Sample for Triangular {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
@@ -147,6 +168,13 @@ impl distribution::Skewness for Triangular {
     }
 }
 
+impl distribution::Support for Triangular {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        (self.a, self.b)
+    }
+}
+
 impl distribution::Variance for Triangular {
     fn variance(&self) -> f64 {
         let &Triangular { a, b, c } = self;
@@ -170,6 +198,18 @@ mod tests {
         let p = vec![0.0, 0.0, 0.125, 0.25, 0.375, 0.5, 0.375, 0.25, 0.125, 0.0, 0.0];
 
         assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(0.0, 4.0, 1.0);
+        let x = vec![0.5, 1.0 - 1e-12, 1.0, 1.0 + 1e-12, 2.5];
+        let p = vec![0.25, 0.5, 0.5, 0.5, 0.25];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-12);
+        assert_eq!(d.density(1.0), 0.5);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 5.0, 3.0).to_string(), "Triangular(a=1, b=5, c=3)");
     }
 
     #[test]
@@ -179,6 +219,13 @@ mod tests {
         let p = vec![0.0, 0.0, 0.03125, 0.125, 0.28125, 0.5, 0.71875, 0.875, 0.96875, 1.0, 1.0];
 
         assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(0.0, 4.0, 1.0);
+        let x = vec![0.5, 1.0 - 1e-12, 1.0, 1.0 + 1e-12, 2.5];
+        let p = vec![0.0625, 0.25, 0.25, 0.25, 0.8125];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-12);
+        assert_eq!(d.distribution(1.0), 0.25);
     }
 
     #[test]
@@ -194,6 +241,13 @@ mod tests {
         let x = vec![1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5, 5.0];
 
         assert::close(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x, 1e-14);
+
+        let d = new!(0.0, 4.0, 1.0);
+        let p = vec![0.0625, 0.25, 0.8125];
+        let x = vec![0.5, 1.0, 2.5];
+
+        assert::close(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x, 1e-14);
+        assert_eq!(d.inverse(0.25), 1.0);
     }
 
     #[test]
@@ -209,6 +263,8 @@ mod tests {
     #[test]
     fn median() {
         assert_eq!(new!(1.0, 5.0, 3.0).median(), 3.0);
+        assert::close(new!(0.0, 4.0, 1.0).median(), 4.0 - 6f64.sqrt(), 1e-15);
+        assert::close(new!(0.0, 4.0, 3.0).median(), 6f64.sqrt(), 1e-15);
     }
 
     #[test]
@@ -216,11 +272,27 @@ mod tests {
         assert_eq!(new!(1.0, 5.0, 3.0).modes(), vec![3.0]);
     }
 
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 5.0, 2.0).num_parameters(), 3);
+    }
+
     #[test]
     fn skewness() {
         assert_eq!(new!(1.0, 5.0, 3.0).skewness(), 0.0);
     }
 
+    #[test]
+    fn support() {
+        assert_eq!(new!(1.0, 5.0, 2.0).support(), (1.0, 5.0));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Triangular::try_new(1.0, 3.0, 2.0).is_ok());
+        assert_eq!(Triangular::try_new(1.0, 3.0, 4.0).err(), Some("c must be in [a, b]"));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(1.0, 5.0, 3.0).variance(), (12.0 / 18.0));
@@ -0,0 +1,246 @@
+use distribution::{self, Continuous, Distribution, Inverse, Parametric, Support};
+use source::Source;
+use std::fmt;
+
+/// A truncated distribution.
+///
+/// The distribution is that of another continuous distribution conditioned on
+/// the outcome lying in the interval `[lower, upper]`. Either bound can be
+/// infinite, which gives a one-sided truncation.
+#[derive(Clone, Copy)]
+pub struct Truncated<D> {
+    inner: D,
+    lower: f64,
+    upper: f64,
+    a: f64,
+    norm: f64,
+}
+
+impl<D> Truncated<D> where D: Continuous + Distribution<Value=f64> + Inverse {
+    /// Create a distribution by truncating `inner` to the interval
+    /// `[lower, upper]`.
+    ///
+    /// It should hold that `lower < upper` and that the interval has a positive
+    /// probability under `inner`.
+    pub fn new(inner: D, lower: f64, upper: f64) -> Self {
+        should!(lower < upper);
+        let a = if lower == ::std::f64::NEG_INFINITY { 0.0 } else { inner.distribution(lower) };
+        let b = if upper == ::std::f64::INFINITY { 1.0 } else { inner.distribution(upper) };
+        should!(b > a);
+        Truncated { inner: inner, lower: lower, upper: upper, a: a, norm: b - a }
+    }
+
+    /// Create a truncated distribution or return an error if the parameters are
+    /// invalid.
+    pub fn try_new(inner: D, lower: f64, upper: f64) -> Result<Self, &'static str> {
+        must!(lower < upper, "lower must be less than upper");
+        let a = if lower == ::std::f64::NEG_INFINITY { 0.0 } else { inner.distribution(lower) };
+        let b = if upper == ::std::f64::INFINITY { 1.0 } else { inner.distribution(upper) };
+        must!(b > a, "the interval must have a positive probability");
+        Ok(Truncated { inner: inner, lower: lower, upper: upper, a: a, norm: b - a })
+    }
+
+    /// Return the underlying distribution.
+    #[inline(always)]
+    pub fn inner(&self) -> &D { &self.inner }
+
+    /// Return the lower bound.
+    #[inline(always)]
+    pub fn lower(&self) -> f64 { self.lower }
+
+    /// Return the upper bound.
+    #[inline(always)]
+    pub fn upper(&self) -> f64 { self.upper }
+}
+
+impl<D> fmt::Display for Truncated<D> where D: fmt::Display {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Truncated({}, [{}, {}])", self.inner, self.lower, self.upper)
+    }
+}
+
+impl<D> distribution::Continuous for Truncated<D>
+    where D: Continuous + Distribution<Value=f64> + Inverse
+{
+    fn density(&self, x: f64) -> f64 {
+        if x < self.lower || x > self.upper {
+            0.0
+        } else {
+            self.inner.density(x) / self.norm
+        }
+    }
+}
+
+impl<D> distribution::Distribution for Truncated<D>
+    where D: Continuous + Distribution<Value=f64> + Inverse
+{
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= self.lower {
+            0.0
+        } else if x >= self.upper {
+            1.0
+        } else {
+            let p = (self.inner.distribution(x) - self.a) / self.norm;
+            if p < 0.0 { 0.0 } else if p > 1.0 { 1.0 } else { p }
+        }
+    }
+}
+
+impl<D> distribution::Inverse for Truncated<D>
+    where D: Continuous + Distribution<Value=f64> + Inverse
+{
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        let x = self.inner.inverse(self.a + p * self.norm);
+        if x < self.lower { self.lower } else if x > self.upper { self.upper } else { x }
+    }
+}
+
+impl<D> distribution::Median for Truncated<D>
+    where D: Continuous + Distribution<Value=f64> + Inverse
+{
+    #[inline]
+    fn median(&self) -> f64 {
+        self.inverse(0.5)
+    }
+}
+
+impl<D> distribution::Parametric for Truncated<D>
+    where D: Continuous + Distribution<Value=f64> + Inverse + Parametric
+{
+    /// Return the number of free parameters, which are those of the underlying
+    /// distribution, the bounds being fixed.
+    #[inline]
+    fn num_parameters(&self) -> usize {
+        self.inner.num_parameters()
+    }
+}
+
+impl<D> distribution::Sample for Truncated<D>
+    where D: Continuous + Distribution<Value=f64> + Inverse
+{
+    /// Draw a sample.
+    ///
+    /// The sample is obtained by inverting the cumulative distribution function
+    /// of the underlying distribution at a uniform variate rescaled to the
+    /// truncation interval.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        self.inverse(source.read::<f64>())
+    }
+}
+
+impl<D> distribution::Support for Truncated<D>
+    where D: Continuous + Distribution<Value=f64> + Inverse + Support
+{
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        let (lower, upper) = self.inner.support();
+        (lower.max(self.lower), upper.min(self.upper))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+    use std::f64::{INFINITY, NEG_INFINITY};
+
+    macro_rules! new(
+        ($inner:expr, $lower:expr, $upper:expr) => (Truncated::new($inner, $lower, $upper));
+    );
+
+    fn integrate<F>(f: F, a: f64, b: f64) -> f64 where F: Fn(f64) -> f64 {
+        let n = 2000;
+        let h = (b - a) / n as f64;
+        (1..n).fold(f(a) + f(b), |sum, i| {
+            sum + if i % 2 == 0 { 2.0 } else { 4.0 } * f(a + i as f64 * h)
+        }) * h / 3.0
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(Gaussian::new(1.0, 2.0), -0.5, 3.0);
+        assert_eq!(d.density(-0.6), 0.0);
+        assert_eq!(d.density(3.1), 0.0);
+        assert::close(integrate(|x| d.density(x), -0.5, 3.0), 1.0, 1e-12);
+
+        let d = new!(Gaussian::new(0.0, 1.0), 0.0, INFINITY);
+        assert::close(d.density(0.0), 2.0 * Gaussian::new(0.0, 1.0).density(0.0), 1e-15);
+        assert::close(integrate(|x| d.density(x), 0.0, 40.0), 1.0, 1e-12);
+
+        let d = new!(Exponential::new(2.0), NEG_INFINITY, 1.5);
+        assert::close(integrate(|x| d.density(x), 0.0, 1.5), 1.0, 1e-12);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(Gaussian::new(0.0, 1.0), 0.0, INFINITY).to_string(),
+                   "Truncated(Gaussian(μ=0, σ=1), [0, inf])");
+    }
+
+    #[test]
+    fn distribution() {
+        let inner = Gaussian::new(1.0, 2.0);
+        let d = new!(inner, -0.5, 3.0);
+        let norm = inner.distribution(3.0) - inner.distribution(-0.5);
+        assert_eq!(d.distribution(-1.0), 0.0);
+        assert_eq!(d.distribution(3.5), 1.0);
+        for &x in &[-0.25, 0.5, 1.0, 2.0, 2.9] {
+            assert::close(d.distribution(x),
+                          (inner.distribution(x) - inner.distribution(-0.5)) / norm, 1e-15);
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        let d = new!(Gaussian::new(1.0, 2.0), -0.5, 3.0);
+        assert::close(d.inverse(0.0), -0.5, 1e-12);
+        assert::close(d.inverse(1.0), 3.0, 1e-12);
+        for &x in &[-0.25, 0.5, 1.0, 2.0, 2.9] {
+            assert::close(d.inverse(d.distribution(x)), x, 1e-12);
+        }
+    }
+
+    #[test]
+    fn median() {
+        let d = new!(Gaussian::new(1.0, 2.0), -1.0, 3.0);
+        assert::close(d.median(), 1.0, 1e-12);
+
+        let d = new!(Exponential::new(2.0), 1.0, INFINITY);
+        assert::close(d.median(), 1.0 + Exponential::new(2.0).median(), 1e-12);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(Gaussian::new(1.0, 2.0), -0.5, 3.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(Gaussian::new(0.0, 1.0), 1.0, INFINITY);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 1.0));
+        let below = samples.iter().filter(|&&x| x <= 1.5).count() as f64 / n as f64;
+        assert!((below - d.distribution(1.5)).abs() < 0.02);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(Gaussian::new(1.0, 2.0), -0.5, 3.0).support(), (-0.5, 3.0));
+        assert_eq!(new!(Exponential::new(2.0), NEG_INFINITY, 1.5).support(), (0.0, 1.5));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Truncated::try_new(Gaussian::new(1.0, 2.0), -0.5, 3.0).is_ok());
+        assert_eq!(Truncated::try_new(Gaussian::new(1.0, 2.0), 3.0, -0.5).err(),
+                   Some("lower must be less than upper"));
+        assert_eq!(Truncated::try_new(Exponential::new(2.0), -2.0, -1.0).err(),
+                   Some("the interval must have a positive probability"));
+    }
+}
@@ -1,5 +1,6 @@
 use distribution;
 use source::Source;
+use std::fmt;
 
 /// A continuous uniform distribution.
 #[derive(Clone, Copy)]
@@ -18,13 +19,21 @@ impl Uniform {
         Uniform { a: a, b: b }
     }
 
+    /// Create a uniform distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(a: f64, b: f64) -> Result<Self, &'static str> {
+        must!(a < b, "a must be less than b");
+        Ok(Uniform::new(a, b))
+    }
+
     /// Return the left endpoint of the support.
     #[inline(always)]
     pub fn a(&self) -> f64 { self.a }
 
     /// Return the right endpoint of the support.
     #[inline(always)]
-    pub fn b(&self) -> f64 { self.a }
+    pub fn b(&self) -> f64 { self.b }
 }
 
 impl Default for Uniform {
@@ -34,6 +43,24 @@ impl Default for Uniform {
     }
 }
 
+impl fmt::Display for Uniform {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Uniform(a={}, b={})", self.a, self.b)
+    }
+}
+
+impl distribution::CharacteristicFunction for Uniform {
+    fn characteristic(&self, t: f64) -> (f64, f64) {
+        if t == 0.0 {
+            return (1.0, 0.0);
+        }
+        let center = 0.5 * (self.a + self.b) * t;
+        let half = 0.5 * (self.b - self.a) * t;
+        let modulus = half.sin() / half;
+        (modulus * center.cos(), modulus * center.sin())
+    }
+}
+
 impl distribution::Continuous for Uniform {
     #[inline]
     fn density(&self, x: f64) -> f64 {
@@ -95,6 +122,25 @@ impl distribution::Median for Uniform {
     }
 }
 
+impl distribution::Moment for Uniform {
+    fn raw_moment(&self, n: u32) -> f64 {
+        let m = n as i32 + 1;
+        (self.b.powi(m) - self.a.powi(m)) / (m as f64 * (self.b - self.a))
+    }
+
+    fn central_moment(&self, n: u32) -> f64 {
+        if n % 2 == 1 {
+            return 0.0;
+        }
+        (0.5 * (self.b - self.a)).powi(n as i32) / (n + 1) as f64
+    }
+}
+
+impl distribution::Parametric for Uniform {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
 impl distribution::Sample for Uniform {
     #[inline]
     fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
@@ -107,6 +153,13 @@ impl distribution::Skewness for Uniform {
     fn skewness(&self) -> f64 { 0.0 }
 }
 
+impl distribution::Support for Uniform {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        (self.a, self.b)
+    }
+}
+
 impl distribution::Variance for Uniform {
     #[inline]
     fn variance(&self) -> f64 {
@@ -122,6 +175,27 @@ mod tests {
         ($a:expr, $b:expr) => (Uniform::new($a, $b));
     );
 
+    #[test]
+    fn characteristic() {
+        let d = new!(-1.0, 3.0);
+        let t = vec![-1.5, 0.0, 0.5, 2.0];
+        let phi = vec![
+            (3.3274781564929060e-03, -4.6922166849747100e-02), (1.0, 0.0),
+            (7.3846026260412880e-01, 4.0342268011133490e-01),
+            (7.8735241078344480e-02, -1.7203964039968855e-01),
+        ];
+        for (&t, &(re, im)) in t.iter().zip(&phi) {
+            let (x, y) = d.characteristic(t);
+            assert::close(x, re, 1e-15);
+            assert::close(y, im, 1e-15);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(-1.0, 2.0).to_string(), "Uniform(a=-1, b=2)");
+    }
+
     #[test]
     fn distribution() {
         let d = new!(-1.0, 1.0);
@@ -140,10 +214,18 @@ mod tests {
         assert_eq!(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p);
     }
 
+    #[test]
+    fn endpoints() {
+        let d = new!(-1.0, 2.0);
+        assert_eq!((d.a(), d.b()), (-1.0, 2.0));
+    }
+
     #[test]
     fn entropy() {
         use std::f64::consts::E;
         assert_eq!(new!(0.0, E).entropy(), 1.0);
+        assert_eq!(new!(3.0, 4.0).entropy(), 0.0);
+        assert::close(new!(-1.0, 1.0).entropy(), 0.69314718055994531, 1e-15);
     }
 
     #[test]
@@ -170,6 +252,24 @@ mod tests {
         assert_eq!(new!(0.0, 2.0).median(), 1.0);
     }
 
+    #[test]
+    fn moment() {
+        let d = new!(-1.0, 3.0);
+        assert_eq!(d.central_moment(0), 1.0);
+        assert_eq!(d.central_moment(1), 0.0);
+        assert::close(d.central_moment(2), d.variance(), 1e-15);
+        assert_eq!(d.central_moment(3), 0.0);
+        assert_eq!(d.central_moment(4), 3.2);
+        assert_eq!(d.raw_moment(0), 1.0);
+        assert_eq!(d.raw_moment(1), d.mean());
+        assert_eq!(d.raw_moment(2), 7.0 / 3.0);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(-1.0, 2.0).num_parameters(), 2);
+    }
+
     #[test]
     fn sample() {
         for x in Independent(&new!(7.0, 42.0), &mut source::default()).take(100) {
@@ -182,6 +282,17 @@ mod tests {
         assert_eq!(new!(0.0, 2.0).skewness(), 0.0);
     }
 
+    #[test]
+    fn support() {
+        assert_eq!(new!(-1.0, 2.0).support(), (-1.0, 2.0));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Uniform::try_new(-1.0, 1.0).is_ok());
+        assert_eq!(Uniform::try_new(1.0, 1.0).err(), Some("a must be less than b"));
+    }
+
     #[test]
     fn variance() {
         assert_eq!(new!(0.0, 12.0).variance(), 12.0);
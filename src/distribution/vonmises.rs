@@ -0,0 +1,378 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A von Mises distribution.
+///
+/// The distribution is defined on the circle, which is represented by the
+/// interval `[-π, π]`. Outside of the interval, the density is zero, and the
+/// cumulative distribution function is the probability of the arc
+/// `[-π, x]`.
+#[derive(Clone, Copy)]
+pub struct VonMises {
+    mu: f64,
+    kappa: f64,
+    i0e: f64,
+}
+
+impl VonMises {
+    /// Create a von Mises distribution with location `mu` and concentration
+    /// `kappa`.
+    ///
+    /// It should hold that `kappa >= 0`. If `kappa = 0`, the distribution is
+    /// the uniform distribution on the circle.
+    #[inline]
+    pub fn new(mu: f64, kappa: f64) -> Self {
+        should!(kappa >= 0.0);
+        VonMises { mu: mu, kappa: kappa, i0e: bessel_scaled(0, kappa) }
+    }
+
+    /// Create a von Mises distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(mu: f64, kappa: f64) -> Result<Self, &'static str> {
+        must!(kappa >= 0.0, "kappa must be nonnegative");
+        Ok(VonMises::new(mu, kappa))
+    }
+
+    /// Return the location parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 { self.mu }
+
+    /// Return the concentration parameter.
+    #[inline(always)]
+    pub fn kappa(&self) -> f64 { self.kappa }
+
+    /// Compute `I1(κ) / I0(κ)`, which is the mean resultant length.
+    #[inline]
+    fn resultant(&self) -> f64 {
+        bessel_scaled(1, self.kappa) / self.i0e
+    }
+}
+
+impl fmt::Display for VonMises {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "VonMises(μ={}, κ={})", self.mu, self.kappa)
+    }
+}
+
+impl distribution::Continuous for VonMises {
+    fn density(&self, x: f64) -> f64 {
+        use std::f64::consts::PI;
+        if x < -PI || x > PI {
+            0.0
+        } else {
+            (self.kappa * ((x - self.mu).cos() - 1.0)).exp() / (2.0 * PI * self.i0e)
+        }
+    }
+}
+
+impl distribution::Distribution for VonMises {
+    type Value = f64;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// The implementation is based on the Fourier series of the density, the
+    /// coefficients of which are computed via a backward recurrence for the
+    /// ratios of the modified Bessel functions.
+    fn distribution(&self, x: f64) -> f64 {
+        use std::f64::consts::PI;
+        if x <= -PI {
+            return 0.0;
+        }
+        if x >= PI {
+            return 1.0;
+        }
+        let &VonMises { mu, kappa, .. } = self;
+        let mut sum = (x + PI) / (2.0 * PI);
+        if kappa > 0.0 {
+            let m = 30 + (10.0 * kappa.sqrt()).ceil() as usize;
+            let mut ratios = vec![0.0; m + 1];
+            let mut ratio = 0.0;
+            for j in (1..(2 * m + 1)).rev() {
+                ratio = 1.0 / (2.0 * j as f64 / kappa + ratio);
+                if j <= m {
+                    ratios[j] = ratio;
+                }
+            }
+            let mut coefficient = 1.0;
+            for j in 1..(m + 1) {
+                coefficient *= ratios[j];
+                let j = j as f64;
+                sum += coefficient / j * ((j * (x - mu)).sin() - (j * (-PI - mu)).sin()) / PI;
+            }
+        }
+        if sum < 0.0 { 0.0 } else if sum > 1.0 { 1.0 } else { sum }
+    }
+}
+
+impl distribution::Entropy for VonMises {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        use std::f64::consts::PI;
+        (2.0 * PI * self.i0e).ln() + self.kappa * (1.0 - self.resultant())
+    }
+}
+
+impl distribution::Mean for VonMises {
+    /// Compute the circular mean.
+    #[inline]
+    fn mean(&self) -> f64 { self.mu }
+}
+
+impl distribution::Median for VonMises {
+    /// Compute the circular median.
+    #[inline]
+    fn median(&self) -> f64 { self.mu }
+}
+
+impl distribution::Modes for VonMises {
+    /// Compute the modes.
+    ///
+    /// If `kappa = 0`, every point of the circle is a mode, and `mu` is
+    /// returned as a representative.
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        vec![self.mu]
+    }
+}
+
+impl distribution::Parametric for VonMises {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for VonMises {
+    /// Draw a sample.
+    ///
+    /// The sample is wrapped into the interval `[-π, π)`.
+    ///
+    /// ## References
+    ///
+    /// 1. D. J. Best and N. I. Fisher, “Efficient simulation of the von Mises
+    ///    distribution,” Journal of the Royal Statistical Society. Series C
+    ///    (Applied Statistics), vol. 28, no. 2, pp. 152–157, 1979.
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use std::f64::consts::PI;
+        let &VonMises { mu, kappa, .. } = self;
+        let x = if kappa == 0.0 {
+            PI * (2.0 * source.read::<f64>() - 1.0)
+        } else {
+            let tau = 1.0 + (1.0 + 4.0 * kappa * kappa).sqrt();
+            let rho = (tau - (2.0 * tau).sqrt()) / (2.0 * kappa);
+            let r = (1.0 + rho * rho) / (2.0 * rho);
+            let f = loop {
+                let z = (PI * source.read::<f64>()).cos();
+                let f = (1.0 + r * z) / (r + z);
+                let c = kappa * (r - f);
+                let u = source.read::<f64>();
+                if c * (2.0 - c) > u || (c / u).ln() + 1.0 >= c {
+                    break f;
+                }
+            };
+            if source.read::<f64>() < 0.5 { mu - f.acos() } else { mu + f.acos() }
+        };
+        x - 2.0 * PI * ((x + PI) / (2.0 * PI)).floor()
+    }
+}
+
+impl distribution::Support for VonMises {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::consts::PI;
+        (-PI, PI)
+    }
+}
+
+impl distribution::Variance for VonMises {
+    /// Compute the circular variance.
+    ///
+    /// The circular variance is `1 - I1(κ) / I0(κ)`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        1.0 - self.resultant()
+    }
+}
+
+/// Compute the exponentially scaled modified Bessel function of the first
+/// kind, `e^(-x) I_n(x)`, for `x >= 0`.
+///
+/// The power series is used for small arguments, and the asymptotic expansion
+/// is used otherwise.
+fn bessel_scaled(n: u32, x: f64) -> f64 {
+    use std::f64::consts::PI;
+    const EPSILON: f64 = 1e-17;
+    if x <= 30.0 {
+        let y = 0.25 * x * x;
+        let mut term = (1..(n + 1)).fold(1.0, |term, i| term * 0.5 * x / i as f64);
+        let mut sum = term;
+        let mut k = 1.0;
+        while term > EPSILON * sum {
+            term *= y / (k * (k + n as f64));
+            sum += term;
+            k += 1.0;
+        }
+        sum * (-x).exp()
+    } else {
+        let mu = 4.0 * (n * n) as f64;
+        let mut term = 1.0;
+        let mut sum = term;
+        let mut k = 1.0;
+        loop {
+            let next = -term * (mu - (2.0 * k - 1.0) * (2.0 * k - 1.0)) / (8.0 * k * x);
+            if next.abs() < EPSILON * sum.abs() || next.abs() > term.abs() {
+                break;
+            }
+            term = next;
+            sum += term;
+            k += 1.0;
+        }
+        sum / (2.0 * PI * x).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $kappa:expr) => (VonMises::new($mu, $kappa));
+    );
+
+    #[test]
+    fn bessel() {
+        use super::bessel_scaled;
+
+        let x = vec![0.001, 1.0, 10.0, 29.9, 30.1, 100.0, 750.0];
+        let i0 = vec![
+            0.99900074958351559, 0.46575960759364043, 0.12783333716342860, 0.073269219046001907,
+            0.073023294131060942, 0.039944379299096680, 0.014569742116743979,
+        ];
+        let i1 = vec![
+            0.00049950031235422132, 0.20791041534970844, 0.12126268138445552,
+            0.072033374911868786, 0.071799854351014339, 0.039744153025130249,
+            0.014560025713286366,
+        ];
+
+        assert::close(&x.iter().map(|&x| bessel_scaled(0, x)).collect::<Vec<_>>(), &i0, 1e-15);
+        assert::close(&x.iter().map(|&x| bessel_scaled(1, x)).collect::<Vec<_>>(), &i1, 1e-15);
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-4.0, -3.0, -2.0, -1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0];
+        let p = vec![
+            0.0000000000000000e+00, 1.0729224676771086e-02, 1.4063706052155360e-02,
+            8.0427734601054388e-02, 4.0385253335183774e-01, 5.1588541201901361e-01,
+            4.0385253335183774e-01, 8.0427734601054388e-02, 1.4063706052155360e-02,
+            0.0000000000000000e+00,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn density_uniform() {
+        use std::f64::consts::PI;
+        let d = new!(1.0, 0.0);
+        assert::close(d.density(-2.0), 0.5 / PI, 1e-15);
+        assert::close(d.density(3.0), 0.5 / PI, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(0.5, 2.0).to_string(), "VonMises(μ=0.5, κ=2)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(0.5, 2.0);
+        let x = vec![-4.0, -3.0, -2.0, -1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0];
+        let p = vec![
+            0.0000000000000000e+00, 1.6073506484450583e-03, 1.2128616483890471e-02,
+            4.7976762448332690e-02, 2.6695139637082604e-01, 5.0514361078935222e-01,
+            7.4333582520787844e-01, 9.6231045913037172e-01, 9.9815860509481402e-01,
+            1.0000000000000000e+00,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+
+        let d = new!(0.0, 150.0);
+        let x = vec![-0.2, -0.1, 0.05, 0.1];
+        let p = vec![
+            7.2757041333148045e-03, 1.1062516682277643e-01, 7.2966387402813104e-01,
+            8.8937483317722354e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-13);
+    }
+
+    #[test]
+    fn distribution_uniform() {
+        let d = new!(1.0, 0.0);
+        assert_eq!(d.distribution(0.0), 0.5);
+    }
+
+    #[test]
+    fn entropy() {
+        use std::f64::consts::PI;
+        assert::close(new!(0.5, 2.0).entropy(), 1.2663212919642859, 1e-15);
+        assert::close(new!(0.5, 0.0).entropy(), (2.0 * PI).ln(), 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(0.5, 2.0).mean(), 0.5);
+    }
+
+    #[test]
+    fn median() {
+        assert_eq!(new!(0.5, 2.0).median(), 0.5);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(0.5, 2.0).modes(), vec![0.5]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        use std::f64::consts::PI;
+        let mut source = source::default();
+        let n = 10000;
+        for &(mu, kappa) in &[(0.5, 2.0), (3.0, 5.0), (0.0, 0.0)] {
+            let d = new!(mu, kappa);
+            let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+            assert!(samples.iter().all(|&x| -PI <= x && x < PI));
+            let below = samples.iter().filter(|&&x| x <= 0.0).count() as f64 / n as f64;
+            assert!((below - d.distribution(0.0)).abs() < 0.02);
+            let cosine = samples.iter().fold(0.0, |sum, &x| sum + (x - mu).cos()) / n as f64;
+            assert!((cosine - (1.0 - d.variance())).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn support() {
+        use std::f64::consts::PI;
+        assert_eq!(new!(1.0, 2.0).support(), (-PI, PI));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(VonMises::try_new(0.0, 2.0).is_ok());
+        assert_eq!(VonMises::try_new(0.0, -2.0).err(), Some("kappa must be nonnegative"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(0.5, 2.0).variance(), 0.302225342035992, 1e-15);
+        assert_eq!(new!(0.5, 0.0).variance(), 1.0);
+    }
+}
@@ -0,0 +1,239 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// An inverse Gaussian (Wald) distribution.
+#[derive(Clone, Copy)]
+pub struct Wald {
+    mu: f64,
+    lambda: f64,
+}
+
+impl Wald {
+    /// Create an inverse Gaussian distribution with mean `mu` and shape
+    /// `lambda`.
+    ///
+    /// It should hold that `mu > 0` and `lambda > 0`.
+    #[inline]
+    pub fn new(mu: f64, lambda: f64) -> Self {
+        should!(mu > 0.0 && lambda > 0.0);
+        Wald { mu: mu, lambda: lambda }
+    }
+
+    /// Create an inverse Gaussian distribution or return an error if the
+    /// parameters are invalid.
+    #[inline]
+    pub fn try_new(mu: f64, lambda: f64) -> Result<Self, &'static str> {
+        must!(mu > 0.0, "mu must be positive");
+        must!(lambda > 0.0, "lambda must be positive");
+        Ok(Wald::new(mu, lambda))
+    }
+
+    /// Return the mean parameter.
+    #[inline(always)]
+    pub fn mu(&self) -> f64 { self.mu }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 { self.lambda }
+}
+
+impl fmt::Display for Wald {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Wald(μ={}, λ={})", self.mu, self.lambda)
+    }
+}
+
+impl distribution::Continuous for Wald {
+    fn density(&self, x: f64) -> f64 {
+        use std::f64::consts::PI;
+        if x <= 0.0 {
+            0.0
+        } else {
+            let &Wald { mu, lambda } = self;
+            (lambda / (2.0 * PI * x * x * x)).sqrt() *
+                (-lambda * (x - mu).powi(2) / (2.0 * mu * mu * x)).exp()
+        }
+    }
+}
+
+impl distribution::Distribution for Wald {
+    type Value = f64;
+
+    fn distribution(&self, x: f64) -> f64 {
+        use special::Error;
+        use std::f64::consts::SQRT_2;
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let &Wald { mu, lambda } = self;
+        let r = (lambda / x).sqrt();
+        let first = 0.5 * Error::erfc(-r * (x / mu - 1.0) / SQRT_2);
+        let second = (2.0 * lambda / mu +
+            (0.5 * Error::erfc(r * (x / mu + 1.0) / SQRT_2)).ln()).exp();
+        first + second
+    }
+}
+
+impl distribution::Kurtosis for Wald {
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        15.0 * self.mu / self.lambda
+    }
+}
+
+impl distribution::Mean for Wald {
+    #[inline]
+    fn mean(&self) -> f64 { self.mu }
+}
+
+impl distribution::Modes for Wald {
+    fn modes(&self) -> Vec<f64> {
+        let &Wald { mu, lambda } = self;
+        let ratio = 1.5 * mu / lambda;
+        vec![mu * ((1.0 + ratio * ratio).sqrt() - ratio)]
+    }
+}
+
+impl distribution::Parametric for Wald {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Sample for Wald {
+    /// Draw a sample.
+    ///
+    /// ## References
+    ///
+    /// 1. J. R. Michael, W. R. Schucany, and R. W. Haas, “Generating random
+    ///    variates using transformations with multiple roots,” The American
+    ///    Statistician, vol. 30, no. 2, pp. 88–90, 1976.
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        use distribution::gaussian;
+        let &Wald { mu, lambda } = self;
+        let nu = gaussian::sample(source);
+        let y = nu * nu;
+        let x = mu + mu * mu * y / (2.0 * lambda) -
+            mu / (2.0 * lambda) * (4.0 * mu * lambda * y + mu * mu * y * y).sqrt();
+        if source.read::<f64>() <= mu / (mu + x) { x } else { mu * mu / x }
+    }
+}
+
+impl distribution::Skewness for Wald {
+    #[inline]
+    fn skewness(&self) -> f64 {
+        3.0 * (self.mu / self.lambda).sqrt()
+    }
+}
+
+impl distribution::Support for Wald {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for Wald {
+    #[inline]
+    fn variance(&self) -> f64 {
+        self.mu * self.mu * self.mu / self.lambda
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($mu:expr, $lambda:expr) => (Wald::new($mu, $lambda));
+    );
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 3.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 5.5913979633876140e-02,
+            3.6152950660010973e-01, 4.7490884963330902e-01, 3.5333804312537141e-01,
+            2.4430125595145996e-01, 1.1735510892143316e-01, 5.9363606204163628e-02,
+            1.7296145725858113e-02, 1.9822751498574634e-03,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 3.0).to_string(), "Wald(μ=1, λ=3)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 3.0);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 10.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 2.1959874857560712e-03,
+            5.5186835993080499e-02, 2.8738674440477363e-01, 4.9569012484162948e-01,
+            6.4367062476672812e-01, 8.1618692345552784e-01, 9.0091051363354096e-01,
+            9.6832780581421315e-01, 9.9596355104199970e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert_eq!(new!(2.0, 3.0).kurtosis(), 10.0);
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!(new!(2.0, 3.0).mean(), 2.0);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(2.0, 3.0).modes()[0], 0.8284271247461901, 1e-15);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        let d = new!(2.0, 3.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x > 0.0));
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= 2.0).count() as f64 / n as f64;
+        assert!((below - d.distribution(2.0)).abs() < 0.02);
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0, 3.0).skewness(), 2.4494897427831781, 1e-15);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(1.0, 2.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Wald::try_new(1.0, 2.0).is_ok());
+        assert_eq!(Wald::try_new(1.0, 0.0).err(), Some("lambda must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert_eq!(new!(2.0, 3.0).variance(), 8.0 / 3.0);
+    }
+}
@@ -0,0 +1,335 @@
+use distribution;
+use source::Source;
+use std::fmt;
+
+/// A Weibull distribution.
+#[derive(Clone, Copy)]
+pub struct Weibull {
+    lambda: f64,
+    k: f64,
+}
+
+impl Weibull {
+    /// Create a Weibull distribution with scale parameter `lambda` and shape
+    /// parameter `k`.
+    ///
+    /// It should hold that `lambda > 0` and `k > 0`.
+    #[inline]
+    pub fn new(lambda: f64, k: f64) -> Self {
+        should!(lambda > 0.0 && k > 0.0);
+        Weibull { lambda: lambda, k: k }
+    }
+
+    /// Create a Weibull distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(lambda: f64, k: f64) -> Result<Self, &'static str> {
+        must!(lambda > 0.0, "lambda must be positive");
+        must!(k > 0.0, "k must be positive");
+        Ok(Weibull::new(lambda, k))
+    }
+
+    /// Return the scale parameter.
+    #[inline(always)]
+    pub fn lambda(&self) -> f64 { self.lambda }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn k(&self) -> f64 { self.k }
+
+    // Γ(1 + i / k)
+    #[inline(always)]
+    fn gamma(&self, i: f64) -> f64 {
+        use special::Gamma;
+        Gamma::gamma(1.0 + i / self.k)
+    }
+}
+
+impl fmt::Display for Weibull {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Weibull(λ={}, k={})", self.lambda, self.k)
+    }
+}
+
+impl distribution::Continuous for Weibull {
+    fn density(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            let z = x / self.lambda;
+            self.k / self.lambda * z.powf(self.k - 1.0) * (-z.powf(self.k)).exp()
+        }
+    }
+}
+
+impl distribution::Distribution for Weibull {
+    type Value = f64;
+
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            -(-(x / self.lambda).powf(self.k)).exp_m1()
+        }
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            (-(x / self.lambda).powf(self.k)).exp()
+        }
+    }
+}
+
+impl distribution::Entropy for Weibull {
+    #[inline]
+    fn entropy(&self) -> f64 {
+        const EULER_MASCHERONI: f64 = 0.5772156649015329;
+        EULER_MASCHERONI * (1.0 - 1.0 / self.k) + (self.lambda / self.k).ln() + 1.0
+    }
+}
+
+impl distribution::Inverse for Weibull {
+    #[inline]
+    fn inverse(&self, p: f64) -> f64 {
+        should!(0.0 <= p && p <= 1.0);
+        self.lambda * (-(-p).ln_1p()).powf(1.0 / self.k)
+    }
+}
+
+impl distribution::Kurtosis for Weibull {
+    fn kurtosis(&self) -> f64 {
+        let (g1, g2, g3, g4) = (self.gamma(1.0), self.gamma(2.0), self.gamma(3.0), self.gamma(4.0));
+        let g1_2 = g1 * g1;
+        (-6.0 * g1_2 * g1_2 + 12.0 * g1_2 * g2 - 3.0 * g2 * g2 - 4.0 * g1 * g3 + g4) /
+            (g2 - g1_2).powi(2)
+    }
+}
+
+impl distribution::Mean for Weibull {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.lambda * self.gamma(1.0)
+    }
+}
+
+impl distribution::Median for Weibull {
+    #[inline]
+    fn median(&self) -> f64 {
+        use std::f64::consts::LN_2;
+        self.lambda * LN_2.powf(1.0 / self.k)
+    }
+}
+
+impl distribution::Modes for Weibull {
+    fn modes(&self) -> Vec<f64> {
+        if self.k > 1.0 {
+            vec![self.lambda * ((self.k - 1.0) / self.k).powf(1.0 / self.k)]
+        } else {
+            vec![0.0]
+        }
+    }
+}
+
+impl distribution::Parametric for Weibull {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 2 }
+}
+
+impl distribution::Reliability for Weibull {
+    #[inline]
+    fn hazard(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            self.k / self.lambda * (x / self.lambda).powf(self.k - 1.0)
+        }
+    }
+
+    #[inline]
+    fn cumulative_hazard(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            (x / self.lambda).powf(self.k)
+        }
+    }
+}
+
+impl distribution::Sample for Weibull {
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> f64 where S: Source {
+        self.lambda * (-source.read::<f64>().ln()).powf(1.0 / self.k)
+    }
+}
+
+impl distribution::Skewness for Weibull {
+    fn skewness(&self) -> f64 {
+        let (g1, g2, g3) = (self.gamma(1.0), self.gamma(2.0), self.gamma(3.0));
+        (g3 - 3.0 * g1 * g2 + 2.0 * g1 * g1 * g1) / (g2 - g1 * g1).powf(1.5)
+    }
+}
+
+impl distribution::Support for Weibull {
+    #[inline]
+    fn support(&self) -> (f64, f64) {
+        use std::f64::INFINITY;
+        (0.0, INFINITY)
+    }
+}
+
+impl distribution::Variance for Weibull {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let g1 = self.gamma(1.0);
+        self.lambda * self.lambda * (self.gamma(2.0) - g1 * g1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($lambda:expr, $k:expr) => (Weibull::new($lambda, $k));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(2.0, 0.5);
+        for &x in &[-1.0, 0.0, 0.5, 1.0, 2.0, 5.0] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+        assert_eq!(d.complementary(5000.0), (-50.0f64).exp());
+    }
+
+    #[test]
+    fn density() {
+        let d = new!(2.0, 1.5);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 2.5370147026198682e-01,
+            3.3093633846922328e-01, 3.7239168821942198e-01, 3.3924179661051490e-01,
+            2.7590958087858174e-01, 1.4630426404454230e-01, 6.2691111301579085e-02,
+            7.1938531207694218e-03,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.density(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(1.0, 0.5).to_string(), "Weibull(λ=1, k=0.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.0, 1.5);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 4.3231839897343432e-02,
+            1.1750309741540460e-01, 2.9781149867344040e-01, 4.7770308641745852e-01,
+            6.3212055882855768e-01, 8.4072409150997863e-01, 9.4089425343804376e-01,
+            9.9446216928561753e-01,
+        ];
+
+        assert::close(&x.iter().map(|&x| d.distribution(x)).collect::<Vec<_>>(), &p, 1e-15);
+    }
+
+    #[test]
+    fn entropy() {
+        assert::close(new!(2.0, 1.5).entropy(), 1.4800872940856252, 1e-15);
+        assert_eq!(new!(1.0, 1.0).entropy(), Exponential::new(1.0).entropy());
+    }
+
+    #[test]
+    fn inverse() {
+        use std::f64::INFINITY;
+
+        let d = new!(2.0, 1.5);
+        let p = vec![0.0, 0.05, 0.1, 0.25, 0.5, 0.75, 0.9, 0.95, 1.0];
+        let x = vec![
+            0.0000000000000000e+00, 2.7610253311256834e-01, 4.4615105127383418e-01,
+            8.7157586340604751e-01, 1.5664395375493027e+00, 2.4865677697503430e+00,
+            3.4874430271928234e+00, 4.1562212750691132e+00, INFINITY,
+        ];
+
+        assert::close(&p.iter().map(|&p| d.inverse(p)).collect::<Vec<_>>(), &x, 1e-14);
+    }
+
+    #[test]
+    fn kurtosis() {
+        assert::close(new!(2.0, 1.5).kurtosis(), 1.3904035615957883, 1e-13);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(2.0, 1.5).mean(), 1.8054905859018672, 1e-15);
+    }
+
+    #[test]
+    fn median() {
+        assert::close(new!(2.0, 1.5).median(), 1.5664395375493027, 1e-15);
+    }
+
+    #[test]
+    fn modes() {
+        assert::close(new!(2.0, 1.5).modes()[0], 0.96149971353827225, 1e-15);
+        assert_eq!(new!(2.0, 0.5).modes(), vec![0.0]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(1.0, 2.0).num_parameters(), 2);
+    }
+
+    #[test]
+    fn reliability() {
+        use std::f64::INFINITY;
+
+        let d = new!(2.0, 1.5);
+        let x = vec![-1.0, 0.0, 0.25, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+        for &x in &x {
+            let survival = 1.0 - d.distribution(x);
+            assert::close(d.survival(x), survival, 1e-15);
+            assert::close(d.hazard(x), d.density(x) / survival, 1e-11);
+            assert::close(d.cumulative_hazard(x), -survival.ln(), 1e-11);
+        }
+
+        assert_eq!(new!(2.0, 1.0).hazard(3.0), 0.5);
+        assert_eq!(new!(2.0, 0.5).hazard(0.0), INFINITY);
+        assert::close(d.hazard(30.0), 0.75 * 15f64.sqrt(), 1e-14);
+    }
+
+    #[test]
+    fn sample() {
+        for x in Independent(&new!(2.0, 1.5), &mut source::default()).take(100) {
+            assert!(x >= 0.0);
+        }
+    }
+
+    #[test]
+    fn skewness() {
+        assert::close(new!(2.0, 1.5).skewness(), 1.0719865728909563, 1e-14);
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(1.0, 2.0).support(), (0.0, ::std::f64::INFINITY));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Weibull::try_new(1.0, 2.0).is_ok());
+        assert_eq!(Weibull::try_new(0.0, 2.0).err(), Some("lambda must be positive"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(2.0, 1.5).variance(), 1.5027611392557280, 1e-14);
+    }
+}
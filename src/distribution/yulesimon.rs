@@ -0,0 +1,287 @@
+use distribution;
+use source::Source;
+use std::fmt;
+use std::ops::RangeFrom;
+
+/// A Yule–Simon distribution.
+///
+/// The mass at `k` is `ρ B(k, ρ + 1)`, which decays as `k^-(ρ + 1)`, and the
+/// support is `{1, 2, 3, …}`. The distribution arises as the limiting degree
+/// distribution of preferential attachment processes.
+#[derive(Clone, Copy)]
+pub struct YuleSimon {
+    rho: f64,
+}
+
+impl YuleSimon {
+    /// Create a Yule–Simon distribution with shape parameter `rho`.
+    ///
+    /// It should hold that `rho > 0`.
+    #[inline]
+    pub fn new(rho: f64) -> Self {
+        should!(rho > 0.0 && rho.is_finite());
+        YuleSimon { rho: rho }
+    }
+
+    /// Create a Yule–Simon distribution or return an error if the parameters
+    /// are invalid.
+    #[inline]
+    pub fn try_new(rho: f64) -> Result<Self, &'static str> {
+        must!(rho > 0.0 && rho.is_finite(), "rho must be positive and finite");
+        Ok(YuleSimon::new(rho))
+    }
+
+    /// Return the shape parameter.
+    #[inline(always)]
+    pub fn rho(&self) -> f64 { self.rho }
+
+    // ln P(X > k) = ln k + ln B(k, ρ + 1)
+    #[inline(always)]
+    fn ln_complementary(&self, k: f64) -> f64 {
+        use special::Beta;
+        k.ln() + k.ln_beta(self.rho + 1.0)
+    }
+}
+
+impl fmt::Display for YuleSimon {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "YuleSimon(ρ={})", self.rho)
+    }
+}
+
+impl distribution::Discrete for YuleSimon {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x == 0 {
+            0.0
+        } else {
+            self.ln_mass(x).exp()
+        }
+    }
+
+    /// Compute the natural logarithm of the probability mass function, which
+    /// is `ln ρ + ln Γ(k) + ln Γ(ρ + 1) - ln Γ(k + ρ + 1)`.
+    #[inline]
+    fn ln_mass(&self, x: usize) -> f64 {
+        use special::Gamma;
+        if x == 0 {
+            return ::std::f64::NEG_INFINITY;
+        }
+        let (k, rho) = (x as f64, self.rho);
+        rho.ln() + Gamma::ln_gamma(k).0 + Gamma::ln_gamma(rho + 1.0).0 -
+            Gamma::ln_gamma(k + rho + 1.0).0
+    }
+}
+
+impl distribution::DiscreteSupport for YuleSimon {
+    type Iter = RangeFrom<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (1, ::std::i64::MAX)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        1..
+    }
+}
+
+impl distribution::Distribution for YuleSimon {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function, which is `1 - k B(k, ρ +
+    /// 1)` where `k = ⌊x⌋`.
+    #[inline]
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            return 0.0;
+        }
+        -self.ln_complementary(x.floor()).exp_m1()
+    }
+
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            return 1.0;
+        }
+        self.ln_complementary(x.floor()).exp()
+    }
+}
+
+impl distribution::Mean for YuleSimon {
+    /// Compute the mean, which is infinite for `ρ ≤ 1`.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.rho <= 1.0 {
+            return ::std::f64::INFINITY;
+        }
+        self.rho / (self.rho - 1.0)
+    }
+}
+
+impl distribution::Modes for YuleSimon {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Parametric for YuleSimon {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for YuleSimon {
+    /// Draw a sample.
+    ///
+    /// Given `W` following the exponential distribution with rate `ρ`, the
+    /// sample is drawn from the geometric distribution on `{1, 2, 3, …}` with
+    /// success probability `e^-W`.
+    fn sample<S>(&self, source: &mut S) -> usize where S: Source {
+        let w = -source.read::<f64>().ln() / self.rho;
+        let ln_q = (-(-w).exp_m1()).ln();
+        let x = 1.0 + (source.read::<f64>().ln() / ln_q).floor();
+        if x.is_finite() { x as usize } else { 1 }
+    }
+}
+
+impl distribution::Variance for YuleSimon {
+    /// Compute the variance, which is infinite for `ρ ≤ 2`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        let rho = self.rho;
+        if rho <= 2.0 {
+            return ::std::f64::INFINITY;
+        }
+        rho * rho / ((rho - 1.0) * (rho - 1.0) * (rho - 2.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($rho:expr) => (YuleSimon::new($rho));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(2.5);
+        for x in -1..12 {
+            let x = x as f64;
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+        assert::close(d.complementary(1e6), 3.3233364308336918e-15, 1e-23);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(2.5).to_string(), "YuleSimon(ρ=2.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(2.5);
+        let p = vec![
+            0.0000000000000000e+00, 0.0000000000000000e+00, 7.1428571428571430e-01,
+            8.7301587301587302e-01, 9.3073593073593075e-01, 9.5737595737595738e-01,
+            9.7158397158397158e-01, 9.7994162700045051e-01, 9.8522014621085829e-01,
+            9.8873915901779674e-01,
+        ];
+
+        let x = (-1..9).map(|x| d.distribution(x as f64)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+
+        let x = (-1..9).map(|x| d.distribution(x as f64 + 0.5)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+    }
+
+    #[test]
+    fn iter_support() {
+        let d = new!(2.5);
+        assert_eq!(d.iter_support().take(3).collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn ln_mass() {
+        let d = new!(2.5);
+        for x in 1..12 {
+            assert::close(d.ln_mass(x), d.mass(x).ln(), 1e-14);
+        }
+        assert_eq!(d.ln_mass(0), ::std::f64::NEG_INFINITY);
+
+        let k = 1000000;
+        let slope = (d.ln_mass(2 * k) - d.ln_mass(k)) / 2f64.ln();
+        assert::close(slope, -3.5, 1e-5);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(2.5);
+        let p = vec![
+            0.0000000000000000e+00, 7.1428571428571430e-01, 1.5873015873015872e-01,
+            5.7720057720057720e-02, 2.6640026640026640e-02, 1.4208014208014208e-02,
+            8.3576554164789454e-03, 5.2785192104077552e-03, 3.5190128069385036e-03,
+            2.4480089091746112e-03,
+        ];
+
+        assert::close(&(0..10).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+        assert::close(new!(1.0).mass(3), 1.0 / 12.0, 1e-15);
+    }
+
+    #[test]
+    fn mean() {
+        use std::f64::INFINITY;
+        assert::close(new!(2.5).mean(), 2.5 / 1.5, 1e-15);
+        assert_eq!(new!(1.0).mean(), INFINITY);
+        assert_eq!(new!(0.5).mean(), INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(2.5).modes(), vec![1]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(2.5).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+        for &rho in &[1.5, 3.5] {
+            let d = new!(rho);
+            let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+            assert!(samples.iter().all(|&x| x >= 1));
+            for &k in &[1, 2, 5, 20] {
+                let p = samples.iter().filter(|&&x| x > k).count() as f64 / n as f64;
+                let q = d.complementary(k as f64);
+                assert!((p - q).abs() < 5.0 * (q * (1.0 - q) / n as f64).sqrt());
+            }
+        }
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(2.5).support(), (1, ::std::i64::MAX));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(YuleSimon::try_new(2.5).is_ok());
+        assert_eq!(YuleSimon::try_new(0.0).err(), Some("rho must be positive and finite"));
+    }
+
+    #[test]
+    fn variance() {
+        use std::f64::INFINITY;
+        assert::close(new!(3.5).variance(), 3.5 * 3.5 / (2.5 * 2.5 * 1.5), 1e-15);
+        assert_eq!(new!(2.0).variance(), INFINITY);
+        assert_eq!(new!(1.5).variance(), INFINITY);
+    }
+}
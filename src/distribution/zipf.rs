@@ -0,0 +1,505 @@
+use distribution;
+use source::Source;
+use std::fmt;
+use std::ops::{Range, RangeFrom};
+
+/// A Zipf distribution.
+///
+/// The distribution is over the ranks `{1, 2, …, n}`, and the mass at rank
+/// `k` is proportional to `k^(-s)`. The normalizing constant is the
+/// generalized harmonic number `H(n, s)`, which is computed once on creation
+/// together with the cumulative distribution function.
+#[derive(Clone)]
+pub struct Zipf {
+    n: u64,
+    s: f64,
+    harmonic: f64,
+    cumsum: Vec<f64>,
+}
+
+/// A zeta distribution.
+///
+/// The distribution is the limit of the Zipf distribution as the number of
+/// ranks tends to infinity. The mass at `k` is `k^(-s) / ζ(s)` where `ζ` is
+/// the Riemann zeta function, and the support is `{1, 2, 3, …}`.
+#[derive(Clone, Copy)]
+pub struct Zeta {
+    s: f64,
+    zeta: f64,
+}
+
+impl Zipf {
+    /// Create a Zipf distribution with `n` ranks and exponent `s`.
+    ///
+    /// It should hold that `n > 0` and `s > 0`.
+    pub fn new(n: u64, s: f64) -> Self {
+        should!(n > 0 && s > 0.0);
+        let harmonic = (1..(n + 1)).rev().fold(0.0, |sum, k| sum + (k as f64).powf(-s));
+        let mut cumsum = Vec::with_capacity(n as usize);
+        let mut sum = 0.0;
+        for k in 1..(n + 1) {
+            sum += (k as f64).powf(-s) / harmonic;
+            cumsum.push(sum);
+        }
+        cumsum[n as usize - 1] = 1.0;
+        Zipf { n: n, s: s, harmonic: harmonic, cumsum: cumsum }
+    }
+
+    /// Create a Zipf distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(n: u64, s: f64) -> Result<Self, &'static str> {
+        must!(n > 0, "n must be positive");
+        must!(s > 0.0, "s must be positive");
+        Ok(Zipf::new(n, s))
+    }
+
+    /// Return the number of ranks.
+    #[inline(always)]
+    pub fn n(&self) -> u64 { self.n }
+
+    /// Return the exponent.
+    #[inline(always)]
+    pub fn s(&self) -> f64 { self.s }
+
+    /// Return the generalized harmonic number `H(n, s)`.
+    #[inline(always)]
+    pub fn harmonic(&self) -> f64 { self.harmonic }
+
+    fn moment(&self, order: f64) -> f64 {
+        let s = self.s - order;
+        (1..(self.n + 1)).rev().fold(0.0, |sum, k| sum + (k as f64).powf(-s)) / self.harmonic
+    }
+}
+
+impl Zeta {
+    /// Create a zeta distribution with exponent `s`.
+    ///
+    /// It should hold that `s > 1`.
+    #[inline]
+    pub fn new(s: f64) -> Self {
+        should!(s > 1.0);
+        Zeta { s: s, zeta: hurwitz(s, 1.0) }
+    }
+
+    /// Create a zeta distribution or return an error if the parameters are
+    /// invalid.
+    #[inline]
+    pub fn try_new(s: f64) -> Result<Self, &'static str> {
+        must!(s > 1.0, "s must be greater than one");
+        Ok(Zeta::new(s))
+    }
+
+    /// Return the exponent.
+    #[inline(always)]
+    pub fn s(&self) -> f64 { self.s }
+}
+
+impl fmt::Display for Zipf {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Zipf(n={}, s={})", self.n, self.s)
+    }
+}
+
+impl fmt::Display for Zeta {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Zeta(s={})", self.s)
+    }
+}
+
+impl distribution::Discrete for Zipf {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x == 0 || x as u64 > self.n {
+            0.0
+        } else {
+            (x as f64).powf(-self.s) / self.harmonic
+        }
+    }
+
+    #[inline]
+    fn ln_mass(&self, x: usize) -> f64 {
+        if x == 0 || x as u64 > self.n {
+            ::std::f64::NEG_INFINITY
+        } else {
+            -self.s * (x as f64).ln() - self.harmonic.ln()
+        }
+    }
+}
+
+impl distribution::Discrete for Zeta {
+    #[inline]
+    fn mass(&self, x: usize) -> f64 {
+        if x == 0 {
+            0.0
+        } else {
+            (x as f64).powf(-self.s) / self.zeta
+        }
+    }
+
+    #[inline]
+    fn ln_mass(&self, x: usize) -> f64 {
+        if x == 0 {
+            ::std::f64::NEG_INFINITY
+        } else {
+            -self.s * (x as f64).ln() - self.zeta.ln()
+        }
+    }
+}
+
+impl distribution::DiscreteSupport for Zipf {
+    type Iter = Range<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (1, self.n as i64)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        1..(self.n as usize + 1)
+    }
+}
+
+impl distribution::DiscreteSupport for Zeta {
+    type Iter = RangeFrom<usize>;
+
+    #[inline]
+    fn support(&self) -> (i64, i64) {
+        (1, ::std::i64::MAX)
+    }
+
+    #[inline]
+    fn iter_support(&self) -> Self::Iter {
+        1..
+    }
+}
+
+impl distribution::Distribution for Zipf {
+    type Value = usize;
+
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            return 0.0;
+        }
+        let x = x as u64;
+        if x >= self.n {
+            return 1.0;
+        }
+        self.cumsum[x as usize - 1]
+    }
+}
+
+impl distribution::Distribution for Zeta {
+    type Value = usize;
+
+    /// Compute the cumulative distribution function.
+    ///
+    /// For small `x`, the masses are summed directly; otherwise, the function
+    /// is computed via `complementary`.
+    fn distribution(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            return 0.0;
+        }
+        let x = x.floor();
+        if x > 64.0 {
+            return 1.0 - self.complementary(x);
+        }
+        (1..(x as usize + 1)).rev().fold(0.0, |sum, k| sum + (k as f64).powf(-self.s)) / self.zeta
+    }
+
+    /// Compute the complementary cumulative distribution function.
+    ///
+    /// The function is `ζ(s, ⌊x⌋ + 1) / ζ(s)` where `ζ(s, a)` is the Hurwitz
+    /// zeta function.
+    #[inline]
+    fn complementary(&self, x: f64) -> f64 {
+        if x < 1.0 {
+            return 1.0;
+        }
+        hurwitz(self.s, x.floor() + 1.0) / self.zeta
+    }
+}
+
+impl distribution::Mean for Zipf {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.moment(1.0)
+    }
+}
+
+impl distribution::Mean for Zeta {
+    /// Compute the mean, which is `ζ(s - 1) / ζ(s)` for `s > 2` and infinite
+    /// otherwise.
+    #[inline]
+    fn mean(&self) -> f64 {
+        if self.s <= 2.0 {
+            return ::std::f64::INFINITY;
+        }
+        hurwitz(self.s - 1.0, 1.0) / self.zeta
+    }
+}
+
+impl distribution::Modes for Zipf {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Modes for Zeta {
+    #[inline]
+    fn modes(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+impl distribution::Parametric for Zipf {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Parametric for Zeta {
+    #[inline(always)]
+    fn num_parameters(&self) -> usize { 1 }
+}
+
+impl distribution::Sample for Zipf {
+    /// Draw a sample by inversion over the cumulative distribution function.
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> usize where S: Source {
+        let u = source.read::<f64>();
+        let x = match self.cumsum.binary_search_by(|p| p.partial_cmp(&u).unwrap()) {
+            Ok(i) => i + 2,
+            Err(i) => i + 1,
+        };
+        x.min(self.n as usize)
+    }
+}
+
+impl distribution::Sample for Zeta {
+    /// Draw a sample.
+    ///
+    /// ## References
+    ///
+    /// 1. L. Devroye, Non-Uniform Random Variate Generation. New York:
+    ///    Springer-Verlag, 1986, pp. 550–551.
+    fn sample<S>(&self, source: &mut S) -> usize where S: Source {
+        let b = 2f64.powf(self.s - 1.0);
+        loop {
+            let x = source.read::<f64>().powf(-(self.s - 1.0).recip()).floor();
+            if !(x < ::std::usize::MAX as f64) {
+                continue;
+            }
+            let t = (1.0 + x.recip()).powf(self.s - 1.0);
+            if source.read::<f64>() * x * (t - 1.0) / (b - 1.0) <= t / b {
+                return x as usize;
+            }
+        }
+    }
+}
+
+impl distribution::Variance for Zipf {
+    #[inline]
+    fn variance(&self) -> f64 {
+        let mean = self.moment(1.0);
+        self.moment(2.0) - mean * mean
+    }
+}
+
+impl distribution::Variance for Zeta {
+    /// Compute the variance, which is finite for `s > 3`.
+    #[inline]
+    fn variance(&self) -> f64 {
+        if self.s <= 3.0 {
+            return ::std::f64::INFINITY;
+        }
+        let mean = hurwitz(self.s - 1.0, 1.0) / self.zeta;
+        hurwitz(self.s - 2.0, 1.0) / self.zeta - mean * mean
+    }
+}
+
+// Compute the Hurwitz zeta function `ζ(s, a) = Σ_k (k + a)^(-s)` for `s > 1`
+// and `a > 0` via the Euler–Maclaurin formula.
+fn hurwitz(s: f64, a: f64) -> f64 {
+    const TERMS: usize = 16;
+    const COEFFICIENTS: [f64; 8] = [
+        1.0 / 12.0, -1.0 / 720.0, 1.0 / 30240.0, -1.0 / 1209600.0, 1.0 / 47900160.0,
+        -691.0 / 1307674368000.0, 1.0 / 74724249600.0, -3617.0 / 10670622842880000.0,
+    ];
+
+    let mut sum = (0..TERMS).rev().fold(0.0, |sum, k| sum + (k as f64 + a).powf(-s));
+    let b = a + TERMS as f64;
+    sum += b.powf(1.0 - s) / (s - 1.0) + 0.5 * b.powf(-s);
+    let (mut factor, mut power) = (s, b.powf(-s - 1.0));
+    for (j, &coefficient) in COEFFICIENTS.iter().enumerate() {
+        sum += coefficient * factor * power;
+        let k = 2.0 * j as f64;
+        factor *= (s + k + 1.0) * (s + k + 2.0);
+        power /= b * b;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    macro_rules! new(
+        ($n:expr, $s:expr) => (Zipf::new($n, $s));
+        ($s:expr) => (Zeta::new($s));
+    );
+
+    #[test]
+    fn complementary() {
+        let d = new!(2.5);
+        for &x in &[0.0, 1.0, 5.5, 64.0, 65.0, 1e3, 1e6] {
+            assert::close(d.complementary(x), 1.0 - d.distribution(x), 1e-15);
+        }
+        assert::close(d.complementary(1e6), 4.9696049147202531e-10, 1e-25);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(new!(10, 1.5).to_string(), "Zipf(n=10, s=1.5)");
+        assert_eq!(new!(2.5).to_string(), "Zeta(s=2.5)");
+    }
+
+    #[test]
+    fn distribution() {
+        let d = new!(5, 1.5);
+        let p = vec![
+            0.0, 0.0, 5.6803780787375269e-01, 7.6886950083268857e-01,
+            8.7818842792795382e-01, 9.4919315391217296e-01, 1.0000000000000000e+00,
+            1.0000000000000000e+00,
+        ];
+        let x = (-1..7).map(|x| d.distribution(x as f64)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+        let x = (-1..7).map(|x| d.distribution(x as f64 + 0.5)).collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+
+        let d = new!(2.5);
+        let p = vec![
+            0.0, 0.0, 7.4544129628877720e-01, 8.7721794518434837e-01,
+            9.8541438136768944e-01, 9.9950675081266960e-01, 9.9999950307640628e-01,
+        ];
+        let x = [-1.0, 0.0, 1.0, 2.0, 10.0, 100.0, 1e4].iter().map(|&x| d.distribution(x))
+                                                        .collect::<Vec<_>>();
+        assert::close(&x, &p, 1e-15);
+    }
+
+    #[test]
+    fn harmonic() {
+        assert::close(new!(5, 1.5).harmonic(), 1.7604461994231406, 1e-15);
+        assert::close(new!(10000, 1.0).harmonic(), 9.7876060360443823, 1e-13);
+    }
+
+    #[test]
+    fn iter_support() {
+        assert_eq!(new!(3, 1.5).iter_support().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(new!(2.5).iter_support().take(3).collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn ln_mass() {
+        let d = new!(5, 1.5);
+        for x in 1..6 {
+            assert::close(d.ln_mass(x), d.mass(x).ln(), 1e-15);
+        }
+        assert_eq!(d.ln_mass(6), ::std::f64::NEG_INFINITY);
+
+        let d = new!(2.5);
+        for x in 1..6 {
+            assert::close(d.ln_mass(x), d.mass(x).ln(), 1e-15);
+        }
+        assert_eq!(d.ln_mass(0), ::std::f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn mass() {
+        let d = new!(5, 1.5);
+        let p = vec![
+            0.0, 5.6803780787375269e-01, 2.0083169295893588e-01, 1.0931892709526533e-01,
+            7.1004725984219086e-02, 5.0806846087827047e-02, 0.0,
+        ];
+        assert::close(&(0..7).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(2.5);
+        let p = vec![
+            0.0, 7.4544129628877720e-01, 1.3177664889557117e-01, 4.7820081453043228e-02,
+            2.3295040509024287e-02,
+        ];
+        assert::close(&(0..5).map(|x| d.mass(x)).collect::<Vec<_>>(), &p, 1e-15);
+
+        let d = new!(1.1);
+        assert::close(new!(1.1).mass(1), 0.094478234110297348, 1e-15);
+        assert::close(d.masses(0..100000).iter().fold(0.0, |a, b| a + b),
+                      1.0 - d.complementary(99999.0), 1e-12);
+    }
+
+    #[test]
+    fn mean() {
+        assert::close(new!(5, 1.5).mean(), 1.835711109453432, 1e-15);
+        assert::close(new!(3.5).mean(), 1.1905981493617695, 1e-15);
+        assert_eq!(new!(2.0).mean(), ::std::f64::INFINITY);
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(new!(5, 1.5).modes(), vec![1]);
+        assert_eq!(new!(2.5).modes(), vec![1]);
+    }
+
+    #[test]
+    fn num_parameters() {
+        assert_eq!(new!(5, 1.5).num_parameters(), 1);
+        assert_eq!(new!(2.5).num_parameters(), 1);
+    }
+
+    #[test]
+    fn sample() {
+        let mut source = source::default();
+        let n = 10000;
+
+        let d = new!(5, 1.5);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 1 && x <= 5));
+        for k in 1..6 {
+            let count = samples.iter().filter(|&&x| x == k).count() as f64;
+            let p = d.mass(k);
+            assert!((count / n as f64 - p).abs() < 5.0 * (p * (1.0 - p) / n as f64).sqrt());
+        }
+
+        let d = new!(3.5);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(samples.iter().all(|&x| x >= 1));
+        for k in 1..4 {
+            let count = samples.iter().filter(|&&x| x == k).count() as f64;
+            let p = d.mass(k);
+            assert!((count / n as f64 - p).abs() < 5.0 * (p * (1.0 - p) / n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    fn support() {
+        assert_eq!(new!(5, 1.5).support(), (1, 5));
+        assert_eq!(new!(2.5).support(), (1, ::std::i64::MAX));
+    }
+
+    #[test]
+    fn try_new() {
+        assert!(Zipf::try_new(5, 1.5).is_ok());
+        assert_eq!(Zipf::try_new(0, 1.5).err(), Some("n must be positive"));
+        assert_eq!(Zipf::try_new(5, 0.0).err(), Some("s must be positive"));
+        assert!(Zeta::try_new(2.5).is_ok());
+        assert_eq!(Zeta::try_new(1.0).err(), Some("s must be greater than one"));
+    }
+
+    #[test]
+    fn variance() {
+        assert::close(new!(5, 1.5).variance(), 1.3916464141393156, 1e-14);
+        assert::close(new!(4.5).variance(), 0.13066023147512419, 1e-14);
+        assert_eq!(new!(3.0).variance(), ::std::f64::INFINITY);
+    }
+}
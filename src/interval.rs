@@ -0,0 +1,102 @@
+//! Confidence intervals.
+
+use distribution::{Beta, Gaussian, Inverse};
+
+/// Compute the Clopper–Pearson interval for a binomial proportion.
+///
+/// The interval is exact in the sense that it covers the success probability
+/// with at least the probability `confidence` given `successes` out of
+/// `trials` Bernoulli trials, which makes it conservative. The bounds are the
+/// `(1 - confidence) / 2` quantile of `Beta(k, n - k + 1)` and the `(1 +
+/// confidence) / 2` quantile of `Beta(k + 1, n - k)`, and the lower and upper
+/// bounds are exactly zero and one for `k = 0` and `k = n`, respectively. It
+/// should hold that `successes ≤ trials`, `trials > 0`, and `0 < confidence <
+/// 1`.
+pub fn clopper_pearson(successes: u64, trials: u64, confidence: f64) -> (f64, f64) {
+    should!(successes <= trials && trials > 0 && 0.0 < confidence && confidence < 1.0);
+    let (k, n) = (successes as f64, trials as f64);
+    let lower = if successes == 0 {
+        0.0
+    } else {
+        Beta::new(k, n - k + 1.0, 0.0, 1.0).inverse(0.5 - 0.5 * confidence)
+    };
+    let upper = if successes == trials {
+        1.0
+    } else {
+        Beta::new(k + 1.0, n - k, 0.0, 1.0).inverse(0.5 + 0.5 * confidence)
+    };
+    (lower, upper)
+}
+
+/// Compute the Wilson score interval for a binomial proportion.
+///
+/// The interval covers the success probability with approximately the
+/// probability `confidence` given `successes` out of `trials` Bernoulli trials.
+/// Unlike the normal approximation, the interval is never degenerate and stays
+/// within `[0, 1]`, which makes it suitable for small samples and proportions
+/// near zero or one. It should hold that `successes ≤ trials`, `trials > 0`,
+/// and `0 < confidence < 1`.
+pub fn wilson(successes: u64, trials: u64, confidence: f64) -> (f64, f64) {
+    should!(successes <= trials && trials > 0 && 0.0 < confidence && confidence < 1.0);
+    let z = Gaussian::new(0.0, 1.0).inverse(0.5 + 0.5 * confidence);
+    let (n, z2) = (trials as f64, z * z);
+    let p = successes as f64 / n;
+    let denominator = 1.0 + z2 / n;
+    let center = (p + 0.5 * z2 / n) / denominator;
+    let half = z / denominator * (p * (1.0 - p) / n + 0.25 * z2 / (n * n)).sqrt();
+    ((center - half).max(0.0), (center + half).min(1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+
+    #[test]
+    fn clopper_pearson() {
+        use interval::{clopper_pearson, wilson};
+
+        let (lower, upper) = clopper_pearson(8, 10, 0.95);
+        assert::close(lower, 0.44390453769235849, 1e-14);
+        assert::close(upper, 0.97478927367316659, 1e-14);
+
+        assert_eq!(clopper_pearson(0, 10, 0.95).0, 0.0);
+        assert::close(clopper_pearson(0, 10, 0.95).1, 0.30849710781876077, 1e-14);
+        assert::close(clopper_pearson(10, 10, 0.95).0, 0.69150289218123928, 1e-14);
+        assert_eq!(clopper_pearson(10, 10, 0.95).1, 1.0);
+
+        let (lower, upper) = clopper_pearson(1, 1000, 0.99);
+        assert::close(lower, 5.0125292607775107e-06, 1e-18);
+        assert::close(upper, 0.0074062869383529368, 1e-14);
+
+        let (lower, upper) = clopper_pearson(3, 50, 0.9);
+        assert::close(lower, 0.016551859189231351, 1e-15);
+        assert::close(upper, 0.14783717636418126, 1e-14);
+
+        let (exact, approximate) = (clopper_pearson(8, 10, 0.95), wilson(8, 10, 0.95));
+        assert!(exact.0 < approximate.0 && approximate.1 < exact.1);
+    }
+
+    #[test]
+    fn wilson() {
+        use interval::wilson;
+
+        let (lower, upper) = wilson(8, 10, 0.95);
+        assert::close(lower, 0.49016247153664178, 1e-14);
+        assert::close(upper, 0.94331784854562473, 1e-14);
+
+        let (lower, upper) = wilson(0, 10, 0.95);
+        assert::close(lower, 0.0, 1e-15);
+        assert::close(upper, 0.2775327998628892, 1e-14);
+
+        let (lower, upper) = wilson(10, 10, 0.95);
+        assert::close(lower, 0.72246720013711085, 1e-14);
+        assert::close(upper, 1.0, 1e-15);
+
+        let (lower, upper) = wilson(1, 1000, 0.99);
+        assert::close(lower, 0.00011741635361832114, 1e-15);
+        assert::close(upper, 0.0084605662199816953, 1e-15);
+
+        let (narrow, wide) = (wilson(8, 10, 0.9), wilson(8, 10, 0.99));
+        assert!(wide.0 < narrow.0 && narrow.1 < wide.1);
+    }
+}
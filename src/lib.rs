@@ -13,10 +13,21 @@
 
 #[cfg(test)]
 extern crate assert;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
+#[cfg(feature = "rand-interop")]
+extern crate rand;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 extern crate random;
 extern crate special;
 
+macro_rules! must(
+    ($requirement:expr, $message:expr) => (if !$requirement { return Err($message); });
+);
+
 macro_rules! nonnan(
     ($argument:ident) => (if $argument.is_nan() { return ::std::f64::NAN; });
 );
@@ -27,6 +38,8 @@ macro_rules! should(
 );
 
 pub mod distribution;
+pub mod interval;
 pub mod prelude;
 pub mod sampler;
 pub mod source;
+pub mod statistic;
@@ -4,29 +4,86 @@ pub use distribution::Distribution;
 
 pub use distribution::Continuous;
 pub use distribution::Discrete;
+pub use distribution::DiscreteSupport;
 
+pub use distribution::CharacteristicFunction;
 pub use distribution::Entropy;
 pub use distribution::Inverse;
 pub use distribution::Kurtosis;
 pub use distribution::Mean;
 pub use distribution::Median;
 pub use distribution::Modes;
+pub use distribution::Moment;
+pub use distribution::Parametric;
+pub use distribution::Reliability;
 pub use distribution::Sample;
 pub use distribution::Skewness;
+pub use distribution::Support;
+pub use distribution::Symmetric;
 pub use distribution::Variance;
 
+pub use distribution::Affine;
+pub use distribution::Bates;
+pub use distribution::Benford;
 pub use distribution::Bernoulli;
 pub use distribution::Beta;
+pub use distribution::BetaBinomial;
 pub use distribution::Binomial;
 pub use distribution::Categorical;
+pub use distribution::Cauchy;
+pub use distribution::Chi;
+pub use distribution::Chisquared;
+pub use distribution::ComPoisson;
+pub use distribution::Dirichlet;
+pub use distribution::DiscreteUniform;
+pub use distribution::Empirical;
+pub use distribution::Erlang;
 pub use distribution::Exponential;
+pub use distribution::Fisher;
+pub use distribution::FoldedNormal;
+pub use distribution::Frechet;
 pub use distribution::Gamma;
 pub use distribution::Gaussian;
+pub use distribution::GeneralizedPareto;
+pub use distribution::Geometric;
+pub use distribution::Gev;
+pub use distribution::Gompertz;
+pub use distribution::Gumbel;
+pub use distribution::HalfNormal;
+pub use distribution::Hypergeometric;
+pub use distribution::InverseGamma;
+pub use distribution::IrwinHall;
+pub use distribution::Kde;
+pub use distribution::Kumaraswamy;
+pub use distribution::Laplace;
+pub use distribution::Levy;
 pub use distribution::Logistic;
 pub use distribution::Lognormal;
+pub use distribution::LogSeries;
+pub use distribution::Maxwell;
+pub use distribution::Mixture;
+pub use distribution::Multinomial;
+pub use distribution::MvGaussian;
+pub use distribution::Nakagami;
+pub use distribution::NoncentralChisquared;
+pub use distribution::Poisson;
+pub use distribution::Rayleigh;
+pub use distribution::Skellam;
+pub use distribution::SkewNormal;
+pub use distribution::StudentsT;
 pub use distribution::Triangular;
+pub use distribution::Truncated;
 pub use distribution::Uniform;
+pub use distribution::VonMises;
+pub use distribution::Wald;
+pub use distribution::Weibull;
+pub use distribution::YuleSimon;
+pub use distribution::Zeta;
+pub use distribution::Zipf;
 
+pub use sampler::Antithetic;
 pub use sampler::Independent;
+pub use sampler::Samples;
+pub use sampler::Stratified;
 
 pub use source;
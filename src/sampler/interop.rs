@@ -0,0 +1,52 @@
+use rand::Rng;
+use rand::distributions::Distribution;
+
+use distribution::Sample;
+use source::Source;
+
+/// An adapter exposing a distribution to the `rand` crate.
+///
+/// The adapter implements `rand::distributions::Distribution`, and it draws
+/// samples by reading the random numbers from the generator provided by
+/// `rand`.
+#[derive(Clone, Copy)]
+pub struct RandAdapter<D>(pub D);
+
+struct Shim<'l, R: ?Sized + 'l>(&'l mut R);
+
+impl<'l, R> Source for Shim<'l, R> where R: Rng + ?Sized {
+    #[inline(always)]
+    fn read_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+}
+
+impl<D> Distribution<D::Value> for RandAdapter<D> where D: Sample {
+    #[inline]
+    fn sample<R>(&self, rng: &mut R) -> D::Value where R: Rng + ?Sized {
+        self.0.sample(&mut Shim(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use prelude::*;
+    use sampler::RandAdapter;
+
+    #[test]
+    fn sample() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let adapter = RandAdapter(Uniform::new(2.0, 3.0));
+        let x = rng.sample(adapter);
+        assert!(2.0 <= x && x <= 3.0);
+
+        let n = 10000;
+        let d = Exponential::new(2.0);
+        let samples = rng.sample_iter(RandAdapter(d)).take(n).collect::<Vec<_>>();
+        let mean = samples.iter().fold(0.0, |a, b| a + b) / n as f64;
+        assert!((mean - d.mean()).abs() < 5.0 * (d.variance() / n as f64).sqrt());
+    }
+}
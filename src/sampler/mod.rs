@@ -1,11 +1,58 @@
 //! Samplers of random numbers.
 
-use distribution::Sample;
+use distribution::{Inverse, Sample};
 use source::Source;
 
+#[cfg(feature = "rand-interop")]
+mod interop;
+
+#[cfg(feature = "rand-interop")]
+pub use self::interop::RandAdapter;
+
+/// A means of drawing antithetic pairs of samples.
+///
+/// Each pair is obtained from a single uniform variate `u` as
+/// `(F^(-1)(u), F^(-1)(1 - u))`, where `F` is the cumulative distribution
+/// function. The two samples are negatively correlated, which reduces the
+/// variance of Monte Carlo estimates of expectations of monotone functions.
+pub struct Antithetic<D> {
+    distribution: D,
+}
+
+impl<D> Antithetic<D> where D: Inverse {
+    /// Create a sampler of antithetic pairs for `distribution`.
+    #[inline]
+    pub fn new(distribution: D) -> Self {
+        Antithetic { distribution: distribution }
+    }
+
+    /// Draw a pair of antithetic samples.
+    #[inline]
+    pub fn sample_pair<S>(&self, source: &mut S) -> (D::Value, D::Value) where S: Source {
+        let u = source.read::<f64>();
+        (self.distribution.inverse(u), self.distribution.inverse(1.0 - u))
+    }
+}
+
 /// A means of drawing a sequence of independent samples.
 pub struct Independent<D, S>(pub D, pub S);
 
+impl<'a, D, S> Independent<&'a D, &'a mut S> where D: Sample, S: Source {
+    /// Draw a `rows × cols` matrix of independent samples.
+    ///
+    /// The matrix is stored in row-major order, so that the element in row `i`
+    /// and column `j` is at index `i * cols + j`, and it is filled via
+    /// `sample_into` in one pass.
+    pub fn matrix(distribution: &'a D, source: &'a mut S, rows: usize, cols: usize)
+                  -> Vec<D::Value>
+        where D::Value: Clone + Default
+    {
+        let mut matrix = vec![D::Value::default(); rows * cols];
+        sample_into(distribution, source, &mut matrix);
+        matrix
+    }
+}
+
 impl<'a, T, D, S> Iterator for Independent<&'a D, &'a mut S> where D: Sample<Value=T>, S: Source {
     type Item = T;
 
@@ -14,3 +61,184 @@ impl<'a, T, D, S> Iterator for Independent<&'a D, &'a mut S> where D: Sample<Val
         Some(self.0.sample(self.1))
     }
 }
+
+/// A lazy iterator over independent samples.
+///
+/// The iterator is created via `Sample::sample_iter`.
+pub struct Samples<'a, D: 'a, S: 'a> {
+    distribution: &'a D,
+    source: &'a mut S,
+}
+
+impl<'a, D, S> Samples<'a, D, S> where D: Sample, S: Source {
+    /// Create an iterator drawing samples of `distribution` from `source`.
+    #[inline]
+    pub fn new(distribution: &'a D, source: &'a mut S) -> Self {
+        Samples { distribution: distribution, source: source }
+    }
+}
+
+impl<'a, D, S> Iterator for Samples<'a, D, S> where D: Sample, S: Source {
+    type Item = D::Value;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<D::Value> {
+        Some(self.distribution.sample(self.source))
+    }
+}
+
+/// A means of drawing stratified samples.
+///
+/// The unit interval is partitioned into equal strata, one uniform variate is
+/// drawn within each stratum, and the variates are mapped through the inverse
+/// of the cumulative distribution function.
+pub struct Stratified;
+
+impl Stratified {
+    /// Draw `n` stratified samples, which are ordered by stratum.
+    pub fn sample<D, S>(distribution: &D, source: &mut S, n: usize) -> Vec<D::Value>
+        where D: Inverse, S: Source
+    {
+        let width = 1.0 / n as f64;
+        (0..n).map(|i| {
+            let u = (i as f64 + source.read::<f64>()) * width;
+            distribution.inverse(if u < 1.0 { u } else { 1.0 })
+        }).collect()
+    }
+}
+
+/// Fill a slice with independent samples.
+///
+/// In contrast to collecting from `Independent`, no allocation takes place,
+/// which makes the function suitable for repeatedly refilling a buffer.
+#[inline]
+pub fn sample_into<D, S>(distribution: &D, source: &mut S, out: &mut [D::Value])
+    where D: Sample, S: Source
+{
+    for value in out.iter_mut() {
+        *value = distribution.sample(source);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::*;
+    use sampler::sample_into;
+
+    #[test]
+    fn antithetic() {
+        use sampler::Antithetic;
+
+        let distribution = Exponential::new(2.0);
+        let sampler = Antithetic::new(distribution);
+        let mut source = source::default();
+        let (m, n) = (10, 10000);
+        let (mut antithetic, mut independent) = (vec![], vec![]);
+        for _ in 0..n {
+            let mut sum = 0.0;
+            for _ in 0..m {
+                let (x, y) = sampler.sample_pair(&mut source);
+                assert!(x >= 0.0 && y >= 0.0);
+                sum += x + y;
+            }
+            antithetic.push(sum / (2 * m) as f64);
+            let sum = Independent(&distribution, &mut source).take(2 * m).fold(0.0, |a, b| a + b);
+            independent.push(sum / (2 * m) as f64);
+        }
+        let variance = |estimates: &[f64]| {
+            let mean = estimates.iter().fold(0.0, |a, b| a + b) / n as f64;
+            estimates.iter().fold(0.0, |a, &b| a + (b - mean) * (b - mean)) / n as f64
+        };
+        assert!(variance(&antithetic) < 0.5 * variance(&independent));
+    }
+
+    #[test]
+    fn matrix() {
+        let distribution = Uniform::new(2.0, 3.0);
+        let (rows, cols) = (1000, 3);
+        let mut source = source::Xorshift128Plus::new([42, 69]);
+        let matrix = Independent::matrix(&distribution, &mut source, rows, cols);
+        assert_eq!(matrix.len(), rows * cols);
+        assert!(matrix.iter().all(|&x| 2.0 <= x && x <= 3.0));
+
+        let mut source = source::Xorshift128Plus::new([42, 69]);
+        let expected = Independent(&distribution, &mut source).take(rows * cols)
+                                                              .collect::<Vec<_>>();
+        assert_eq!(matrix, expected);
+
+        let mean = |j: usize| (0..rows).fold(0.0, |a, i| a + matrix[i * cols + j]) / rows as f64;
+        let (first, second) = (mean(0), mean(1));
+        for j in 0..cols {
+            assert!((mean(j) - 2.5).abs() < 5.0 * (1.0 / 12.0 / rows as f64).sqrt());
+        }
+        let covariance = (0..rows).fold(0.0, |a, i| {
+            a + (matrix[i * cols] - first) * (matrix[i * cols + 1] - second)
+        }) / rows as f64;
+        assert!(covariance.abs() < 5.0 / 12.0 / (rows as f64).sqrt());
+
+        let mut source = source::default();
+        assert!(Independent::matrix(&distribution, &mut source, 0, 3).is_empty());
+        let matrix = Independent::matrix(&Bernoulli::new(0.5), &mut source, 2, 5);
+        assert_eq!(matrix.len(), 10);
+    }
+
+    #[test]
+    fn sample_into_slice() {
+        let distribution = Uniform::new(2.0, 3.0);
+        let mut buffer = [0.0; 100];
+        sample_into(&distribution, &mut source::Xorshift128Plus::new([42, 69]), &mut buffer);
+        assert!(buffer.iter().all(|&x| 2.0 <= x && x <= 3.0));
+
+        let mut expected = [0.0; 100];
+        let mut source = source::Xorshift128Plus::new([42, 69]);
+        for (value, x) in expected.iter_mut().zip(Independent(&distribution, &mut source)) {
+            *value = x;
+        }
+        assert_eq!(&buffer[..], &expected[..]);
+    }
+
+    #[test]
+    fn stratified() {
+        use sampler::Stratified;
+
+        let distribution = Uniform::new(0.0, 10.0);
+        let mut source = source::default();
+        let samples = Stratified::sample(&distribution, &mut source, 10);
+        assert_eq!(samples.len(), 10);
+        for (i, &x) in samples.iter().enumerate() {
+            assert!(i as f64 <= x && x <= (i + 1) as f64);
+        }
+
+        let distribution = Exponential::new(2.0);
+        let (m, n) = (20, 1000);
+        let (mut stratified, mut independent) = (vec![], vec![]);
+        for _ in 0..n {
+            let sum = Stratified::sample(&distribution, &mut source, m).iter()
+                                                                       .fold(0.0, |a, b| a + b);
+            stratified.push(sum / m as f64);
+            let sum = Independent(&distribution, &mut source).take(m).fold(0.0, |a, b| a + b);
+            independent.push(sum / m as f64);
+        }
+        let variance = |estimates: &[f64]| {
+            let mean = estimates.iter().fold(0.0, |a, b| a + b) / n as f64;
+            estimates.iter().fold(0.0, |a, &b| a + (b - mean) * (b - mean)) / n as f64
+        };
+        assert!(variance(&stratified) < 0.5 * variance(&independent));
+    }
+
+    #[test]
+    fn samples() {
+        let distribution = Uniform::new(2.0, 3.0);
+        let mut source = source::Xorshift128Plus::new([42, 69]);
+        let samples = distribution.sample_iter(&mut source)
+                                  .map(|x| x - 2.0)
+                                  .filter(|&x| x < 0.5)
+                                  .take(100)
+                                  .collect::<Vec<_>>();
+        assert_eq!(samples.len(), 100);
+        assert!(samples.iter().all(|&x| 0.0 <= x && x < 0.5));
+
+        let first = distribution.sample_iter(&mut source).next().unwrap();
+        assert!(2.0 <= first && first <= 3.0);
+    }
+}
@@ -0,0 +1,88 @@
+use source::Source;
+
+/// A Halton low-discrepancy sequence.
+///
+/// The sequence consists of points in the unit hypercube of a given
+/// dimension; coordinate `j` of point `i` is the radical inverse of `i` in
+/// the base given by the `j`th prime. The source returns the coordinates of
+/// the first point, then those of the second one, and so on. The origin is
+/// skipped so that quantile functions are never evaluated at zero.
+///
+/// The sequence is deterministic and is intended for quasi-Monte Carlo
+/// integration with distributions whose sampling is based on the inverse of
+/// the cumulative distribution function, one coordinate per sample. Samplers
+/// relying on rejection consume a varying number of values per sample, which
+/// destroys the low-discrepancy structure, and they do not benefit.
+#[derive(Clone)]
+pub struct Halton {
+    bases: Vec<u64>,
+    index: u64,
+    coordinate: usize,
+}
+
+impl Halton {
+    /// Create a Halton sequence of dimension `dimension`.
+    ///
+    /// It should hold that `dimension > 0`.
+    pub fn new(dimension: usize) -> Self {
+        should!(dimension > 0);
+        let mut bases = Vec::with_capacity(dimension);
+        let mut candidate = 2;
+        while bases.len() < dimension {
+            if bases.iter().take_while(|&&p| p * p <= candidate).all(|&p| candidate % p != 0) {
+                bases.push(candidate);
+            }
+            candidate += 1;
+        }
+        Halton { bases: bases, index: 1, coordinate: 0 }
+    }
+
+    /// Return the dimension.
+    #[inline(always)]
+    pub fn dimension(&self) -> usize { self.bases.len() }
+}
+
+impl Source for Halton {
+    #[inline]
+    fn read_u64(&mut self) -> u64 {
+        (self.read_f64() * 18446744073709551616.0) as u64
+    }
+
+    /// Read the next coordinate, which lies in `(0, 1)`.
+    fn read_f64(&mut self) -> f64 {
+        let base = self.bases[self.coordinate];
+        let (mut i, mut scale, mut value) = (self.index, 1.0, 0.0);
+        while i > 0 {
+            scale /= base as f64;
+            value += (i % base) as f64 * scale;
+            i /= base;
+        }
+        self.coordinate += 1;
+        if self.coordinate == self.bases.len() {
+            self.coordinate = 0;
+            self.index += 1;
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use source::{Halton, Source};
+
+    #[test]
+    fn read_f64() {
+        let mut source = Halton::new(1);
+        assert_eq!(source.iter().take(7).collect::<Vec<f64>>(),
+                   vec![0.5, 0.25, 0.75, 0.125, 0.625, 0.375, 0.875]);
+
+        let mut source = Halton::new(3);
+        let values = source.iter().take(9).collect::<Vec<f64>>();
+        assert::close(&values, &vec![
+            1.0 / 2.0, 1.0 / 3.0, 1.0 / 5.0,
+            1.0 / 4.0, 2.0 / 3.0, 2.0 / 5.0,
+            3.0 / 4.0, 1.0 / 9.0, 3.0 / 5.0,
+        ], 1e-15);
+    }
+}
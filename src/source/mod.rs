@@ -1,3 +1,11 @@
 //! Sources of randomness.
 
 pub use random::*;
+
+mod halton;
+mod sobol;
+mod xoshiro;
+
+pub use self::halton::Halton;
+pub use self::sobol::Sobol;
+pub use self::xoshiro::Xoshiro256;
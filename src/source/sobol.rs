@@ -0,0 +1,144 @@
+use source::Source;
+
+/// A Sobol low-discrepancy sequence.
+///
+/// The sequence consists of points in the unit hypercube of a given
+/// dimension, which are generated in the Gray-code order. The source returns
+/// the coordinates of the first point, then those of the second one, and so
+/// on. The origin is skipped so that quantile functions are never evaluated
+/// at zero.
+///
+/// The sequence is deterministic and is intended for quasi-Monte Carlo
+/// integration with distributions whose sampling is based on the inverse of
+/// the cumulative distribution function, one coordinate per sample. Samplers
+/// relying on rejection consume a varying number of values per sample, which
+/// destroys the low-discrepancy structure, and they do not benefit.
+///
+/// ## References
+///
+/// 1. S. Joe and F. Y. Kuo, “Constructing Sobol sequences with better
+///    two-dimensional projections,” SIAM Journal on Scientific Computing,
+///    vol. 30, no. 5, pp. 2635–2654, 2008.
+#[derive(Clone)]
+pub struct Sobol {
+    directions: Vec<[u64; BITS]>,
+    state: Vec<u64>,
+    index: u64,
+    coordinate: usize,
+}
+
+const BITS: usize = 64;
+
+/// The degree, the encoded coefficients of the primitive polynomial, and the
+/// initial direction numbers for each dimension beyond the first one.
+const PARAMETERS: &'static [(usize, u64, &'static [u64])] = &[
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+];
+
+impl Sobol {
+    /// The maximal supported dimension.
+    pub const MAX_DIMENSION: usize = 16;
+
+    /// Create a Sobol sequence of dimension `dimension`.
+    ///
+    /// It should hold that `0 < dimension <= MAX_DIMENSION`.
+    pub fn new(dimension: usize) -> Self {
+        should!(0 < dimension && dimension <= Self::MAX_DIMENSION);
+        let mut directions = Vec::with_capacity(dimension);
+        let mut first = [0; BITS];
+        for k in 0..BITS {
+            first[k] = 1 << (BITS - 1 - k);
+        }
+        directions.push(first);
+        for &(degree, coefficients, initial) in PARAMETERS.iter().take(dimension - 1) {
+            let mut v = [0; BITS];
+            for k in 0..degree {
+                v[k] = initial[k] << (BITS - 1 - k);
+            }
+            for k in degree..BITS {
+                v[k] = v[k - degree] ^ (v[k - degree] >> degree);
+                for j in 1..degree {
+                    if (coefficients >> (degree - 1 - j)) & 1 == 1 {
+                        v[k] ^= v[k - j];
+                    }
+                }
+            }
+            directions.push(v);
+        }
+        Sobol { directions: directions, state: vec![0; dimension], index: 0, coordinate: 0 }
+    }
+
+    /// Return the dimension.
+    #[inline(always)]
+    pub fn dimension(&self) -> usize { self.state.len() }
+}
+
+impl Source for Sobol {
+    /// Read the next coordinate scaled to `{0, 1, …, u64::MAX}`.
+    fn read_u64(&mut self) -> u64 {
+        if self.coordinate == 0 {
+            let bit = (!self.index).trailing_zeros() as usize;
+            for (state, directions) in self.state.iter_mut().zip(&self.directions) {
+                *state ^= directions[bit];
+            }
+            self.index += 1;
+        }
+        let value = self.state[self.coordinate];
+        self.coordinate += 1;
+        if self.coordinate == self.state.len() {
+            self.coordinate = 0;
+        }
+        value
+    }
+
+    /// Read the next coordinate, which lies in `(0, 1)`.
+    #[inline]
+    fn read_f64(&mut self) -> f64 {
+        (self.read_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use source::{Sobol, Source};
+
+    #[test]
+    fn read_f64() {
+        let mut source = Sobol::new(2);
+        assert_eq!(source.iter().take(14).collect::<Vec<f64>>(), vec![
+            0.5, 0.5, 0.75, 0.25, 0.25, 0.75, 0.375, 0.375,
+            0.875, 0.875, 0.625, 0.125, 0.125, 0.625,
+        ]);
+    }
+
+    #[test]
+    fn stratification() {
+        let dimension = Sobol::MAX_DIMENSION;
+        let m = 10;
+        let mut source = Sobol::new(dimension);
+        let mut counts = vec![vec![0; 1 << m]; dimension];
+        for j in 0..dimension {
+            counts[j][0] += 1;
+        }
+        for _ in 1..(1 << m) {
+            for j in 0..dimension {
+                counts[j][(source.read_u64() >> (64 - m)) as usize] += 1;
+            }
+        }
+        assert!(counts.iter().all(|counts| counts.iter().all(|&count| count == 1)));
+    }
+}
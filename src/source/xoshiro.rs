@@ -0,0 +1,81 @@
+use source::Source;
+
+/// An instance of the xoshiro256** algorithm.
+///
+/// The generator is fully specified by its seed, and it produces the same
+/// stream on all platforms. The stream for a given seed is part of the public
+/// interface and does not change between versions.
+///
+/// ## References
+///
+/// 1. D. Blackman and S. Vigna, “Scrambled linear pseudorandom number
+///    generators,” ACM Transactions on Mathematical Software, vol. 47, no. 4,
+///    pp. 1–32, 2021.
+#[derive(Clone, Copy)]
+pub struct Xoshiro256([u64; 4]);
+
+impl Xoshiro256 {
+    /// Create an instance of the algorithm seeded with `seed`.
+    ///
+    /// The state is obtained by expanding the seed via the SplitMix64 generator,
+    /// so that any seed, including zero, is valid.
+    pub fn seed(mut seed: u64) -> Self {
+        let mut state = [0; 4];
+        for value in state.iter_mut() {
+            seed = seed.wrapping_add(0x9E3779B97F4A7C15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            *value = z ^ (z >> 31);
+        }
+        Xoshiro256(state)
+    }
+}
+
+impl Source for Xoshiro256 {
+    #[inline]
+    fn read_u64(&mut self) -> u64 {
+        let s = &mut self.0;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Read the top 53 bits of the next value scaled to `[0, 1)`.
+    #[inline]
+    fn read_f64(&mut self) -> f64 {
+        (self.read_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use source::{Source, Xoshiro256};
+
+    #[test]
+    fn read_f64() {
+        let mut source = Xoshiro256::seed(42);
+        assert_eq!(source.iter().take(4).collect::<Vec<f64>>(), vec![
+            0.08386297105988216, 0.3789802506626686, 0.6800434110281394, 0.9246929453253876,
+        ]);
+    }
+
+    #[test]
+    fn read_u64() {
+        let mut source = Xoshiro256::seed(42);
+        assert_eq!(source.iter().take(6).collect::<Vec<u64>>(), vec![
+            1546998764402558742, 6990951692964543102, 12544586762248559009,
+            17057574109182124193, 18295552978065317476, 14199186830065750584,
+        ]);
+
+        let mut source = Xoshiro256::seed(0);
+        assert_eq!(source.iter().take(2).collect::<Vec<u64>>(),
+                   vec![11091344671253066420, 13793997310169335082]);
+    }
+}
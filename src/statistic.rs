@@ -0,0 +1,147 @@
+//! Statistics of samples.
+
+use distribution::{Continuous, Discrete, Distribution, Parametric};
+
+/// Compute the Akaike information criterion, which is `2k - 2 ln(L)`, given
+/// the log-likelihood `log_likelihood` of a model with `k` free parameters.
+#[inline]
+pub fn aic(log_likelihood: f64, k: usize) -> f64 {
+    2.0 * k as f64 - 2.0 * log_likelihood
+}
+
+/// Compute the Akaike information criterion of `distribution` given
+/// `samples`.
+///
+/// The log-likelihood is computed via `log_likelihood`, and the number of
+/// free parameters is given by `num_parameters`.
+pub fn aic_of<D>(distribution: &D, samples: &[f64]) -> f64 where D: Continuous + Parametric {
+    aic(log_likelihood(distribution, samples), distribution.num_parameters())
+}
+
+/// Compute the Bayesian information criterion, which is `k ln(n) - 2 ln(L)`,
+/// given the log-likelihood `log_likelihood` of a model with `k` free
+/// parameters fitted to `n` samples.
+#[inline]
+pub fn bic(log_likelihood: f64, k: usize, n: usize) -> f64 {
+    k as f64 * (n as f64).ln() - 2.0 * log_likelihood
+}
+
+/// Compute the Bayesian information criterion of `distribution` given
+/// `samples`.
+///
+/// The log-likelihood is computed via `log_likelihood`, and the number of
+/// free parameters is given by `num_parameters`.
+pub fn bic_of<D>(distribution: &D, samples: &[f64]) -> f64 where D: Continuous + Parametric {
+    bic(log_likelihood(distribution, samples), distribution.num_parameters(), samples.len())
+}
+
+/// Compute the one-sample Kolmogorov–Smirnov statistic.
+///
+/// The statistic is the largest absolute difference between the empirical
+/// cumulative distribution function of `samples` and the cumulative
+/// distribution function of `distribution`. It should hold that `samples` is
+/// nonempty and contains no NaNs.
+pub fn ks<D>(samples: &[f64], distribution: &D) -> f64 where D: Distribution {
+    should!(!samples.is_empty() && samples.iter().all(|x| !x.is_nan()));
+    let mut samples = samples.to_vec();
+    samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = samples.len() as f64;
+    samples.iter().enumerate().fold(0.0, |statistic, (i, &x)| {
+        let p = distribution.distribution(x);
+        statistic.max((i + 1) as f64 / n - p).max(p - i as f64 / n)
+    })
+}
+
+/// Compute the log-likelihood of `samples` under a continuous distribution.
+///
+/// The log-likelihood is the sum of `ln_density` over the samples, which
+/// avoids the underflow of the product of densities.
+pub fn log_likelihood<D>(distribution: &D, samples: &[f64]) -> f64 where D: Continuous {
+    samples.iter().fold(0.0, |sum, &x| sum + distribution.ln_density(x))
+}
+
+/// Compute the log-likelihood of `samples` under a discrete distribution.
+///
+/// The log-likelihood is the sum of `ln_mass` over the samples.
+pub fn log_likelihood_discrete<D>(distribution: &D, samples: &[D::Value]) -> f64
+    where D: Discrete, D::Value: Copy
+{
+    samples.iter().fold(0.0, |sum, &x| sum + distribution.ln_mass(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert;
+    use prelude::*;
+
+    #[test]
+    fn aic() {
+        use statistic::{aic, aic_of, log_likelihood};
+
+        assert_eq!(aic(-10.0, 2), 24.0);
+        assert_eq!(aic(3.5, 1), -5.0);
+
+        let samples = [1.0, 2.5, -0.5, 3.0];
+        let d = Gaussian::new(1.0, 2.0);
+        assert_eq!(aic_of(&d, &samples), 4.0 - 2.0 * log_likelihood(&d, &samples));
+    }
+
+    #[test]
+    fn bic() {
+        use statistic::{bic, bic_of, log_likelihood};
+
+        assert::close(bic(-10.0, 2, 8), 24.158883083359672, 1e-14);
+        assert_eq!(bic(3.5, 1, 1), -7.0);
+
+        let samples = [1.0, 2.5, 0.5, 3.0];
+        let d = Exponential::new(2.0);
+        assert_eq!(bic_of(&d, &samples), 4f64.ln() - 2.0 * log_likelihood(&d, &samples));
+    }
+
+    #[test]
+    fn ks() {
+        use statistic::ks;
+
+        let d = Uniform::new(0.0, 1.0);
+        assert_eq!(ks(&[0.5], &d), 0.5);
+        assert_eq!(ks(&[0.75, 0.25], &d), 0.25);
+        assert_eq!(ks(&[0.5, 0.5], &d), 0.5);
+
+        let mut source = source::default();
+        let n = 10000;
+        let d = Exponential::new(2.0);
+        let samples = Independent(&d, &mut source).take(n).collect::<Vec<_>>();
+        assert!(ks(&samples, &d) < 1.63 / (n as f64).sqrt());
+        assert!(ks(&samples, &Exponential::new(1.8)) > 1.63 / (n as f64).sqrt());
+    }
+
+    #[test]
+    fn log_likelihood() {
+        use statistic::log_likelihood;
+        use std::f64::consts::PI;
+
+        let samples = [1.0, 2.5, -0.5, 3.0, 0.75, 1.25];
+        let n = samples.len() as f64;
+        let d = Gaussian::fit(&samples);
+        let best = log_likelihood(&d, &samples);
+        assert::close(best, -0.5 * n * (2.0 * PI * d.sigma() * d.sigma()).ln() - 0.5 * n, 1e-12);
+        for &(mu, sigma) in &[(-0.1, 0.0), (0.1, 0.0), (0.0, -0.1), (0.0, 0.1), (0.05, 0.05)] {
+            let other = Gaussian::new(d.mu() + mu, d.sigma() + sigma);
+            assert!(log_likelihood(&other, &samples) < best);
+        }
+
+        let samples = vec![1e3; 100];
+        assert::close(log_likelihood(&Gaussian::new(0.0, 1.0), &samples),
+                      -100.0 * (0.5 * (2.0 * PI).ln() + 5e5), 1e-6);
+    }
+
+    #[test]
+    fn log_likelihood_discrete() {
+        use statistic::log_likelihood_discrete;
+
+        let d = Poisson::new(2.0);
+        let samples = [0, 3, 1, 2];
+        assert::close(log_likelihood_discrete(&d, &samples),
+                      samples.iter().fold(0.0, |sum, &x| sum + d.mass(x).ln()), 1e-14);
+    }
+}